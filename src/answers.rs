@@ -0,0 +1,227 @@
+//! Expected answers, read from a TOML file with a table for each day and a key for each
+//! part, like this:
+//!
+//! ```toml
+//! [day01]
+//! part1 = 69795
+//! part2 = 208437
+//! ```
+//!
+//! Answers can be written as integers or strings. Answers that don't fit in an `i64`
+//! (or that aren't numbers at all) need to be strings. Days and parts that aren't in
+//! the file are reported as missing instead of failing, so any other table or key is
+//! an error. Otherwise a typo like `[day1]` would quietly turn into a missing answer.
+use crate::*;
+use std::fmt::{Display, Formatter, Result as FmtResult};
+use std::path::Path;
+use toml::Value;
+
+/// The expected answers for any number of days and parts
+#[derive(Debug, Default, Clone)]
+pub struct Answers(toml::value::Table);
+
+impl Answers {
+    /// Parse expected answers from the text of a TOML file
+    pub fn parse(text: &str) -> Result<Self> {
+        let table = match text.parse::<Value>() {
+            Ok(Value::Table(table)) => table,
+            Ok(_) => return Err(Error::parse(text, text, "expected a table of days")),
+            Err(error) => {
+                let (line, column) = error.line_col().unwrap_or_default();
+                let reason = error.to_string();
+                return Err(Error::Parse {
+                    line: line + 1,
+                    column: column + 1,
+                    reason,
+                });
+            }
+        };
+
+        // Check that every answer is something we can compare against, for a day
+        // and part that actually exist
+        for (day, parts) in table.iter() {
+            let Value::Table(parts) = parts else {
+                let at = toml_slice(text, None, 0, Some(day));
+                return Err(Error::parse(text, at, format!("[{day}] should be a table")));
+            };
+            if !is_day(day) {
+                let reason = format!("[{day}] isn't a day, they go from [day01] to [day25]");
+                let at = toml_slice(text, Some(day), 0, None);
+                return Err(Error::parse(text, at, reason));
+            }
+            for (part, answer) in parts.iter() {
+                if !matches!(part.as_str(), "part1" | "part2") {
+                    let reason = format!("{day}.{part} isn't a part, they're part1 and part2");
+                    let at = toml_slice(text, Some(day), 0, Some(part));
+                    return Err(Error::parse(text, at, reason));
+                }
+                if !matches!(answer, Value::Integer(_) | Value::String(_)) {
+                    let reason = format!("{day}.{part} should be an integer or a string");
+                    let at = toml_slice(text, Some(day), 0, Some(part));
+                    return Err(Error::parse(text, at, reason));
+                }
+            }
+        }
+        Ok(Answers(table))
+    }
+
+    /// Read and parse expected answers from a TOML file
+    pub fn read(path: &Path) -> Result<Self> {
+        let text = Source::Path(path).text("")?;
+        Answers::parse(&text)
+    }
+
+    /// The expected answer for one part of a day, if there is one
+    pub fn expected(&self, day: u8, part: Part) -> Option<String> {
+        let part = match part {
+            Part::One => "part1",
+            Part::Two => "part2",
+        };
+        match self.0.get(&format!("day{day:02}"))?.get(part)? {
+            Value::Integer(answer) => Some(answer.to_string()),
+            Value::String(answer) => Some(answer.clone()),
+            _ => None,
+        }
+    }
+
+    /// Compare an answer against the expected answer for that day and part
+    pub fn check(&self, day: u8, part: Part, answer: &Output) -> Check {
+        match self.expected(day, part) {
+            Some(expected) if *answer == expected => Check::Pass,
+            Some(expected) => Check::Fail(expected),
+            None => Check::Missing,
+        }
+    }
+}
+
+/// Check whether a table name is a day with answers, `day01` through `day25`
+fn is_day(name: &str) -> bool {
+    name.strip_prefix("day")
+        .filter(|number| number.len() == 2 && number.bytes().all(|b| b.is_ascii_digit()))
+        .and_then(|number| number.parse::<u8>().ok())
+        .is_some_and(|number| (1..=25).contains(&number))
+}
+
+/// Find where something was written in the text of a TOML file, so errors can point
+/// right at it. Looks for `key` in the `nth` table called `table` (or before any
+/// tables, for `None`) and returns its value. Without a `key`, or if the key isn't
+/// there, it's the table's header instead. This only understands one `key = value`
+/// per line, which is all these files ever have, and anything it can't find is at
+/// the end of the text.
+pub(crate) fn toml_slice<'a>(
+    text: &'a str,
+    table: Option<&str>,
+    nth: usize,
+    key: Option<&str>,
+) -> &'a str {
+    let mut found = &text[text.len()..];
+    let mut tables_seen = 0;
+    let mut in_table = table.is_none();
+    for line in text.lines() {
+        let trimmed = line.trim();
+        if trimmed.starts_with('[') {
+            let name = trimmed.trim_matches(|c| c == '[' || c == ']').trim();
+            let is_table = table == Some(name.trim_matches('"'));
+            in_table = is_table && tables_seen == nth;
+            tables_seen += usize::from(is_table);
+            if in_table {
+                found = trimmed;
+            }
+            continue;
+        }
+        let Some((name, value)) = line.split_once('=').filter(|_| in_table) else {
+            continue;
+        };
+        if key == Some(name.trim().trim_matches('"')) {
+            return value.trim();
+        }
+    }
+    found
+}
+
+/// The result of comparing an answer to the expected answer
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Check {
+    Pass,
+    /// Holds the answer that was expected
+    Fail(String),
+    Missing,
+}
+
+impl Check {
+    /// A short label for the result, like "pass"
+    pub fn label(&self) -> &'static str {
+        match self {
+            Check::Pass => "pass",
+            Check::Fail(_) => "fail",
+            Check::Missing => "missing",
+        }
+    }
+}
+
+impl Display for Check {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match self {
+            Check::Fail(expected) if expected.contains('\n') => {
+                write!(f, "FAIL, expected:\n{expected}")
+            }
+            Check::Fail(expected) => write!(f, "FAIL, expected {expected}"),
+            check => write!(f, "{}", check.label().to_uppercase()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn check_answers() {
+        let text = "[day01]\npart1 = 69795\npart2 = \"208437\"\n\n[day25]\npart1 = \"2=-0\"\n";
+        let answers = Answers::parse(text).unwrap();
+        assert_eq!(answers.check(1, Part::One, &69795u32.into()), Check::Pass);
+        assert_eq!(answers.check(1, Part::Two, &208437u64.into()), Check::Pass);
+        assert_eq!(
+            answers.check(25, Part::One, &String::from("2=-1").into()),
+            Check::Fail(String::from("2=-0"))
+        );
+        assert_eq!(answers.check(25, Part::Two, &0u8.into()), Check::Missing);
+        assert_eq!(answers.check(2, Part::One, &0u8.into()), Check::Missing);
+    }
+
+    #[test]
+    fn check_bad_answers() {
+        let Err(Error::Parse { line, .. }) = Answers::parse("[day01]\npart1 = \n") else {
+            panic!("Should not parse!");
+        };
+        assert_eq!(line, 2);
+
+        // Answers of the wrong type point at the answer itself
+        let text = "[day01]\npart1 = 1\n\n[day02]\npart1 = 2\npart2 = 1.5\n";
+        let Err(Error::Parse { line, column, .. }) = Answers::parse(text) else {
+            panic!("Should not parse!");
+        };
+        assert_eq!((line, column), (6, 9));
+        let Err(Error::Parse { line, column, .. }) = Answers::parse("day01 = 5\n") else {
+            panic!("Should not parse!");
+        };
+        assert_eq!((line, column), (1, 9));
+    }
+
+    #[test]
+    fn check_unknown_answers() {
+        // Days and parts that don't exist point at the table or the answer
+        for (text, at) in [
+            ("[day01]\npart1 = 1\n\n[day1]\npart1 = 2\n", (4, 1)),
+            ("[day26]\npart1 = 1\n", (1, 1)),
+            ("[day00]\npart1 = 1\n", (1, 1)),
+            ("[day01]\npart1 = 1\npart_2 = 2\n", (3, 10)),
+        ] {
+            let Err(Error::Parse { line, column, .. }) = Answers::parse(text) else {
+                panic!("Should not parse {text:?}!");
+            };
+            assert_eq!((line, column), at, "{text:?}");
+        }
+        assert!(Answers::parse("[day25]\npart2 = \"\"\n").is_ok());
+    }
+}
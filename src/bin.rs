@@ -1,12 +1,20 @@
 // Declare modules for each day here
+use anyhow::{anyhow, bail, Context, Result};
 use aoc2022lib::*;
-use clap::Parser;
+use clap::{Parser, Subcommand, ValueEnum};
+use rayon::prelude::*;
+use std::collections::BTreeSet;
 use std::fmt::{Display, Formatter, Result as FmtResult};
+use std::path::PathBuf;
+use std::str::FromStr;
 use std::time::{Duration, Instant};
 
 #[derive(Parser, Debug)]
 #[clap(author, version, about, long_about = None)]
 struct Args {
+    #[command(subcommand)]
+    command: Option<Command>,
+
     /// Run one specific day
     #[arg(short, long)]
     day: Option<u8>,
@@ -15,61 +23,635 @@ struct Args {
     #[arg(short, long)]
     all: bool,
 
+    /// Run a selection of days, like `1-5,12,20-25`
+    #[arg(long, value_name = "DAYS", conflicts_with_all = ["day", "all"])]
+    days: Option<DaySet>,
+
+    /// Leave these days out of the selection, like `16,19`
+    #[arg(long, value_name = "DAYS")]
+    skip: Option<DaySet>,
+
+    /// Which part (or parts) of each day to run
+    #[arg(long, value_enum, default_value_t = PartSelection::Both)]
+    part: PartSelection,
+
     /// Run with timing
     #[arg(short, long)]
     timed: bool,
+
+    /// Read the puzzle input for `--day` from this file instead of the bundled input
+    #[arg(short, long, conflicts_with_all = ["all", "days"])]
+    input: Option<PathBuf>,
+
+    /// Read puzzle inputs from `<DIR>/XX/input.txt` instead of the bundled inputs
+    #[arg(long, value_name = "DIR", conflicts_with = "input")]
+    input_dir: Option<PathBuf>,
+
+    /// Read puzzle inputs downloaded with `fetch` instead of the bundled inputs
+    #[arg(long, conflicts_with_all = ["input", "input_dir"])]
+    fetched: bool,
+
+    /// How to print the results
+    #[arg(short, long, value_enum, default_value_t = Format::Text)]
+    format: Format,
+
+    /// Run each day this many times and report the min, median, and max time
+    /// taken by each phase
+    #[arg(short, long, default_value_t = 1, value_parser = clap::value_parser!(u32).range(1..))]
+    repeat: u32,
+
+    /// Check the answers against the expected answers in this TOML file, and fail
+    /// if any of them don't match
+    #[arg(short, long, value_name = "FILE")]
+    check: Option<PathBuf>,
+
+    /// Run the selected days at the same time on a thread pool. Results are still
+    /// printed in order.
+    #[arg(short, long)]
+    parallel: bool,
+
+    #[command(flatten)]
+    account: Account,
+}
+
+#[derive(Subcommand, Debug)]
+enum Command {
+    /// Download a day's puzzle input, so it can be solved with `--fetched`
+    Fetch(FetchArgs),
+    /// Solve one part of a day's downloaded input and submit the answer
+    Submit(SubmitArgs),
+    /// Draw the states of a day's simulation as text, a replay, or images
+    Visualize(VisualizeArgs),
+}
+
+#[derive(clap::Args, Debug)]
+struct FetchArgs {
+    /// The day to download the input for
+    #[arg(short, long, value_parser = clap::value_parser!(u8).range(1..=25))]
+    day: u8,
+
+    /// Download the input again, even if it's already been downloaded
+    #[arg(long)]
+    refresh: bool,
+}
+
+#[derive(clap::Args, Debug)]
+struct SubmitArgs {
+    /// The day to submit an answer for
+    #[arg(short, long, value_parser = clap::value_parser!(u8).range(1..=25))]
+    day: u8,
+
+    /// The part to submit an answer for
+    #[arg(short, long, value_parser = clap::value_parser!(u8).range(1..=2))]
+    part: u8,
+
+    /// Solve the puzzle input in this file instead of the downloaded input
+    #[arg(short, long)]
+    input: Option<PathBuf>,
+}
+
+#[derive(clap::Args, Debug)]
+struct VisualizeArgs {
+    /// The day to draw (one of 9, 14, 17, 22, 23, or 24)
+    #[arg(short, long, value_parser = visualized_day)]
+    day: u8,
+
+    /// Draw the puzzle input in this file instead of the bundled input
+    #[arg(short, long)]
+    input: Option<PathBuf>,
+
+    /// Draw each frame over the last one in the terminal, waiting this many
+    /// milliseconds between frames
+    #[arg(long, value_name = "MS", conflicts_with = "ppm")]
+    replay: Option<u64>,
+
+    /// Write each frame as a PPM image in this directory instead of printing it
+    #[arg(long, value_name = "DIR")]
+    ppm: Option<PathBuf>,
+
+    /// How many pixels on a side each cell is in the images
+    #[arg(long, default_value_t = 4, value_parser = clap::value_parser!(u64).range(1..))]
+    scale: u64,
+
+    /// Only draw every Nth frame, for the long simulations
+    #[arg(long, value_name = "N", default_value_t = 1, value_parser = clap::value_parser!(u64).range(1..))]
+    every: u64,
+}
+
+/// Who's talking to the puzzle server, and where to keep their inputs and answers.
+/// Used by `fetch` and `submit`, and by `--fetched` to find the inputs again.
+#[derive(clap::Args, Debug)]
+struct Account {
+    /// The `session` cookie from a browser logged in to Advent of Code. Defaults to
+    /// `$AOC_SESSION`, which keeps it out of your shell history.
+    #[arg(long, global = true, value_name = "TOKEN")]
+    session: Option<String>,
+
+    /// Whose downloaded inputs to use. Defaults to a name made up from the session
+    /// token, so each token gets its own inputs.
+    #[arg(long, global = true)]
+    user: Option<String>,
+
+    /// Where to keep downloaded inputs. Defaults to `$AOC_DATA_DIR`, or
+    /// `~/.local/share/aoc2022`.
+    #[arg(long, global = true, value_name = "DIR")]
+    data_dir: Option<PathBuf>,
+
+    /// Talk to this server instead of the Advent of Code site. Defaults to
+    /// `$AOC_BASE_URL`, if it's set.
+    #[arg(long, global = true, value_name = "URL")]
+    base_url: Option<String>,
+}
+
+impl Account {
+    /// The session token, from `--session` or `$AOC_SESSION`
+    fn session(&self) -> Option<String> {
+        let session = self.session.clone().or_else(|| env_var("AOC_SESSION"));
+        session.filter(|session| !session.trim().is_empty())
+    }
+
+    /// The cache of downloaded inputs for this user
+    fn cache(&self) -> Result<fetch::Cache> {
+        let data_dir = self
+            .data_dir
+            .clone()
+            .or_else(fetch::default_data_dir)
+            .ok_or(anyhow!(
+                "Nowhere to keep inputs! Pass --data-dir or set AOC_DATA_DIR."
+            ))?;
+        let user = match (&self.user, self.session()) {
+            (Some(user), _) => user.clone(),
+            (None, Some(session)) => fetch::user_name(&session),
+            (None, None) => bail!(
+                "Whose inputs? Pass --user, or a session token with --session or AOC_SESSION."
+            ),
+        };
+        Ok(fetch::Cache::new(&data_dir, &user)?)
+    }
+
+    /// A client for the puzzle server, logged in with the session token
+    fn client(&self) -> Result<fetch::Client> {
+        let session = self.session().ok_or(anyhow!(
+            "No session token! Pass --session or set AOC_SESSION to the `session` cookie \
+             from a browser logged in to Advent of Code."
+        ))?;
+        let base_url = self
+            .base_url
+            .clone()
+            .or_else(|| env_var("AOC_BASE_URL"))
+            .unwrap_or_else(|| fetch::BASE_URL.to_string());
+        Ok(fetch::Client::new(&base_url, &session)?)
+    }
 }
 
-fn main() {
-    let args = Args::parse();
-    let all = args.all;
+/// An environment variable, if it's set to something
+fn env_var(name: &str) -> Option<String> {
+    std::env::var(name).ok().filter(|value| !value.is_empty())
+}
+
+/// The ways results can be printed. `Json` and `Csv` are meant for scripts, and
+/// include the type of each answer and how long each part took.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+enum Format {
+    /// The asterisk boxes
+    Text,
+    /// A JSON array with an object for each day
+    Json,
+    /// A header row, then a row for each day
+    Csv,
+}
+
+/// Which parts of each day to run
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+enum PartSelection {
+    /// Only part one
+    #[value(name = "1")]
+    One,
+    /// Only part two
+    #[value(name = "2")]
+    Two,
+    /// Both parts
+    Both,
+}
+
+impl PartSelection {
+    fn parts(&self) -> &'static [Part] {
+        match self {
+            PartSelection::One => &[Part::One],
+            PartSelection::Two => &[Part::Two],
+            PartSelection::Both => &[Part::One, Part::Two],
+        }
+    }
+}
+
+/// A set of days, parsed from a comma-separated list of days and ranges of days,
+/// like `1-5,12,20-25`
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct DaySet(BTreeSet<u8>);
+
+impl FromStr for DaySet {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        let day = |s: &str| match s.trim().parse::<u8>() {
+            Ok(day @ 1..=25) => Ok(day),
+            _ => Err(format!("'{s}' isn't a day on the Advent Calendar (1-25)")),
+        };
+        let mut days = BTreeSet::new();
+        for item in s.split(',') {
+            match item.split_once('-') {
+                Some((first, last)) => {
+                    let (first, last) = (day(first)?, day(last)?);
+                    if first > last {
+                        return Err(format!("'{item}' goes backwards"));
+                    }
+                    days.extend(first..=last);
+                }
+                None => {
+                    days.insert(day(item)?);
+                }
+            }
+        }
+        Ok(DaySet(days))
+    }
+}
+
+impl Args {
+    /// The days to run, in order, from `--day`, `--days`, or `--all`, leaving out
+    /// any days from `--skip`
+    fn selected_days(&self) -> Vec<u8> {
+        let mut days: Vec<_> = match (&self.days, self.all) {
+            (Some(DaySet(days)), _) => days.iter().copied().collect(),
+            (None, true) => (1..=25).collect(),
+            (None, false) => self.day.into_iter().collect(),
+        };
+        if let Some(DaySet(skip)) = &self.skip {
+            days.retain(|day| !skip.contains(day));
+        }
+        days
+    }
+
+    /// Determine the path to the puzzle input file for a given day, if the
+    /// bundled input shouldn't be used.
+    fn input_path(&self, day: u8) -> Option<PathBuf> {
+        if let Some(path) = &self.input {
+            return Some(path.clone());
+        }
+        let dir = self.input_dir.as_ref()?;
+        Some(dir.join(format!("{day:02}")).join("input.txt"))
+    }
+}
+
+/// Use the puzzle input file at `path`, if there is one, or the bundled input.
+fn source(path: &Option<PathBuf>) -> Source {
+    path.as_deref().map_or(Source::Bundled, Source::Path)
+}
+
+fn main() -> Result<()> {
+    let mut args = Args::parse();
+    match &args.command {
+        Some(Command::Fetch(options)) => return fetch_input(options, &args.account),
+        Some(Command::Submit(options)) => return submit_answer(options, &args.account),
+        Some(Command::Visualize(options)) => return visualize_day(options),
+        None => {}
+    }
+
+    // Downloaded inputs are laid out just like an `--input-dir`
+    if args.fetched {
+        let cache = args.account.cache()?;
+        args.input_dir = Some(cache.dir().to_path_buf());
+    }
     let timed = args.timed;
     let timer = Instant::now();
+    let answers = match &args.check {
+        Some(path) => Some(Answers::read(path).context("Could not load the expected answers")?),
+        None => None,
+    };
+
+    let days = args.selected_days();
+    if days.is_empty() {
+        println!("Didn't do anything. Run with --help to see flags.");
+        return Ok(());
+    }
+    let mut selected = Vec::with_capacity(days.len());
+    for day in days {
+        let puzzle = puzzle(day).ok_or(anyhow!("There's no day {day} on the Advent Calendar!"))?;
+        selected.push(puzzle);
+    }
 
-    if all {
-        if timed {
-            let mut results = Vec::with_capacity(25);
-            for day in 1..=25 {
-                results.push(run_timed(day, &timer));
+    // A day that fails shouldn't keep the rest of the days from running, so
+    // report the error and keep going, then fail at the end.
+    let parts = args.part.parts();
+    let run = |puzzle: &dyn Puzzle| {
+        let path = args.input_path(puzzle.day());
+        let outcome = run_day(puzzle, parts, source(&path), args.repeat);
+        (puzzle.day(), outcome)
+    };
+    let outcomes: Vec<_> = match args.parallel {
+        true => selected.par_iter().map(|puzzle| run(*puzzle)).collect(),
+        false => selected.iter().map(|puzzle| run(*puzzle)).collect(),
+    };
+    let total = timer.elapsed();
+
+    let mut failed = 0;
+    let mut results = Vec::with_capacity(selected.len());
+    for (day, outcome) in outcomes {
+        match outcome {
+            Ok(result) => results.push(result.checked(answers.as_ref())),
+            Err(error) => {
+                eprintln!("Day {day} failed: {error}");
+                failed += 1;
             }
-            let total = timer.elapsed();
-            results.iter().for_each(|result| println!("{}", result));
-            println!("Total Runtime: {total:?}");
-        } else {
-            (1..=25).for_each(|day| println!("{}", run_day(day)));
-        }
-    } else if let Some(day) = args.day {
-        if timed {
-            println!("{}", run_timed(day, &timer));
-        } else {
-            println!("{}", run_day(day));
         }
-    } else {
-        println!("Didn't do anything. Run with --help to see flags.")
     }
+    report(args.format, timed, &results);
+
+    // When the days run in parallel, the total time should be a lot less than
+    // the time spent on each day added together.
+    let many_days = selected.len() > 1;
+    if many_days && (timed || args.parallel) && args.format == Format::Text {
+        let summed: Duration = results
+            .iter()
+            .flat_map(|result| result.timings.iter().map(Timings::total))
+            .sum();
+        println!("Total Runtime: {total:?} (summed across days: {summed:?})");
+    }
+    let mismatched = summarize_checks(&results);
+    if failed > 0 {
+        bail!("{failed} of {} days failed!", selected.len());
+    }
+    if mismatched > 0 {
+        bail!("{mismatched} answers didn't match the expected answers!");
+    }
+
+    Ok(())
+}
+
+/// Download a day's input into the cache, unless it's already there
+fn fetch_input(options: &FetchArgs, account: &Account) -> Result<()> {
+    let day = options.day;
+    let client = account.client()?;
+    let cache = account.cache()?;
+    let path = cache
+        .fetch(&client, day, options.refresh)
+        .with_context(|| format!("Could not fetch the input for day {day}"))?;
+    println!("The input for day {day} is in {}", path.display());
+    Ok(())
+}
+
+/// Solve one part of a day and submit the answer, unless the history already says
+/// how that would go. Every answer that gets sent is added to the history.
+fn submit_answer(options: &SubmitArgs, account: &Account) -> Result<()> {
+    use aoc2022lib::submit::{Attempt, History, Verdict};
+
+    let day = options.day;
+    let part = match options.part {
+        1 => Part::One,
+        _ => Part::Two,
+    };
+    let cache = account.cache()?;
+    let path = options
+        .input
+        .clone()
+        .or_else(|| cache.get(day))
+        .ok_or(anyhow!(
+        "There's no input for day {day} to solve! Run `fetch --day {day}` first, or pass --input."
+    ))?;
+    let puzzle = puzzle(day).ok_or(anyhow!("There's no day {day} on the Advent Calendar!"))?;
+    let answer = puzzle.run(part, Source::Path(&path))?.to_string();
+    let label = format!("Day {day}, part {}: {answer}", options.part);
+
+    let mut history = History::read(&cache.history_path())?;
+    match history.known(day, part, &answer) {
+        Some(Verdict::Right) => {
+            println!("{label} is already known to be right, so there's no need to submit it");
+            return Ok(());
+        }
+        Some(verdict) => bail!("Not submitting {label}, since it's already known to be {verdict}"),
+        None => {}
+    }
+
+    let verdict = account
+        .client()?
+        .submit(day, part, &answer)
+        .with_context(|| format!("Could not submit {label}"))?;
+    history
+        .record(Attempt::new(day, part, &answer, verdict))
+        .context("Could not record the answer in the history")?;
+    println!("{label} is {verdict}");
+    if verdict.is_wrong() {
+        bail!("The answer for day {day}, part {} was wrong!", options.part);
+    }
+    Ok(())
+}
+
+/// Parse a day for the `visualize` command, which only works for the days that have
+/// a visualization
+fn visualized_day(s: &str) -> std::result::Result<u8, String> {
+    use aoc2022lib::visualize::DAYS;
+
+    match s.trim().parse::<u8>() {
+        Ok(day) if DAYS.contains(&day) => Ok(day),
+        _ => Err(format!(
+            "there's no visualization for day '{s}', try one of {DAYS:?}"
+        )),
+    }
+}
+
+/// Run a day's visualization, sending the frames wherever they were asked to go
+fn visualize_day(options: &VisualizeArgs) -> Result<()> {
+    use aoc2022lib::visualize::{visualization, Ascii, Every, Ppm, Replay};
+
+    let day = options.day;
+    let visualize = visualization(day).ok_or(anyhow!("There's no visualization for day {day}!"))?;
+    let source = source(&options.input);
+    let every = options.every as usize;
+    let out = std::io::stdout().lock();
+    match (&options.ppm, options.replay) {
+        (Some(dir), _) => {
+            let mut sink = Every::new(Ppm::new(dir, options.scale as usize), every);
+            visualize(source, &mut sink)?;
+            let count = sink.into_inner().count();
+            println!("Wrote {count} frames to {}", dir.display());
+        }
+        (None, Some(delay)) => {
+            let delay = Duration::from_millis(delay);
+            visualize(source, &mut Every::new(Replay::new(out, delay), every))?;
+        }
+        (None, None) => visualize(source, &mut Every::new(Ascii(out), every))?,
+    }
+    Ok(())
+}
+
+/// Print the results in the requested format
+fn report(format: Format, timed: bool, results: &[RunResult]) {
+    match format {
+        Format::Text if timed => results.iter().for_each(|r| println!("{}", TimedResult(r))),
+        Format::Text => results.iter().for_each(|r| println!("{r}")),
+        Format::Json => {
+            let records: Vec<_> = results.iter().map(|r| format!("  {}", r.json())).collect();
+            println!("[\n{}\n]", records.join(",\n"));
+        }
+        Format::Csv => {
+            let header = [
+                "day,runs,parse_ns,parse_min_ns,parse_max_ns",
+                "part_one,part_one_type,part_one_ns,part_one_min_ns,part_one_max_ns",
+                "part_two,part_two_type,part_two_ns,part_two_min_ns,part_two_max_ns",
+                "part_one_check,part_two_check",
+            ];
+            println!("{}", header.join(","));
+            results.iter().for_each(|r| println!("{}", r.csv()));
+        }
+    }
+}
+
+/// If the answers were checked, print how many passed, failed, or were missing, and
+/// return the number that failed.
+fn summarize_checks(results: &[RunResult]) -> usize {
+    let checks: Vec<_> = results
+        .iter()
+        .flat_map(|r| [&r.check_one, &r.check_two])
+        .flatten()
+        .collect();
+    if checks.is_empty() {
+        return 0;
+    }
+    let count = |label| checks.iter().filter(|c| c.label() == label).count();
+    let (passed, failed, missing) = (count("pass"), count("fail"), count("missing"));
+    eprintln!(
+        "Checked {} answers: {passed} passed, {failed} failed, {missing} missing",
+        checks.len()
+    );
+    failed
 }
 
+/// The answers for the parts of a day that were run, how long they took, and how
+/// they compared to the expected answers (if they were checked)
 struct RunResult {
     day: u8,
-    answer_one: Output,
-    answer_two: Output,
+    answer_one: Option<Output>,
+    answer_two: Option<Output>,
+    timings: Vec<Timings>,
+    check_one: Option<Check>,
+    check_two: Option<Check>,
 }
 
-impl Display for RunResult {
-    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
-        let RunResult {
-            day,
-            answer_one,
-            answer_two,
-        } = self;
+impl RunResult {
+    /// Check the answers against the expected answers, if there are any
+    fn checked(mut self, answers: Option<&Answers>) -> Self {
+        if let Some(answers) = answers {
+            let check = |part, answer: &Option<Output>| {
+                answer.as_ref().map(|a| answers.check(self.day, part, a))
+            };
+            self.check_one = check(Part::One, &self.answer_one);
+            self.check_two = check(Part::Two, &self.answer_two);
+        }
+        self
+    }
+
+    /// Summarize one phase of the timings across all the runs
+    fn stats(&self, phase: impl Fn(&Timings) -> Duration) -> Stats {
+        Stats::from(self.timings.iter().map(phase).collect::<Vec<_>>())
+    }
+
+    /// Write the top of the asterisk box, with the answers and the checks
+    fn fmt_answers(&self, f: &mut Formatter<'_>) -> FmtResult {
         writeln!(
             f,
             "************************************************************"
         )?;
-        writeln!(f, "* Advent of Code: 2022, Day {day}")?;
+        writeln!(f, "* Advent of Code: 2022, Day {}", self.day)?;
         writeln!(f, "*   Solution for...")?;
-        writeln!(f, "*     Part One: {answer_one}")?;
-        writeln!(f, "*     Part Two: {answer_two}")?;
+        if let Some(answer_one) = &self.answer_one {
+            writeln!(f, "*     Part One: {answer_one}")?;
+        }
+        if let Some(answer_two) = &self.answer_two {
+            writeln!(f, "*     Part Two: {answer_two}")?;
+        }
+        if self.check_one.is_some() || self.check_two.is_some() {
+            writeln!(f, "*   Checked against the expected answers...")?;
+        }
+        if let Some(check_one) = &self.check_one {
+            writeln!(f, "*     Part One: {check_one}")?;
+        }
+        if let Some(check_two) = &self.check_two {
+            writeln!(f, "*     Part Two: {check_two}")?;
+        }
+        Ok(())
+    }
+
+    /// Produce a JSON object with the answers, their types, and how long each
+    /// phase took, in nanoseconds. The `duration_ns` is the median of all runs.
+    /// Parts that weren't run are `null`.
+    fn json(&self) -> String {
+        let part = |answer: &Option<Output>, stats: Stats, check: &Option<Check>| {
+            let Some(answer) = answer else {
+                return String::from("null");
+            };
+            let check = match check {
+                Some(Check::Fail(expected)) => {
+                    format!(
+                        ", \"check\": \"fail\", \"expected\": {}",
+                        Output::String(expected.clone()).to_json()
+                    )
+                }
+                Some(check) => format!(", \"check\": \"{}\"", check.label()),
+                None => String::new(),
+            };
+            format!(
+                "{{\"answer\": {}, \"type\": \"{}\", {}{check}}}",
+                answer.to_json(),
+                answer.kind(),
+                stats.json()
+            )
+        };
+        format!(
+            "{{\"day\": {}, \"runs\": {}, \"parse\": {{{}}}, \"part_one\": {}, \"part_two\": {}}}",
+            self.day,
+            self.timings.len(),
+            self.stats(|t| t.parse).json(),
+            part(
+                &self.answer_one,
+                self.stats(|t| t.part_one),
+                &self.check_one
+            ),
+            part(
+                &self.answer_two,
+                self.stats(|t| t.part_two),
+                &self.check_two
+            )
+        )
+    }
+
+    /// Produce a CSV row with the same fields as the JSON object, in the order
+    /// given by the header row in `report`. Parts that weren't run are left empty.
+    fn csv(&self) -> String {
+        let part = |answer: &Option<Output>, stats: Stats| match answer {
+            Some(answer) => format!(
+                "{},{},{}",
+                csv_field(&answer.to_string()),
+                answer.kind(),
+                stats.csv()
+            ),
+            None => String::from(",,,,"),
+        };
+        let check = |check: &Option<Check>| check.as_ref().map_or("", Check::label);
+        format!(
+            "{},{},{},{},{},{},{}",
+            self.day,
+            self.timings.len(),
+            self.stats(|t| t.parse).csv(),
+            part(&self.answer_one, self.stats(|t| t.part_one)),
+            part(&self.answer_two, self.stats(|t| t.part_two)),
+            check(&self.check_one),
+            check(&self.check_two)
+        )
+    }
+}
+
+impl Display for RunResult {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        self.fmt_answers(f)?;
         writeln!(
             f,
             "************************************************************"
@@ -78,69 +660,127 @@ impl Display for RunResult {
     }
 }
 
-fn run_day(day: u8) -> RunResult {
-    let run = match day {
-        1 => day01::run,
-        2 => day02::run,
-        3 => day03::run,
-        4 => day04::run,
-        5 => day05::run,
-        6 => day06::run,
-        7 => day07::run,
-        8 => day08::run,
-        9 => day09::run,
-        10 => day10::run,
-        11 => day11::run,
-        12 => day12::run,
-        13 => day13::run,
-        14 => day14::run,
-        15 => day15::run,
-        16 => day16::run,
-        17 => day17::run,
-        18 => day18::run,
-        19 => day19::run,
-        20 => day20::run,
-        21 => day21::run,
-        22 => day22::run,
-        23 => day23::run,
-        24 => day24::run,
-        25 => day25::run,
-        _ => panic!("There's no day {day} on the Advent Calendar!"),
-    };
+/// The min, median, and max of the durations for one phase over every run
+#[derive(Debug, Clone, Copy)]
+struct Stats {
+    runs: usize,
+    min: Duration,
+    median: Duration,
+    max: Duration,
+}
 
-    let answer_one = run(Part::One);
-    let answer_two = run(Part::Two);
-    RunResult {
-        day,
-        answer_one,
-        answer_two,
+impl From<Vec<Duration>> for Stats {
+    fn from(mut durations: Vec<Duration>) -> Self {
+        durations.sort_unstable();
+        let runs = durations.len();
+        let min = durations.first().copied().unwrap_or_default();
+        let max = durations.last().copied().unwrap_or_default();
+        let median = match runs {
+            0 => Duration::ZERO,
+            n if n % 2 == 1 => durations[n / 2],
+            n => (durations[n / 2 - 1] + durations[n / 2]) / 2,
+        };
+        Stats {
+            runs,
+            min,
+            median,
+            max,
+        }
     }
 }
 
-struct TimedResult {
-    day: u8,
-    answer_one: Output,
-    answer_two: Output,
-    duration: Duration,
+impl Stats {
+    /// The fields for these stats in a JSON object, without the braces
+    fn json(&self) -> String {
+        format!(
+            "\"duration_ns\": {}, \"min_ns\": {}, \"max_ns\": {}",
+            self.median.as_nanos(),
+            self.min.as_nanos(),
+            self.max.as_nanos()
+        )
+    }
+
+    /// The fields for these stats in a CSV row
+    fn csv(&self) -> String {
+        let Stats {
+            median, min, max, ..
+        } = self;
+        format!(
+            "{},{},{}",
+            median.as_nanos(),
+            min.as_nanos(),
+            max.as_nanos()
+        )
+    }
 }
 
-impl Display for TimedResult {
+/// With only one run there's nothing to summarize, so just show the duration
+impl Display for Stats {
     fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
-        let TimedResult {
-            day,
-            answer_one,
-            answer_two,
-            duration,
+        let Stats {
+            runs,
+            min,
+            median,
+            max,
         } = self;
-        writeln!(
-            f,
-            "************************************************************"
-        )?;
-        writeln!(f, "* Advent of Code: 2022, Day {day}")?;
-        writeln!(f, "*   Solution for...")?;
-        writeln!(f, "*     Part One: {answer_one}")?;
-        writeln!(f, "*     Part Two: {answer_two}")?;
-        writeln!(f, "* Run Time: {duration:?}")?;
+        match runs {
+            1 => write!(f, "{median:?}"),
+            _ => write!(f, "{median:?} (min {min:?}, max {max:?})"),
+        }
+    }
+}
+
+/// Quote a CSV field if it has anything in it that would break up the row
+fn csv_field(field: &str) -> String {
+    if field.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_string()
+    }
+}
+
+/// Solve the `parts` of a day `repeat` times, timing the parse and each part on
+/// their own. The answers are kept from the first run.
+fn run_day(puzzle: &dyn Puzzle, parts: &[Part], source: Source, repeat: u32) -> Result<RunResult> {
+    let day = puzzle.day();
+    let (answer_one, answer_two, first) = puzzle.run_timed(parts, source)?;
+    let mut timings = vec![first];
+    for _ in 1..repeat {
+        let (_, _, next) = puzzle.run_timed(parts, source)?;
+        timings.push(next);
+    }
+    Ok(RunResult {
+        day,
+        answer_one,
+        answer_two,
+        timings,
+        check_one: None,
+        check_two: None,
+    })
+}
+
+/// Shows a `RunResult` in an asterisk box along with how long each phase took
+struct TimedResult<'a>(&'a RunResult);
+
+impl Display for TimedResult<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        let result = self.0;
+        result.fmt_answers(f)?;
+        match result.timings.len() {
+            1 => writeln!(f, "* Run Time: {}", result.stats(Timings::total))?,
+            n => writeln!(
+                f,
+                "* Run Time (median of {n} runs): {}",
+                result.stats(Timings::total)
+            )?,
+        }
+        writeln!(f, "*   Parse Input: {}", result.stats(|t| t.parse))?;
+        if result.answer_one.is_some() {
+            writeln!(f, "*   Part One:    {}", result.stats(|t| t.part_one))?;
+        }
+        if result.answer_two.is_some() {
+            writeln!(f, "*   Part Two:    {}", result.stats(|t| t.part_two))?;
+        }
         writeln!(
             f,
             "************************************************************"
@@ -149,43 +789,46 @@ impl Display for TimedResult {
     }
 }
 
-fn run_timed(day: u8, timer: &Instant) -> TimedResult {
-    let run = match day {
-        1 => day01::run_both,
-        2 => day02::run_both,
-        3 => day03::run_both,
-        4 => day04::run_both,
-        5 => day05::run_both,
-        6 => day06::run_both,
-        7 => day07::run_both,
-        8 => day08::run_both,
-        9 => day09::run_both,
-        10 => day10::run_both,
-        11 => day11::run_both,
-        12 => day12::run_both,
-        13 => day13::run_both,
-        14 => day14::run_both,
-        15 => day15::run_both,
-        16 => day16::run_both,
-        17 => day17::run_both,
-        18 => day18::run_both,
-        19 => day19::run_both,
-        20 => day20::run_both,
-        21 => day21::run_both,
-        22 => day22::run_both,
-        23 => day23::run_both,
-        24 => day24::run_both,
-        25 => day25::run_both,
-        _ => panic!("There's no day {day} on the Advent Calendar!"),
-    };
+#[cfg(test)]
+mod test {
+    use super::*;
 
-    let start = timer.elapsed();
-    let (answer_one, answer_two) = run();
-    let duration = timer.elapsed() - start;
-    TimedResult {
-        day,
-        answer_one,
-        answer_two,
-        duration,
+    #[test]
+    fn check_day_set() {
+        let DaySet(days) = "1-5,12,20-25".parse().unwrap();
+        let expected: BTreeSet<u8> = (1..=5).chain([12]).chain(20..=25).collect();
+        assert_eq!(days, expected);
+
+        let DaySet(days) = "3, 1-2,3".parse().unwrap();
+        assert_eq!(days, BTreeSet::from([1, 2, 3]));
+
+        assert!("0-3".parse::<DaySet>().is_err());
+        assert!("5-2".parse::<DaySet>().is_err());
+        assert!("26".parse::<DaySet>().is_err());
+        assert!("1,,2".parse::<DaySet>().is_err());
+    }
+
+    #[test]
+    fn check_fetch_args() {
+        let args = Args::try_parse_from(["aoc2022bin", "fetch", "--day", "5", "--user", "me"]);
+        let args = args.unwrap();
+        let Some(Command::Fetch(options)) = &args.command else {
+            panic!("Should be the fetch command!");
+        };
+        assert_eq!(options.day, 5);
+        assert_eq!(args.account.user.as_deref(), Some("me"));
+
+        assert!(Args::try_parse_from(["aoc2022bin", "fetch", "--day", "26"]).is_err());
+        assert!(Args::try_parse_from(["aoc2022bin", "fetch"]).is_err());
+        assert!(Args::try_parse_from(["aoc2022bin", "visualize", "--day", "23"]).is_ok());
+        assert!(Args::try_parse_from(["aoc2022bin", "visualize", "--day", "1"]).is_err());
+        assert!(Args::try_parse_from(["aoc2022bin", "-a", "--fetched", "-i", "x"]).is_err());
+
+        let args = Args::try_parse_from(["aoc2022bin", "submit", "-d", "1", "-p", "2"]);
+        let Some(Command::Submit(options)) = args.unwrap().command else {
+            panic!("Should be the submit command!");
+        };
+        assert_eq!((options.day, options.part), (1, 2));
+        assert!(Args::try_parse_from(["aoc2022bin", "submit", "-d", "1", "-p", "3"]).is_err());
     }
 }
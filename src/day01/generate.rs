@@ -0,0 +1,44 @@
+use crate::day01::Input;
+use crate::random::Rng;
+
+/// The most calories `print` will put in a single snack
+const MAX_SNACK: u64 = 10_000;
+
+/// Generate the total calories carried by each of `size` Elves. Each Elf carries
+/// up to fifteen snacks, like in the puzzle input.
+pub fn generate(rng: &mut Rng, size: usize) -> Input {
+    (0..size)
+        .map(|_| {
+            (0..rng.range(1..=15))
+                .map(|_| rng.range(1_000..=MAX_SNACK))
+                .sum()
+        })
+        .collect()
+}
+
+/// Print the Elves' snacks the way they're listed in the input. Only the totals are
+/// kept when parsing, so each Elf's total gets split up into snacks of
+/// `MAX_SNACK` calories, plus whatever's left over.
+pub fn print(input: &Input) -> String {
+    let mut elves = Vec::new();
+    for total in input {
+        let mut snacks = vec![MAX_SNACK.to_string(); (total / MAX_SNACK) as usize];
+        if total % MAX_SNACK > 0 {
+            snacks.push((total % MAX_SNACK).to_string());
+        }
+        elves.push(snacks.join("\n") + "\n");
+    }
+    elves.join("\n")
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::day01::input::parse;
+    use crate::random::check_round_trip;
+
+    #[test]
+    fn check_generated_input() {
+        check_round_trip(generate, print, parse);
+    }
+}
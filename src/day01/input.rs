@@ -1,30 +1,22 @@
+use crate::day01::stream::elf_totals;
 use crate::day01::Input;
-
-const INPUT: &str = include_str!("../../input/01/input.txt");
-
-/// Read and parse the input file
-pub fn read() -> Input {
-    // Iterate over each empty-line separated "chunk",
-    // parsing each chunk into a total calorie count
-    // per Elf, returning the list of total calories per
-    // Elf.
-    INPUT
-        .trim()
-        .split("\n\n")
-        .map(try_parse_elf_calories)
-        .collect::<Result<_, _>>()
-        .expect("Could not parse input!")
+use crate::Result;
+
+pub(crate) const INPUT: &str = include_str!("../../input/01/input.txt");
+
+/// Parse the text of an input file. This is the same as reading it one line at a
+/// time with `stream::elf_totals`, just with all the totals collected, so both ways
+/// of reading the input agree on what's an error (like an Elf carrying more calories
+/// than fit in a u64).
+pub fn parse(input: &str) -> Result<Input> {
+    elf_totals(input.as_bytes())
+        .map(|total| total.map(|total| total.calories))
+        .collect()
 }
 
-/// Parse a "chunk" of lines representing an individual
-/// Elf's snacks into the total calories for that Elf.
-fn try_parse_elf_calories(value: &str) -> Result<u32, std::num::ParseIntError> {
-    // Iterate over each line, convert it to a u32, and sum the results
-    let mut total = 0;
-    for line in value.lines() {
-        total += line.parse::<u32>()?;
-    }
-    Ok(total)
+/// Read and parse the bundled input file
+pub fn read() -> Input {
+    parse(INPUT).expect("Could not parse input!")
 }
 
 #[cfg(test)]
@@ -42,5 +34,10 @@ mod test {
         assert_eq!(last, 48165);
 
         assert_eq!(input.len(), 264);
+
+        // Totals that would overflow a u32 are fine, but not ones that overflow a u64
+        let big = parse("4294967295\n1\n\n5").unwrap();
+        assert_eq!(big, [4294967296, 5]);
+        assert!(parse(&format!("{}\n1\n", u64::MAX)).is_err());
     }
 }
@@ -1,41 +1,74 @@
+pub mod generate;
 pub mod input;
 pub mod part1;
 pub mod part2;
+pub mod stream;
 
-use crate::{Output, Part};
+use crate::{Output, Part, Result, Solution, Source};
 
 // Input for today is a vector of numbers, each of which represents the
 // total number of calories carried by each Elf. In my first stab at this,
 // I used a wrapper around a `Vec<Vec<u32>>`, keeping track of each item.
 // Turns out, all I needed was the total calories per elf!
-pub type Input = Vec<u32>;
+pub type Input = Vec<u64>;
 
-pub fn run(part: Part) -> Output {
-    let input = input::read();
-    match part {
-        Part::One => part1::solve(&input),
-        Part::Two => part2::solve(&input),
+pub struct Day01;
+
+impl Solution for Day01 {
+    const DAY: u8 = 1;
+    const INPUT: &'static str = input::INPUT;
+    type Input<'a> = Input;
+
+    fn parse(text: &str) -> Result<Self::Input<'_>> {
+        input::parse(text)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Result<Output> {
+        part1::solve(input)
     }
-}
 
-pub fn run_both() -> (Output, Output) {
-    let input = input::read();
-    (part1::solve(&input), part2::solve(&input))
+    fn part2(input: &Self::Input<'_>) -> Result<Output> {
+        part2::solve(input)
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::examples;
 
     #[test]
     fn check_answer_one() {
-        let result = run(Part::One);
+        let result = Day01::run(Part::One, Source::Bundled).unwrap();
         assert_eq!(result, 69795);
     }
 
     #[test]
     fn check_answer_two() {
-        let result = run(Part::Two);
+        let result = Day01::run(Part::Two, Source::Bundled).unwrap();
         assert_eq!(result, 208437);
     }
+
+    #[test]
+    fn check_runtime_input() {
+        let path = std::path::Path::new("input/01/input.txt");
+        let result = Day01::run(Part::One, Source::Path(path)).unwrap();
+        assert_eq!(result, 69795);
+
+        let result = Day01::run(Part::One, Source::Text("100\n200\n\n250\n")).unwrap();
+        assert_eq!(result, 300);
+
+        let missing = std::path::Path::new("input/01/missing.txt");
+        assert!(Day01::run(Part::One, Source::Path(missing)).is_err());
+    }
+
+    #[test]
+    fn check_example_one() {
+        examples::check::<Day01>(Part::One);
+    }
+
+    #[test]
+    fn check_example_two() {
+        examples::check::<Day01>(Part::Two);
+    }
 }
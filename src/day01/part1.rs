@@ -1,8 +1,8 @@
-use crate::day01::{Input, Output};
+use crate::day01::{Input, Output, Result};
 
 /// Solve Day 01, Part 01
-pub fn solve(input: &Input) -> Output {
+pub fn solve(input: &Input) -> Result<Output> {
     // Get the maximum calorie count for an Elf
-    // and return it as an Output::U32.
-    input.iter().copied().max().unwrap_or_default().into()
+    // and return it as an Output::U64.
+    Ok(input.iter().copied().max().unwrap_or_default().into())
 }
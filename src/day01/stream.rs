@@ -0,0 +1,222 @@
+use crate::{Error, Result};
+use std::cmp::{Ordering, Reverse};
+use std::collections::BinaryHeap;
+use std::io::BufRead;
+
+/// How many calories one Elf is carrying, and which Elf that is. Elves are counted
+/// from zero in the order they're listed, same as the indices of `input::parse`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ElfTotal {
+    pub elf: usize,
+    pub calories: u64,
+}
+
+/// Elves carrying more calories come first, and when two Elves are carrying the
+/// same amount, the one listed first wins. That way the top K doesn't depend on
+/// anything but the input.
+impl Ord for ElfTotal {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.calories
+            .cmp(&other.calories)
+            .then(other.elf.cmp(&self.elf))
+    }
+}
+
+impl PartialOrd for ElfTotal {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// Reads the Elves' snacks from any `BufRead` one line at a time, handing back each
+/// Elf's total as soon as their list of snacks ends. Only the current line and the
+/// current Elf's running total are kept around, so the inventory can be as long as
+/// you like. Stops after the first error.
+pub struct ElfTotals<R> {
+    reader: R,
+    line: String,
+    line_number: usize,
+    elf: usize,
+    total: Option<u64>,
+    done: bool,
+}
+
+/// Total up each Elf's snacks as they're read from `reader`
+pub fn elf_totals<R: BufRead>(reader: R) -> ElfTotals<R> {
+    ElfTotals {
+        reader,
+        line: String::new(),
+        line_number: 0,
+        elf: 0,
+        total: None,
+        done: false,
+    }
+}
+
+impl<R: BufRead> ElfTotals<R> {
+    /// Wrap up the current Elf's total, if they had any snacks
+    fn finish_elf(&mut self) -> Option<ElfTotal> {
+        let calories = self.total.take()?;
+        let elf = self.elf;
+        self.elf += 1;
+        Some(ElfTotal { elf, calories })
+    }
+
+    /// A parse error on the current line. Every error here is about the whole
+    /// line, so it's always at the first column.
+    fn error(&self, reason: impl ToString) -> Error {
+        Error::Parse {
+            line: self.line_number,
+            column: 1,
+            reason: reason.to_string(),
+        }
+    }
+}
+
+impl<R: BufRead> Iterator for ElfTotals<R> {
+    type Item = Result<ElfTotal>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while !self.done {
+            self.line.clear();
+            match self.reader.read_line(&mut self.line) {
+                // The end of the inventory is the end of the last Elf's list, too
+                Ok(0) => {
+                    self.done = true;
+                    return self.finish_elf().map(Ok);
+                }
+                Ok(_) => self.line_number += 1,
+                Err(error) => {
+                    self.done = true;
+                    return Some(Err(Error::Read(error)));
+                }
+            }
+
+            // A blank line ends the current Elf's list. Extra blank lines (or
+            // blank lines before the first Elf) don't count as Elves.
+            let line = self.line.trim_end();
+            if line.is_empty() {
+                match self.finish_elf() {
+                    Some(total) => return Some(Ok(total)),
+                    None => continue,
+                }
+            }
+
+            // Otherwise, add the snack to the current Elf's total, if it fits
+            let elf = self.elf;
+            let snack = line.parse::<u64>();
+            let total = snack.map(|snack| self.total.unwrap_or_default().checked_add(snack));
+            match total {
+                Ok(Some(total)) => self.total = Some(total),
+                Ok(None) => {
+                    self.done = true;
+                    let reason = format!("Elf {elf} is carrying more calories than fit in a u64");
+                    return Some(Err(self.error(reason)));
+                }
+                Err(error) => {
+                    self.done = true;
+                    return Some(Err(self.error(error)));
+                }
+            }
+        }
+        None
+    }
+}
+
+/// Keeps the `k` Elves carrying the most calories out of all the ones it's shown,
+/// without holding on to any of the others.
+#[derive(Debug, Clone)]
+pub struct TopK {
+    k: usize,
+    heap: BinaryHeap<Reverse<ElfTotal>>,
+}
+
+impl TopK {
+    pub fn new(k: usize) -> Self {
+        let heap = BinaryHeap::with_capacity(k + 1);
+        TopK { k, heap }
+    }
+
+    /// Consider another Elf. If there are more than `k` Elves now, the one with
+    /// the fewest calories gets dropped.
+    pub fn push(&mut self, total: ElfTotal) {
+        self.heap.push(Reverse(total));
+        if self.heap.len() > self.k {
+            self.heap.pop();
+        }
+    }
+
+    /// The Elves on top, most calories first
+    pub fn into_sorted_vec(self) -> Vec<ElfTotal> {
+        // Sorting the `Reverse`s in ascending order puts the biggest totals first
+        let sorted = self.heap.into_sorted_vec();
+        sorted.into_iter().map(|Reverse(total)| total).collect()
+    }
+
+    /// All the calories carried by the Elves on top, or `None` if that's too many
+    /// to fit in a u64
+    pub fn calories(&self) -> Option<u64> {
+        self.heap
+            .iter()
+            .try_fold(0u64, |sum, Reverse(total)| sum.checked_add(total.calories))
+    }
+}
+
+/// Read the whole inventory from `reader` and return the `k` Elves carrying the
+/// most calories, most calories first
+pub fn top_k(reader: impl BufRead, k: usize) -> Result<Vec<ElfTotal>> {
+    let mut top = TopK::new(k);
+    for total in elf_totals(reader) {
+        top.push(total?);
+    }
+    Ok(top.into_sorted_vec())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::day01::input::{read, INPUT};
+
+    #[test]
+    fn check_streaming_totals() {
+        // Streaming the bundled input gives the same totals as parsing it
+        let streamed: Vec<_> = elf_totals(INPUT.as_bytes())
+            .map(|total| total.unwrap().calories)
+            .collect();
+        assert_eq!(streamed, read());
+
+        // Extra blank lines don't make extra Elves, and ties go to the first Elf
+        let text = "\n100\n200\n\n\n300\n\n50\n";
+        let top = top_k(text.as_bytes(), 2).unwrap();
+        let expected = [
+            ElfTotal {
+                elf: 0,
+                calories: 300,
+            },
+            ElfTotal {
+                elf: 1,
+                calories: 300,
+            },
+        ];
+        assert_eq!(top, expected);
+        assert_eq!(top_k(text.as_bytes(), 5).unwrap().len(), 3);
+        assert!(top_k(text.as_bytes(), 0).unwrap().is_empty());
+    }
+
+    #[test]
+    fn check_streaming_errors() {
+        let text = format!("1\n\n{}\n1\n", u64::MAX);
+        let Err(Error::Parse { line, reason, .. }) = top_k(text.as_bytes(), 3) else {
+            panic!("Should overflow!");
+        };
+        assert_eq!(line, 4);
+        assert!(reason.contains("Elf 1"));
+
+        let mut totals = elf_totals("1\n2\nthree\n\n4\n".as_bytes());
+        let Some(Err(Error::Parse { line, .. })) = totals.next() else {
+            panic!("Should fail to parse!");
+        };
+        assert_eq!(line, 3);
+        assert!(totals.next().is_none());
+    }
+}
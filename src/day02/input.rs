@@ -1,31 +1,53 @@
+use crate::day02::shared::{Column, Rules};
 use crate::day02::Input;
+use crate::error::finish;
+use crate::Result;
 use nom::{
-    character::complete::{anychar, space1},
-    error::Error as NomError,
+    character::complete::{char, one_of},
     sequence::separated_pair,
-    Finish, IResult,
+    IResult,
 };
 
-const INPUT: &str = include_str!("../../input/02/input.txt");
+pub(crate) const INPUT: &str = include_str!("../../input/02/input.txt");
 
 /// Attempts to parse a line from the INPUT
-fn parse_line(line: &str) -> Result<(char, char), NomError<&str>> {
-    // parses lines that contain a character, one or more spaces,
-    // then another character
-    let (_, char_pair) = separated_pair(anychar, space1, anychar)(line).finish()?;
-    Ok(char_pair)
+fn parse_line<'a>(line: &'a str, first: &str, second: &str) -> IResult<&'a str, (char, char)> {
+    // parses lines that contain one of the opponent's letters, a space, then one
+    // of the player's letters
+    separated_pair(one_of(first), char(' '), one_of(second))(line)
+}
+
+/// Parse the text of an input file, for plain old Rock, Paper, Scissors
+pub fn parse(input: &str) -> Result<Input> {
+    parse_for(input, &Rules::rock_paper_scissors())
+}
+
+/// Parse the text of an input file, for a game played with `rules`. The letters
+/// each column can hold come from the rules, and the second column can hold any
+/// letter that works for either way of reading it. Whether it works for the way
+/// it's actually read gets checked when the rounds are played.
+pub fn parse_for(input: &str, rules: &Rules) -> Result<Input> {
+    let first = rules.opponent_letters();
+    let second = rules.player_letters(Column::Shape) + &rules.player_letters(Column::Outcome);
+
+    // Parse the lines into (char, char) values and return the resulting
+    // list, failing on the first line that can't be parsed (or that has anything
+    // left over after the second column).
+    input
+        .lines()
+        .map(|line| finish(input, parse_line(line, &first, &second)))
+        .collect()
 }
 
 /// Parse the INPUT
 pub fn read() -> Input {
-    // Parse the lines into (char, char) values and return the resulting
-    // list. Ignores any lines that fail to parse.
-    INPUT.lines().flat_map(parse_line).collect()
+    parse(INPUT).expect("Could not parse input!")
 }
 
 #[cfg(test)]
 mod test {
     use super::*;
+    use crate::Error;
 
     #[test]
     fn check_input_parsing() {
@@ -41,4 +63,34 @@ mod test {
 
         assert_eq!(input.len(), 2500);
     }
+
+    #[test]
+    fn check_letters() {
+        // Letters that aren't in the game are a parse error, right where they are
+        let Err(Error::Parse { line, column, .. }) = parse("A X\nB Y\nD Z\n") else {
+            panic!("Should fail to parse!");
+        };
+        assert_eq!((line, column), (3, 1));
+        let Err(Error::Parse { line, column, .. }) = parse("A X\nB W\n") else {
+            panic!("Should fail to parse!");
+        };
+        assert_eq!((line, column), (2, 3));
+
+        // Nothing else can be on the line
+        let Err(Error::Parse { line, column, .. }) = parse("A X\nA XYZ\n") else {
+            panic!("Should fail to parse!");
+        };
+        assert_eq!((line, column), (2, 4));
+        assert!(parse("A  X").is_err());
+
+        // With more shapes, there are more letters, and the second column goes on
+        // from 'A' after 'Z'
+        let rules = Rules::rock_paper_scissors_lizard_spock();
+        assert_eq!(
+            parse_for("E B\nA X", &rules).unwrap(),
+            [('E', 'B'), ('A', 'X')]
+        );
+        assert!(parse_for("F X", &rules).is_err());
+        assert!(parse_for("A C", &rules).is_err());
+    }
 }
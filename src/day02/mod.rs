@@ -3,37 +3,55 @@ pub mod part1;
 pub mod part2;
 pub mod shared;
 
-use crate::{Output, Part};
+use crate::{Output, Part, Result, Solution, Source};
 
 // Today's input is a list of character pairs
 pub type Input = Vec<(char, char)>;
 
-pub fn run(part: Part) -> Output {
-    let input = input::read();
-    match part {
-        Part::One => part1::solve(&input),
-        Part::Two => part2::solve(&input),
+pub struct Day02;
+
+impl Solution for Day02 {
+    const DAY: u8 = 2;
+    const INPUT: &'static str = input::INPUT;
+    type Input<'a> = Input;
+
+    fn parse(text: &str) -> Result<Self::Input<'_>> {
+        input::parse(text)
     }
-}
 
-pub fn run_both() -> (Output, Output) {
-    let input = input::read();
-    (part1::solve(&input), part2::solve(&input))
+    fn part1(input: &Self::Input<'_>) -> Result<Output> {
+        part1::solve(input)
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Result<Output> {
+        part2::solve(input)
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::examples;
 
     #[test]
     fn check_answer_one() {
-        let result = run(Part::One);
+        let result = Day02::run(Part::One, Source::Bundled).unwrap();
         assert_eq!(result, 10994);
     }
 
     #[test]
     fn check_answer_two() {
-        let result = run(Part::Two);
+        let result = Day02::run(Part::Two, Source::Bundled).unwrap();
         assert_eq!(result, 12526);
     }
+
+    #[test]
+    fn check_example_one() {
+        examples::check::<Day02>(Part::One);
+    }
+
+    #[test]
+    fn check_example_two() {
+        examples::check::<Day02>(Part::Two);
+    }
 }
@@ -1,47 +1,332 @@
-//! Common structs and functions for both parts. `Shape` and `Outcome`
-//! are used in both parts and the functionality for converting them into
-//! points is unchanged between the parts.
-
-/// Represents a 'shape' in a game of Rock, Paper, Scissors
-#[derive(Clone, Copy)]
-pub enum Shape {
-    Rock,
-    Paper,
-    Scissors,
+//! Common structs and functions for both parts. Both parts play the same game with
+//! the same scores, they just read the strategy guide differently, so the game
+//! itself lives here as a set of `Rules`. The rules don't have to be plain old Rock,
+//! Paper, Scissors, either: any number of shapes with any "beats" relationship and
+//! any scores will do, so Rock, Paper, Scissors, Lizard, Spock works too.
+use crate::{Error, Result};
+use std::cmp::Reverse;
+
+/// A shape is just its position in the list of shapes for the `Rules`
+pub type Shape = usize;
+
+/// Represents the outcome of a game, from the perspective of you, the player
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Outcome {
+    Lose,
+    Draw,
+    Win,
+}
+
+impl Outcome {
+    /// Every outcome, in the order the strategy guide lists them
+    pub const ALL: [Outcome; 3] = [Outcome::Lose, Outcome::Draw, Outcome::Win];
+}
+
+/// What the second column of the strategy guide means. In part one, we thought it
+/// was the shape to play. In part two, the Elf explained it's how the round needs
+/// to end.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Column {
+    Shape,
+    Outcome,
 }
 
-/// Convert a `Shape` into it's score value
-impl From<Shape> for u32 {
-    fn from(shape: Shape) -> Self {
-        match shape {
-            Shape::Rock => 1,
-            Shape::Paper => 2,
-            Shape::Scissors => 3,
+/// The rules of the game: which shapes there are, which shapes beat which, and how
+/// many points each shape and each outcome is worth. Two shapes that are the same
+/// or where neither beats the other are a draw.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Rules {
+    names: Vec<String>,
+    beats: Vec<Vec<bool>>,
+    shape_scores: Vec<u32>,
+    outcome_scores: [u32; 3],
+}
+
+impl Rules {
+    /// Rules for the given shapes, where nothing beats anything (yet). Shapes are
+    /// worth 1, 2, 3, etc. points in the order they're given, and losing, drawing,
+    /// and winning are worth 0, 3, and 6 points, like in the puzzle. Panics for more
+    /// than 26 shapes, since the strategy guide runs out of letters for them.
+    pub fn new(names: &[&str]) -> Self {
+        let count = names.len();
+        assert!(count <= 26, "There are only letters for 26 shapes!");
+        Rules {
+            names: names.iter().map(|name| name.to_string()).collect(),
+            beats: vec![vec![false; count]; count],
+            shape_scores: (1..=count as u32).collect(),
+            outcome_scores: [0, 3, 6],
+        }
+    }
+
+    /// Rules where every shape beats the half of the other shapes that come an odd
+    /// number of places before it (wrapping around), so every shape beats as many
+    /// shapes as it loses to. With three shapes, that's Paper beats Rock, Scissors
+    /// beats Paper, and Rock beats Scissors. Panics for an even number of shapes,
+    /// since there's no way to make that fair.
+    pub fn balanced(names: &[&str]) -> Self {
+        let count = names.len();
+        assert!(
+            count % 2 == 1,
+            "Balanced rules need an odd number of shapes!"
+        );
+        let mut rules = Rules::new(names);
+        for winner in 0..count {
+            for places_back in (1..count).step_by(2) {
+                rules = rules.with_beats(winner, (winner + count - places_back) % count);
+            }
+        }
+        rules
+    }
+
+    /// Good old Rock, Paper, Scissors
+    pub fn rock_paper_scissors() -> Self {
+        Rules::balanced(&["Rock", "Paper", "Scissors"])
+    }
+
+    /// Rock, Paper, Scissors, Lizard, Spock. Scissors cuts Paper, Paper covers
+    /// Rock, Rock crushes Lizard, Lizard poisons Spock, Spock smashes Scissors,
+    /// and so on.
+    pub fn rock_paper_scissors_lizard_spock() -> Self {
+        Rules::balanced(&["Rock", "Paper", "Scissors", "Spock", "Lizard"])
+    }
+
+    /// Make `winner` beat `loser` (and not the other way around)
+    pub fn with_beats(mut self, winner: Shape, loser: Shape) -> Self {
+        self.beats[winner][loser] = winner != loser;
+        self.beats[loser][winner] = false;
+        self
+    }
+
+    /// Change how many points each shape is worth, in the same order as the shapes.
+    /// Panics if there isn't a score for every shape.
+    pub fn with_shape_scores(mut self, scores: &[u32]) -> Self {
+        assert_eq!(scores.len(), self.names.len(), "Every shape needs a score!");
+        self.shape_scores = scores.to_vec();
+        self
+    }
+
+    /// Change how many points losing, drawing, and winning are worth
+    pub fn with_outcome_scores(mut self, lose: u32, draw: u32, win: u32) -> Self {
+        self.outcome_scores = [lose, draw, win];
+        self
+    }
+
+    /// The number of shapes in the game
+    pub fn shapes(&self) -> usize {
+        self.names.len()
+    }
+
+    /// The name of a shape
+    pub fn name(&self, shape: Shape) -> &str {
+        &self.names[shape]
+    }
+
+    /// Look up a shape by its name
+    pub fn shape(&self, name: &str) -> Option<Shape> {
+        self.names.iter().position(|known| known == name)
+    }
+
+    /// How a round ends for you, if you play `player` and the opponent plays
+    /// `opponent`
+    pub fn outcome(&self, player: Shape, opponent: Shape) -> Outcome {
+        match (self.beats[player][opponent], self.beats[opponent][player]) {
+            (true, _) => Outcome::Win,
+            (_, true) => Outcome::Lose,
+            _ => Outcome::Draw,
         }
     }
+
+    /// The points you get for a round
+    pub fn score(&self, player: Shape, opponent: Shape) -> u32 {
+        let outcome = self.outcome(player, opponent);
+        self.shape_scores[player] + self.outcome_scores[outcome as usize]
+    }
+
+    /// The shape to play against `opponent` to get the `outcome` you want. If more
+    /// than one shape would do it, pick the one worth the most points (and the
+    /// first one listed, if they're worth the same).
+    pub fn shape_for(&self, opponent: Shape, outcome: Outcome) -> Option<Shape> {
+        (0..self.shapes())
+            .filter(|player| self.outcome(*player, opponent) == outcome)
+            .min_by_key(|player| (Reverse(self.shape_scores[*player]), *player))
+    }
+
+    /// Work out which shapes you and the opponent play in the round on `line` of the
+    /// strategy guide. The first column is the opponent's shape, starting from 'A'.
+    /// The second column is either your shape, starting from 'X', or whether you
+    /// need to lose, draw, or win ('X', 'Y', or 'Z'). A letter that doesn't mean
+    /// anything in these rules is a parse error, right where it is on the line.
+    pub fn round(
+        &self,
+        line: usize,
+        (first, second): (char, char),
+        column: Column,
+    ) -> Result<(Shape, Shape)> {
+        let opponent = self.opponent(line, first)?;
+        let player = match column {
+            Column::Shape => self.letter(second, 'X'),
+            Column::Outcome => (second as usize)
+                .checked_sub('X' as usize)
+                .and_then(|idx| Outcome::ALL.get(idx))
+                .and_then(|outcome| self.shape_for(opponent, *outcome)),
+        };
+        let player = player.ok_or_else(|| Error::Parse {
+            line,
+            column: 3,
+            reason: format!("can't tell what to play for '{first} {second}'"),
+        })?;
+        Ok((player, opponent))
+    }
+
+    /// The letters that can show up in the first column of the strategy guide, one
+    /// for each shape, starting from 'A'
+    pub fn opponent_letters(&self) -> String {
+        letters('A', self.shapes())
+    }
+
+    /// The letters that can show up in the second column of the strategy guide,
+    /// starting from 'X'. That's one for each shape when it's the shape to play,
+    /// or one for each outcome when it's how the round needs to end.
+    pub fn player_letters(&self, column: Column) -> String {
+        match column {
+            Column::Shape => letters('X', self.shapes()),
+            Column::Outcome => letters('X', Outcome::ALL.len()),
+        }
+    }
+
+    /// The opponent's shape from the first column of the strategy guide
+    fn opponent(&self, line: usize, first: char) -> Result<Shape> {
+        self.letter(first, 'A').ok_or_else(|| Error::Parse {
+            line,
+            column: 1,
+            reason: format!("'{first}' isn't a shape in these rules"),
+        })
+    }
+
+    /// The shape for a `letter` in a column where the first shape is `from`, going
+    /// on from 'A' after 'Z' like `letters` does
+    fn letter(&self, letter: char, from: char) -> Option<Shape> {
+        let position = |ch: char| {
+            let offset = (ch as usize).checked_sub('A' as usize);
+            offset.filter(|_| ch.is_ascii_uppercase())
+        };
+        let shape = (position(letter)? + 26 - position(from)?) % 26;
+        (shape < self.shapes()).then_some(shape)
+    }
+
+    /// Follow the whole strategy guide and add up the points. The rounds are on
+    /// the lines of the guide in order, starting from line one.
+    pub fn play(&self, guide: &[(char, char)], column: Column) -> Result<u32> {
+        guide.iter().zip(1..).try_fold(0, |total, (pair, line)| {
+            let (player, opponent) = self.round(line, *pair, column)?;
+            Ok(total + self.score(player, opponent))
+        })
+    }
+
+    /// How often the opponent plays each shape in the strategy guide, as a fraction
+    /// of all the rounds
+    pub fn opponent_distribution(&self, guide: &[(char, char)]) -> Result<Vec<f64>> {
+        let mut counts = vec![0.0; self.shapes()];
+        for ((first, _), line) in guide.iter().zip(1..) {
+            counts[self.opponent(line, *first)?] += 1.0;
+        }
+        let rounds = guide.len().max(1) as f64;
+        Ok(counts.into_iter().map(|count| count / rounds).collect())
+    }
+
+    /// The points you can expect per round playing `player` every time, against an
+    /// opponent who plays each shape as often as `distribution` says
+    pub fn expected_score(&self, player: Shape, distribution: &[f64]) -> f64 {
+        distribution
+            .iter()
+            .enumerate()
+            .map(|(opponent, chance)| chance * f64::from(self.score(player, opponent)))
+            .sum()
+    }
+
+    /// The best shape to play every round against an opponent who plays each shape
+    /// as often as `distribution` says, along with the points you can expect per
+    /// round. Against an opponent who doesn't change it up, there's never anything
+    /// to gain by mixing up your own shapes, so one shape is the whole strategy.
+    pub fn best_response(&self, distribution: &[f64]) -> (Shape, f64) {
+        (0..self.shapes())
+            .map(|player| (player, self.expected_score(player, distribution)))
+            .fold((0, f64::MIN), |best, next| match next.1 > best.1 {
+                true => next,
+                false => best,
+            })
+    }
 }
 
-/// Represents the outcome of a game of Rock, Paper, Scissors, from the
-/// perspective of you, the player. Each variant encapsulates the shape
-/// you made to achieve that outcome.
-pub enum Outcome {
-    Win(Shape),
-    Draw(Shape),
-    Lose(Shape),
+/// `count` capital letters in a row, starting from `from` and going on from 'A'
+/// after 'Z', so the second column can have as many shapes as the first
+fn letters(from: char, count: usize) -> String {
+    let start = from as u8 - b'A';
+    (0..count.min(26) as u8)
+        .map(|offset| char::from(b'A' + (start + offset) % 26))
+        .collect()
 }
 
-impl Outcome {
-    /// Calculate the score from a given outcome
-    pub fn score(&self) -> u32 {
-        match self {
-            // 6 points for winning + the points for your shape
-            Outcome::Win(t) => 6 + u32::from(*t),
+#[cfg(test)]
+mod test {
+    use super::*;
 
-            // 3 points for a draw + the points for your shape
-            Outcome::Draw(t) => 3 + u32::from(*t),
+    #[test]
+    fn check_rules() {
+        let rules = Rules::rock_paper_scissors();
+        let [rock, paper, scissors] =
+            ["Rock", "Paper", "Scissors"].map(|n| rules.shape(n).unwrap());
+        assert_eq!(rules.outcome(paper, rock), Outcome::Win);
+        assert_eq!(rules.outcome(rock, paper), Outcome::Lose);
+        assert_eq!(rules.outcome(scissors, scissors), Outcome::Draw);
+        assert_eq!(rules.score(rock, scissors), 7);
+        assert_eq!(rules.shape_for(scissors, Outcome::Lose), Some(paper));
 
-            // 0 points for losing + the points for your shape
-            Outcome::Lose(t) => u32::from(*t),
+        // Every shape beats exactly two others, and every pair has a winner
+        let rules = Rules::rock_paper_scissors_lizard_spock();
+        let [lizard, spock] = ["Lizard", "Spock"].map(|n| rules.shape(n).unwrap());
+        assert_eq!(rules.outcome(lizard, spock), Outcome::Win);
+        assert_eq!(rules.outcome(spock, scissors), Outcome::Win);
+        assert_eq!(rules.outcome(rock, lizard), Outcome::Win);
+        for player in 0..rules.shapes() {
+            let wins = (0..rules.shapes())
+                .filter(|opponent| rules.outcome(player, *opponent) == Outcome::Win);
+            assert_eq!(wins.count(), 2);
         }
     }
+
+    #[test]
+    fn check_strategy() {
+        let guide = [('A', 'Y'), ('B', 'X'), ('C', 'Z')];
+        let rules = Rules::rock_paper_scissors();
+        assert_eq!(rules.play(&guide, Column::Shape).unwrap(), 15);
+        assert_eq!(rules.play(&guide, Column::Outcome).unwrap(), 12);
+        let Err(Error::Parse { line, column, .. }) = rules.play(&[('A', 'X'), ('D', 'X')], Column::Shape) else {
+            panic!("Should not play!");
+        };
+        assert_eq!((line, column), (2, 1));
+
+        // An outcome the rules can't give is just as bad as a letter they don't have.
+        // Nothing beats anything here, so there's no way to lose the second round.
+        let draws_only = Rules::new(&["Rock", "Paper", "Scissors"]);
+        let Err(Error::Parse { line, column, .. }) = draws_only.play(&guide, Column::Outcome)
+        else {
+            panic!("Should not play!");
+        };
+        assert_eq!((line, column), (2, 3));
+
+        // Against an opponent who mostly plays Rock, play Paper
+        let distribution = [0.5, 0.25, 0.25];
+        let (best, expected) = rules.best_response(&distribution);
+        assert_eq!(rules.name(best), "Paper");
+        assert_eq!(expected, 0.5 * 8.0 + 0.25 * 5.0 + 0.25 * 2.0);
+
+        // With a big enough bonus for Scissors, it's worth losing to Rock
+        let rules = rules.with_shape_scores(&[1, 2, 10]);
+        assert_eq!(rules.name(rules.best_response(&distribution).0), "Scissors");
+
+        let mut counts = rules.opponent_distribution(&guide).unwrap();
+        counts.iter_mut().for_each(|chance| *chance *= 3.0);
+        assert_eq!(counts, [1.0, 1.0, 1.0]);
+    }
 }
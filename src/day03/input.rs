@@ -1,24 +1,98 @@
-use crate::day03::Input;
-use anyhow::{anyhow, bail, Error, Result};
+use crate::day03::{Input, Item, ItemSet};
+use crate::Error as ParseError;
+use std::fmt::{Display, Formatter, Result as FmtResult};
 
-const INPUT: &str = include_str!("../../input/03/input.txt");
-
-// Today we'll do a bit of math converting ASCII characters to numbers.
-// These constants are used in that math. For references, ASCII 'a' corresponds
-// to a value of 97, and ASCII 'A' corresponds to a value of 65.
-const LOWERCASE_OFFSET: u32 = 96; // 'a' -> 97 -  1 (priority of 'a') = 96
-const CAPITAL_OFFSET: u32 = 38; // 'A' -> 65 - 26 (priority of 'A') = 38
+pub(crate) const INPUT: &str = include_str!("../../input/03/input.txt");
 
 /// A `Rucksack` represents a pack carried by an elf with two different,
 /// separated sets of `Item`s.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct Rucksack(pub ItemSet, pub ItemSet);
 
-/// Attempt to convert a line from the input into a `Rucksack`
+/// Why a line of the input isn't a valid `Rucksack`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Problem {
+    /// There's nothing on the line, so no compartments either
+    EmptyLine,
+
+    /// The line has this many items, which can't be split evenly into two
+    /// compartments
+    OddLength(usize),
+
+    /// This character isn't an item (only letters are)
+    InvalidItem(char),
+}
+
+impl Display for Problem {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match self {
+            Problem::EmptyLine => write!(f, "the line is empty"),
+            Problem::OddLength(len) => write!(
+                f,
+                "{len} items can't be split evenly between two compartments"
+            ),
+            Problem::InvalidItem(found) => write!(f, "{found:?} isn't an item"),
+        }
+    }
+}
+
+/// A line of the input that couldn't be turned into a `Rucksack`, and where on
+/// the line the problem is. Lines start at one, and the offset is the number of
+/// characters from the start of the line (so the first character is at zero).
+/// For a line with an odd number of items, that's the last item, the one that
+/// doesn't fit.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BadLine {
+    pub line: usize,
+    pub offset: usize,
+    pub problem: Problem,
+}
+
+impl Display for BadLine {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        let BadLine {
+            line,
+            offset,
+            problem,
+        } = self;
+        write!(f, "line {line}, offset {offset}: {problem}")
+    }
+}
+
+/// Report a bad line the same way as every other parsing error
+impl From<BadLine> for ParseError {
+    fn from(bad_line: BadLine) -> Self {
+        ParseError::Parse {
+            line: bad_line.line,
+            column: bad_line.offset + 1,
+            reason: bad_line.problem.to_string(),
+        }
+    }
+}
+
+/// Attempt to convert a line from the input into a `Rucksack`. Fails with the
+/// offset of the problem on the line, and what the problem is.
 impl TryFrom<&str> for Rucksack {
-    type Error = Error;
+    type Error = (usize, Problem);
 
     fn try_from(s: &str) -> Result<Self, Self::Error> {
+        if s.is_empty() {
+            return Err((0, Problem::EmptyLine));
+        }
+
+        // Every character needs to be an item. Once we know they are, we know
+        // they're all ASCII, so the number of characters is the number of bytes.
+        if let Some((offset, found)) = s
+            .chars()
+            .enumerate()
+            .find_map(|(offset, ch)| Item::try_from(ch).err().map(|found| (offset, found)))
+        {
+            return Err((offset, Problem::InvalidItem(found)));
+        }
+        if s.len() % 2 == 1 {
+            return Err((s.len() - 1, Problem::OddLength(s.len())));
+        }
+
         // Because the input lines contain two equal-length strings of
         // characters representing `Item`s, we need the length of each
         // half.
@@ -27,87 +101,54 @@ impl TryFrom<&str> for Rucksack {
         // Split the line into two equal-length strings
         let (str1, str2) = s.split_at(compartment_len);
 
-        // Convert each string into a set of `Item`s
-        let compartment1 = ItemSet::try_from(str1)?;
-        let compartment2 = ItemSet::try_from(str2)?;
+        // Convert each string into a set of `Item`s. We already checked every
+        // character, so these can't fail.
+        let compartment1 = str1.chars().flat_map(Item::try_from).collect();
+        let compartment2 = str2.chars().flat_map(Item::try_from).collect();
 
         // Return a `Rucksack` with two sets of items
         Ok(Rucksack(compartment1, compartment2))
     }
 }
 
-/// An `ItemSet` represents the unique items held in each compartment of a
-/// `Rucksack`. The goal is to provide functionality equivalent to a
-/// `HashSet<Item>` (at least as much as we need) without the overhead
-/// of an actual `HashSet`. This is accomplished by assigning each type of
-/// `Item` to a particular bit in the inner `u64`.
-#[derive(Default, Debug, Clone, Copy, PartialEq, Eq)]
-pub struct ItemSet(pub u64);
-
-impl ItemSet {
-    /// Insert an `Item` into an `ItemSet`.
-    fn insert(&mut self, item: Item) {
-        // Set the bit in the `ItemSet` that corresponds to the particular type
-        // of `Item`.
-        self.0 |= item.0;
-    }
+/// Try to convert every line of the input into a `Rucksack`, in order
+fn rucksacks(input: &str) -> impl Iterator<Item = Result<Rucksack, BadLine>> + '_ {
+    input.lines().enumerate().map(|(idx, line)| {
+        Rucksack::try_from(line).map_err(|(offset, problem)| BadLine {
+            line: idx + 1,
+            offset,
+            problem,
+        })
+    })
 }
 
-/// Attempt to convert a string slice into a set of `Item`s.
-impl TryFrom<&str> for ItemSet {
-    type Error = Error;
-
-    fn try_from(value: &str) -> Result<Self, Self::Error> {
-        // Start with an empty item set
-        let mut item_set = ItemSet::default();
-
-        // Convert each character in the input string into an `Item` and
-        // insert each `Item` into the set
-        for ch in value.chars() {
-            let item = Item::try_from(ch)?;
-            item_set.insert(item);
-        }
-
-        Ok(item_set)
-    }
+/// Parse the text of an input file
+pub fn parse(input: &str) -> crate::Result<Input> {
+    // Attempt to convert each line into a `Rucksack` and return the
+    // list, failing on the first line that isn't a valid `Rucksack`.
+    rucksacks(input)
+        .map(|rucksack| rucksack.map_err(ParseError::from))
+        .collect()
 }
 
-/// An `Item` represents a particular item carried by the elves. Because the puzzle
-/// specifies that there are only 52 possible item types, each item can be uniquely
-/// represented by a single set bit in a `u64` with 6 extra bits to spare. We'll set
-/// bits in order of increasing priority, starting with 'a' at 2^1. This way, the
-/// number of trailing zeros will be equal to priority. So, 'a' will be stored as
-/// 2u64 with 1 trailing zero, and 'A' will be stored as 134217728u64 with 27 trailing
-/// zeros.
-///
-/// Just for excessive clarity, 134217728u64 is represented in bits as:
-///   0b00000000000000000000000000000000001000000000000000000000000000
-#[derive(Debug, Clone, Copy)]
-pub struct Item(pub u64);
-
-/// Attempt to convert a single character into an `Item`
-impl TryFrom<char> for Item {
-    type Error = Error;
-
-    #[rustfmt::skip]
-    fn try_from(value: char) -> Result<Self, Self::Error> {
-        // Error if trying to make an `Item` out of any character that's not a letter
-        if !value.is_alphabetic() { bail!("Cannot convert {value} to an Item!") }
-
-        // Offset for ASCII range starts and priority offset.
-        let offset = if value > 'Z' { LOWERCASE_OFFSET } else { CAPITAL_OFFSET };
-        let priority = value as u32 - offset;
-        let set_bit = 1 << priority; // One bit, shifted left by priority
-
-        Ok(Item(set_bit))
+/// Parse the text of an input file, but skip the lines that aren't valid
+/// `Rucksack`s instead of giving up on the whole file. The lines that were
+/// skipped come back alongside the rucksacks, so they can be reported.
+pub fn parse_lenient(input: &str) -> (Input, Vec<BadLine>) {
+    let mut parsed = Vec::new();
+    let mut bad_lines = Vec::new();
+    for rucksack in rucksacks(input) {
+        match rucksack {
+            Ok(rucksack) => parsed.push(rucksack),
+            Err(bad_line) => bad_lines.push(bad_line),
+        }
     }
+    (parsed, bad_lines)
 }
 
 /// Read and parse the input
 pub fn read() -> Input {
-    // Attempt to convert each line into a `Rucksack` and return the
-    // list of successful results.
-    INPUT.lines().flat_map(Rucksack::try_from).collect()
+    parse(INPUT).expect("Could not parse input!")
 }
 
 #[cfg(test)]
@@ -127,4 +168,31 @@ mod test {
         let last_expected = Rucksack::try_from("vjWPWjWPPPWgwmfCrNvTvZ").unwrap();
         assert_eq!(last, last_expected);
     }
+
+    #[test]
+    fn check_bad_lines() {
+        let input = "abAB\n\nabc\nab1B\nzZ\n";
+        let Err(ParseError::Parse { line, column, reason }) = parse(input) else {
+            panic!("Should fail to parse!");
+        };
+        assert_eq!((line, column), (2, 1));
+        assert_eq!(reason, "the line is empty");
+
+        let (rucksacks, bad_lines) = parse_lenient(input);
+        assert_eq!(rucksacks.len(), 2);
+        let found: Vec<_> = bad_lines
+            .iter()
+            .map(|bad| (bad.line, bad.offset, bad.problem))
+            .collect();
+        let expected = [
+            (2, 0, Problem::EmptyLine),
+            (3, 2, Problem::OddLength(3)),
+            (4, 2, Problem::InvalidItem('1')),
+        ];
+        assert_eq!(found, expected);
+        assert_eq!(
+            bad_lines[2].to_string(),
+            "line 4, offset 2: '1' isn't an item"
+        );
+    }
 }
@@ -0,0 +1,303 @@
+use crate::{Error, Result};
+
+// Today we'll do a bit of math converting ASCII characters to numbers.
+// These constants are used in that math. For references, ASCII 'a' corresponds
+// to a value of 97, and ASCII 'A' corresponds to a value of 65.
+const LOWERCASE_OFFSET: u32 = 96; // 'a' -> 97 -  1 (priority of 'a') = 96
+const CAPITAL_OFFSET: u32 = 38; // 'A' -> 65 - 26 (priority of 'A') = 38
+
+/// An `Item` represents a particular item carried by the elves. Because the puzzle
+/// specifies that there are only 52 possible item types, each item can be uniquely
+/// represented by a single set bit in a `u64` with 6 extra bits to spare. We'll set
+/// bits in order of increasing priority, starting with 'a' at 2^1. This way, the
+/// number of trailing zeros will be equal to priority. So, 'a' will be stored as
+/// 2u64 with 1 trailing zero, and 'A' will be stored as 134217728u64 with 27 trailing
+/// zeros.
+///
+/// Just for excessive clarity, 134217728u64 is represented in bits as:
+///   0b00000000000000000000000000000000001000000000000000000000000000
+///
+/// The bits are private, so the only `Item`s there are come from letters or
+/// priorities, and each one always has exactly one of the 52 bits set.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Item(u64);
+
+impl Item {
+    /// Calculate the priority of the `Item`. Recall each `Item` is represented
+    /// by a single bit shifted left by priority, so priority is just the
+    /// number of trailing zeros.
+    pub fn priority(&self) -> u32 {
+        self.0.trailing_zeros()
+    }
+
+    /// The `Item` with a given priority, if there is one (1 through 52)
+    pub fn from_priority(priority: u32) -> Option<Item> {
+        // Checking first, since shifting by 64 or more would overflow
+        match (1..=52).contains(&priority) {
+            true => Some(Item(1 << priority)),
+            false => None,
+        }
+    }
+
+    /// The letter for this `Item`, the reverse of converting a letter into an `Item`
+    pub fn letter(&self) -> char {
+        let priority = self.priority();
+        let offset = if priority > 26 {
+            CAPITAL_OFFSET
+        } else {
+            LOWERCASE_OFFSET
+        };
+        char::from_u32(priority + offset).unwrap_or('?')
+    }
+}
+
+/// Attempt to convert a single character into an `Item`. Fails with the character
+/// if it isn't one, since only whoever read it knows where it came from.
+impl TryFrom<char> for Item {
+    type Error = char;
+
+    fn try_from(value: char) -> Result<Self, Self::Error> {
+        // Error if trying to make an `Item` out of any character that's not a letter.
+        if !value.is_ascii_alphabetic() {
+            return Err(value);
+        }
+
+        // Offset for ASCII range starts and priority offset.
+        let offset = if value > 'Z' {
+            LOWERCASE_OFFSET
+        } else {
+            CAPITAL_OFFSET
+        };
+        let priority = value as u32 - offset;
+        let set_bit = 1 << priority; // One bit, shifted left by priority
+
+        Ok(Item(set_bit))
+    }
+}
+
+/// Attempt to convert an `ItemSet` into a single `Item`.
+/// Fails and returns an Error if the `ItemSet` doesn't contain exactly one item.
+impl TryFrom<ItemSet> for Item {
+    type Error = Error;
+
+    fn try_from(set: ItemSet) -> Result<Self, Self::Error> {
+        let letters = || set.iter().map(|item| item.letter()).collect::<String>();
+        match set.len() {
+            1 => Ok(Item(set.0)),
+            0 => Err(Error::no_solution("There aren't any items in common!")),
+            _ => Err(Error::no_solution(format!(
+                "There's more than one item in common ({})!",
+                letters()
+            ))),
+        }
+    }
+}
+
+/// An `ItemSet` represents a set of unique items, like the ones held in each
+/// compartment of a `Rucksack`. The goal is to provide functionality equivalent
+/// to a `HashSet<Item>` without the overhead of an actual `HashSet`. This is
+/// accomplished by assigning each type of `Item` to a particular bit in the inner
+/// `u64`, which makes every set operation a single bitwise operation.
+///
+/// Like with `Item`, the bits are private, so only the 52 bits that stand for items
+/// can ever be set.
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ItemSet(u64);
+
+/// The bits that stand for items, 2^1 ('a') through 2^52 ('Z')
+const ITEM_BITS: u64 = ((1 << 53) - 1) & !1;
+
+impl ItemSet {
+    /// An empty `ItemSet`
+    pub fn new() -> Self {
+        ItemSet(0)
+    }
+
+    /// The `ItemSet` with these bits set, if every one of them stands for an item
+    pub fn from_bits(bits: u64) -> Option<Self> {
+        (bits & !ITEM_BITS == 0).then_some(ItemSet(bits))
+    }
+
+    /// The bits for the items in the set
+    pub fn bits(&self) -> u64 {
+        self.0
+    }
+
+    /// Insert an `Item` into an `ItemSet`. Returns whether the item is new.
+    pub fn insert(&mut self, item: Item) -> bool {
+        // Set the bit in the `ItemSet` that corresponds to the particular type
+        // of `Item`.
+        let added = !self.contains(item);
+        self.0 |= item.0;
+        added
+    }
+
+    /// Remove an `Item` from an `ItemSet`. Returns whether it was there to remove.
+    pub fn remove(&mut self, item: Item) -> bool {
+        let removed = self.contains(item);
+        self.0 &= !item.0;
+        removed
+    }
+
+    /// Check whether the `ItemSet` holds an `Item`
+    pub fn contains(&self, item: Item) -> bool {
+        self.0 & item.0 != 0
+    }
+
+    /// The items in either set. Just a bitwise _or_ on the underlying integers.
+    pub fn union(&self, other: ItemSet) -> ItemSet {
+        ItemSet(self.0 | other.0)
+    }
+
+    /// Construct an `ItemSet` that contains only items in common between
+    /// `self` and `other`. Just a bitwise _and_ on the underlying integers.
+    pub fn intersection(&self, other: ItemSet) -> ItemSet {
+        ItemSet(self.0 & other.0)
+    }
+
+    /// The items in `self` that aren't in `other`
+    pub fn difference(&self, other: ItemSet) -> ItemSet {
+        ItemSet(self.0 & !other.0)
+    }
+
+    /// The items every one of `sets` has in common, or an empty set if there
+    /// aren't any sets
+    pub fn common(sets: impl IntoIterator<Item = ItemSet>) -> ItemSet {
+        sets.into_iter()
+            .reduce(|acc, set| acc.intersection(set))
+            .unwrap_or_default()
+    }
+
+    /// The number of items in the set
+    pub fn len(&self) -> usize {
+        self.0.count_ones() as usize
+    }
+
+    /// Check whether the set has no items
+    pub fn is_empty(&self) -> bool {
+        self.0 == 0
+    }
+
+    /// The items in the set, from lowest priority to highest
+    pub fn iter(&self) -> Items {
+        Items(self.0)
+    }
+}
+
+/// Attempt to convert a string slice into a set of `Item`s.
+impl TryFrom<&str> for ItemSet {
+    type Error = Error;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        // Convert each character in the input string into an `Item` and
+        // collect them all into a set, pointing at the first one that isn't
+        value
+            .char_indices()
+            .map(|(idx, ch)| {
+                Item::try_from(ch).map_err(|ch| {
+                    Error::parse(
+                        value,
+                        &value[idx..],
+                        format!("Cannot convert {ch:?} to an Item!"),
+                    )
+                })
+            })
+            .collect()
+    }
+}
+
+impl FromIterator<Item> for ItemSet {
+    fn from_iter<T: IntoIterator<Item = Item>>(iter: T) -> Self {
+        let mut item_set = ItemSet::new();
+        item_set.extend(iter);
+        item_set
+    }
+}
+
+impl Extend<Item> for ItemSet {
+    fn extend<T: IntoIterator<Item = Item>>(&mut self, iter: T) {
+        for item in iter {
+            self.insert(item);
+        }
+    }
+}
+
+impl IntoIterator for ItemSet {
+    type Item = Item;
+    type IntoIter = Items;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+/// Iterates over the items in an `ItemSet` from lowest priority to highest, by
+/// peeling off the lowest set bit each time.
+#[derive(Debug, Clone)]
+pub struct Items(u64);
+
+impl Iterator for Items {
+    type Item = Item;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.0 == 0 {
+            return None;
+        }
+        let lowest = self.0 & self.0.wrapping_neg();
+        self.0 &= !lowest;
+        Some(Item(lowest))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.0.count_ones() as usize;
+        (len, Some(len))
+    }
+}
+
+impl ExactSizeIterator for Items {}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn check_item_set() {
+        let mut set = ItemSet::try_from("abcXYZa").unwrap();
+        assert_eq!(set.len(), 6);
+        assert!(set.contains(Item::try_from('X').unwrap()));
+        assert!(!set.insert(Item::try_from('a').unwrap()));
+        assert!(set.remove(Item::try_from('b').unwrap()));
+        assert!(!set.remove(Item::try_from('b').unwrap()));
+
+        let letters: String = set.iter().map(|item| item.letter()).collect();
+        assert_eq!(letters, "acXYZ");
+        let priorities: Vec<_> = set.iter().map(|item| item.priority()).collect();
+        assert_eq!(priorities, [1, 3, 50, 51, 52]);
+        assert_eq!(Item::from_priority(50), Item::try_from('X').ok());
+        assert_eq!(Item::try_from('1'), Err('1'));
+
+        let other: ItemSet = "aZq".chars().flat_map(Item::try_from).collect();
+        let letters = |set: ItemSet| set.iter().map(|item| item.letter()).collect::<String>();
+        assert_eq!(letters(set.union(other)), "acqXYZ");
+        assert_eq!(letters(set.intersection(other)), "aZ");
+        assert_eq!(letters(set.difference(other)), "cXY");
+        assert_eq!(
+            letters(ItemSet::common([
+                set,
+                other,
+                ItemSet::try_from("Z").unwrap()
+            ])),
+            "Z"
+        );
+        assert!(ItemSet::common([]).is_empty());
+        let Err(Error::Parse { column, .. }) = ItemSet::try_from("ab1") else {
+            panic!("Should not convert!");
+        };
+        assert_eq!(column, 3);
+        assert!(Item::try_from(ItemSet::try_from("ab").unwrap()).is_err());
+
+        assert_eq!(ItemSet::from_bits(set.bits()), Some(set));
+        assert_eq!(ItemSet::from_bits(1), None);
+        assert_eq!(ItemSet::from_bits(1 << 53), None);
+    }
+}
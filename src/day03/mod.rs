@@ -1,38 +1,77 @@
 pub mod input;
+pub mod items;
 pub mod part1;
 pub mod part2;
 
-use crate::{Output, Part};
-use input::{Item, ItemSet, Rucksack};
+use crate::{Output, Part, Result, Solution, Source};
+use input::Rucksack;
+use items::{Item, ItemSet};
 
 pub type Input = Vec<Rucksack>;
 
-pub fn run(part: Part) -> Output {
-    let input = input::read();
-    match part {
-        Part::One => part1::solve(&input),
-        Part::Two => part2::solve(&input),
+pub struct Day03;
+
+impl Solution for Day03 {
+    const DAY: u8 = 3;
+    const INPUT: &'static str = input::INPUT;
+    type Input<'a> = Input;
+
+    fn parse(text: &str) -> Result<Self::Input<'_>> {
+        input::parse(text)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Result<Output> {
+        part1::solve(input)
     }
-}
 
-pub fn run_both() -> (Output, Output) {
-    let input = input::read();
-    (part1::solve(&input), part2::solve(&input))
+    fn part2(input: &Self::Input<'_>) -> Result<Output> {
+        part2::solve(input)
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::{examples, Error};
 
     #[test]
     fn check_answer_one() {
-        let result = run(Part::One);
+        let result = Day03::run(Part::One, Source::Bundled).unwrap();
         assert_eq!(result, 8153);
     }
 
     #[test]
     fn check_answer_two() {
-        let result = run(Part::Two);
+        let result = Day03::run(Part::Two, Source::Bundled).unwrap();
         assert_eq!(result, 2342);
     }
+
+    #[test]
+    fn check_example_one() {
+        examples::check::<Day03>(Part::One);
+    }
+
+    #[test]
+    fn check_example_two() {
+        examples::check::<Day03>(Part::Two);
+    }
+
+    #[test]
+    fn check_no_common_item() {
+        // Both parts fail the same way when there isn't exactly one item in common
+        let input = Day03::parse("vJrwpWtwJgWrhcsFMMfFFhFp\nabAB\nabAB\n").unwrap();
+        assert!(matches!(Day03::part1(&input), Err(Error::NoSolution(_))));
+        assert!(matches!(Day03::part2(&input), Err(Error::NoSolution(_))));
+    }
+
+    #[test]
+    fn check_partial_group() {
+        let input = input::read();
+        let Err(Error::NoSolution(reason)) = part2::common_items(&input[..299], 3) else {
+            panic!("Should not split into groups!");
+        };
+        assert!(reason.starts_with("Rucksacks 298 through 299 are left over"));
+        assert!(part2::common_items(&input, 0).is_err());
+        assert_eq!(part2::common_items(&input, 5).unwrap().count(), 60);
+    }
 }
@@ -1,38 +1,54 @@
 use crate::day03::{Input, Item, ItemSet, Output, Rucksack};
-use anyhow::Result;
-use itertools::Itertools;
+use crate::{Error, Result};
 
-pub fn solve(input: &Input) -> Output {
+pub fn solve(input: &Input) -> Result<Output> {
     let mut total = 0; // The sum of badge priorities
 
-    // Convert each `Rucksack` to an `ItemSet` consisting of all its unique
-    // `Item`s, as an iterator.
-    let rucksack_sets = input.iter().map(|r| r.all_items());
-
-    // For each chunk of three rucksack_sets in sequence...
-    for chunk in &rucksack_sets.chunks(3) {
-        // Produce an `ItemSet` that is the intersection of all three sets
-        // in the chunk. If the chunk is empty, we get an empty `ItemSet` back.
-        let intersect = chunk
-            .reduce(|acc, i| acc.intersect(i))
-            .unwrap_or(ItemSet(0));
-
-        // Attempt to convert the `ItemSet` into a single Item. Panic if
-        // the chunk contains more than one common item. The puzzle text
+    // For each group of three rucksacks in sequence, get the `ItemSet` of the
+    // items all three have in common. If a group is empty, we get an empty
+    // `ItemSet` back.
+    for common in common_items(input, 3)? {
+        // Attempt to convert the `ItemSet` into a single Item. Fail if
+        // the group has more than one common item. The puzzle text
         // assures us this won't happen.
-        let badge = Item::try_from(intersect).expect("{intersect:?} contains multiple Items!");
+        let badge = Item::try_from(common)?;
 
         // Add the priority of the badge to the total
         total += badge.priority();
     }
 
-    total.into()
+    Ok(total.into())
+}
+
+/// Split the rucksacks into groups of `group_size` in sequence and produce the
+/// items every rucksack in each group has in common, one `ItemSet` per group. Fails
+/// if the rucksacks don't split evenly into groups, naming the ones left over.
+pub fn common_items(
+    rucksacks: &[Rucksack],
+    group_size: usize,
+) -> Result<impl Iterator<Item = ItemSet> + '_> {
+    if group_size == 0 {
+        return Err(Error::no_solution("A group needs at least one rucksack!"));
+    }
+    let (count, leftover) = (rucksacks.len(), rucksacks.len() % group_size);
+    if leftover > 0 {
+        let first = count - leftover + 1;
+        let which = match leftover {
+            1 => format!("Rucksack {first} is"),
+            _ => format!("Rucksacks {first} through {count} are"),
+        };
+        let reason = format!("{which} left over, without a full group of {group_size}!");
+        return Err(Error::no_solution(reason));
+    }
+
+    let groups = rucksacks.chunks(group_size);
+    Ok(groups.map(|group| ItemSet::common(group.iter().map(Rucksack::all_items))))
 }
 
 impl Rucksack {
     /// Return an `ItemSet` comprised of the items in both compartments of
     /// the `Rucksack`. Recall that this is a set, so duplicates aren't counted.
-    fn all_items(&self) -> ItemSet {
-        ItemSet(self.0 .0 | self.1 .0)
+    pub fn all_items(&self) -> ItemSet {
+        self.0.union(self.1)
     }
 }
@@ -0,0 +1,43 @@
+use crate::day04::{AssignmentRange, AssignmentRangePair, Input};
+use crate::random::Rng;
+
+/// Generate `size` random pairs of assignments. Sections can be numbered anywhere
+/// from 0 to 255, and some assignments are only one section long.
+pub fn generate(rng: &mut Rng, size: usize) -> Input {
+    fn range(rng: &mut Rng) -> AssignmentRange {
+        let start = rng.range(0..=u8::MAX);
+        let end = if rng.chance(0.1) {
+            start
+        } else {
+            rng.range(start..=u8::MAX)
+        };
+        AssignmentRange { start, end }
+    }
+    (0..size)
+        .map(|_| AssignmentRangePair(range(rng), range(rng)))
+        .collect()
+}
+
+/// Print the pairs of assignments the way they're written in the input
+pub fn print(input: &Input) -> String {
+    let mut out = String::new();
+    for AssignmentRangePair(first, second) in input {
+        out += &format!(
+            "{}-{},{}-{}\n",
+            first.start, first.end, second.start, second.end
+        );
+    }
+    out
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::day04::input::parse;
+    use crate::random::check_round_trip;
+
+    #[test]
+    fn check_generated_input() {
+        check_round_trip(generate, print, parse);
+    }
+}
@@ -1,19 +1,11 @@
 use crate::day04::Input;
+use crate::interval::Interval;
+use crate::{Error, Result};
+use nom::error::ErrorKind;
 
-/// Represents a range of beach assignments for a particular elf
-#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
-pub struct AssignmentRange {
-    pub start: u8,
-    pub stop: u8,
-}
-
-/// Convert a pair of integers to an `AssignmentRange`
-impl From<(u8, u8)> for AssignmentRange {
-    fn from(value: (u8, u8)) -> Self {
-        let (start, stop) = value;
-        AssignmentRange { start, stop }
-    }
-}
+/// Represents a range of beach assignments for a particular elf, from the first
+/// section to the last one (both included)
+pub type AssignmentRange = Interval<u8>;
 
 /// Represents a pair of elf beach cleaning assignment ranges
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd)]
@@ -38,8 +30,12 @@ impl From<(AssignmentRange, AssignmentRange)> for AssignmentRangePair {
 mod arp_parser {
     use super::{AssignmentRange, AssignmentRangePair};
     use nom::{
-        bytes::complete::tag, character::complete::u8, combinator::into, error::Error as NomError,
-        sequence::separated_pair, Finish, IResult,
+        bytes::complete::tag,
+        character::complete::u8,
+        combinator::{into, verify},
+        error::Error as NomError,
+        sequence::separated_pair,
+        Finish, IResult,
     };
 
     /// Nom parser for "12-22" -> (12u8, 22u8)
@@ -47,14 +43,16 @@ mod arp_parser {
         separated_pair(u8, tag("-"), u8)(s)
     }
 
-    /// Nom parser for "12-22" -> AssignmentRange { start: 12, stop: 22 }
+    /// Nom parser for "12-22" -> AssignmentRange { start: 12, end: 22 }. A range
+    /// that ends before it starts, like "22-12", fails with `ErrorKind::Verify`
+    /// instead of turning into an empty range.
     fn range(s: &str) -> IResult<&str, AssignmentRange> {
-        into(number_pair)(s)
+        into(verify(number_pair, |(start, end)| start <= end))(s)
     }
 
     /// Nom parser for "12-22,18-24" -> AssignmentRangePair(
-    ///    AssignmentRange { start: 12, stop: 22 },
-    ///    AssignmentRange { start: 18, stop: 24 },
+    ///    AssignmentRange { start: 12, end: 22 },
+    ///    AssignmentRange { start: 18, end: 24 },
     /// )
     pub fn parse(s: &str) -> Result<AssignmentRangePair, NomError<&str>> {
         let pair_parser = separated_pair(range, tag(","), range);
@@ -65,13 +63,33 @@ mod arp_parser {
 }
 
 // Keep the input file as a compile time constant string slice
-const INPUT: &str = include_str!("../../input/04/input.txt");
+pub(crate) const INPUT: &str = include_str!("../../input/04/input.txt");
+
+/// Parse the text of an input file by parsing each line into an `AssignmentRangePair`,
+/// failing on the first line that returns an Error.
+pub fn parse(input: &str) -> Result<Input> {
+    input
+        .lines()
+        .map(|line| arp_parser::parse(line).map_err(|e| parse_error(input, e)))
+        .collect()
+}
 
-/// Read the input by parsing each line into an `AssignmentRangePair` and discarding
-/// any lines that return an Error. We'll check in the tests to make sure every line
+/// Turn a nom error into a parse error, with a better reason for backwards ranges
+fn parse_error(input: &str, error: nom::error::Error<&str>) -> Error {
+    match error.code {
+        ErrorKind::Verify => {
+            let range = error.input.split(',').next().unwrap_or_default();
+            let reason = format!("the range {range} ends before it starts");
+            Error::parse(input, error.input, reason)
+        }
+        _ => Error::nom(input, error),
+    }
+}
+
+/// Read the bundled input. We'll check in the tests to make sure every line
 /// is parsed.
 pub fn read() -> Input {
-    INPUT.lines().flat_map(arp_parser::parse).collect()
+    parse(INPUT).expect("Could not parse input!")
 }
 
 #[cfg(test)]
@@ -97,4 +115,15 @@ mod test {
         ));
         assert_eq!(last, last_expected);
     }
+
+    #[test]
+    fn check_backwards_ranges() {
+        let Err(Error::Parse { line, column, reason }) = parse("2-4,6-8\n1-1,5-2\n") else {
+            panic!("Should fail to parse!");
+        };
+        assert_eq!((line, column), (2, 5));
+        assert!(reason.contains("5-2"));
+        assert!(parse("5-2,1-1").is_err());
+        assert!(parse("3-3,1-9").is_ok());
+    }
 }
@@ -1,7 +1,7 @@
-use crate::day04::{AssignmentRange, AssignmentRangePair, Input, Output};
+use crate::day04::{AssignmentRangePair, Input, Output, Result};
 
 /// Solve Day 4, Part 1
-pub fn solve(input: &Input) -> Output {
+pub fn solve(input: &Input) -> Result<Output> {
     // For each assignment pair in the input, check to see if one assignment
     // complete contains another. Count only the pairs where this check returns
     // true.
@@ -10,19 +10,7 @@ pub fn solve(input: &Input) -> Output {
         .map(|pair| pair.full_containment())
         .filter(|x| *x)
         .count() as u32;
-    result.into()
-}
-
-/// Trait for determine whether one `AssignmentRange` completely contains another
-trait RangeContains {
-    fn contains(&self, other: &Self) -> bool;
-}
-
-impl RangeContains for AssignmentRange {
-    fn contains(&self, other: &Self) -> bool {
-        // Return true if one range is completely inside another
-        self.start <= other.start && self.stop >= other.stop
-    }
+    Ok(result.into())
 }
 
 impl AssignmentRangePair {
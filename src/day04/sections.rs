@@ -0,0 +1,72 @@
+//! Questions about the sections that the puzzle didn't ask, but that are easy
+//! enough to answer now that the assignments are `Interval`s.
+use crate::day04::{AssignmentRange, AssignmentRangePair, Input};
+use crate::interval::{Interval, IntervalSet};
+
+impl AssignmentRangePair {
+    /// The sections both Elves in the pair were assigned, if there are any
+    pub fn overlap(&self) -> Option<AssignmentRange> {
+        let AssignmentRangePair(first, second) = self;
+        first.intersection(second)
+    }
+}
+
+/// The number of sections both Elves in a pair were assigned, added up over
+/// every pair
+pub fn total_overlap(input: &Input) -> u64 {
+    input
+        .iter()
+        .flat_map(|pair| pair.overlap())
+        .map(|overlap| overlap.len())
+        .sum()
+}
+
+/// Every section that at least one Elf was assigned
+pub fn covered_sections(input: &Input) -> IntervalSet<u8> {
+    input
+        .iter()
+        .flat_map(|AssignmentRangePair(first, second)| [*first, *second])
+        .collect()
+}
+
+/// The sections between the lowest and highest ones anybody was assigned that
+/// nobody was assigned, so nobody cleans them
+pub fn unassigned_sections(input: &Input) -> IntervalSet<u8> {
+    let covered = covered_sections(input);
+    let (Some(first), Some(last)) = (covered.intervals().first(), covered.intervals().last()) else {
+        return IntervalSet::new();
+    };
+    covered.gaps(Interval::new(first.start, last.end))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::day04::input::parse;
+    use crate::examples;
+
+    #[test]
+    fn check_sections() {
+        let input = parse(&examples::text(4)).unwrap();
+
+        // 2-4,6-8 and 2-3,4-5 don't overlap, then 5-7,7-9 overlap on 7, 2-8,3-7
+        // on 3 through 7, 6-6,4-6 on 6, and 2-6,4-8 on 4 through 6
+        assert_eq!(total_overlap(&input), 1 + 5 + 1 + 3);
+
+        // Everything from 2 through 9 is covered by somebody
+        let covered: Vec<_> = covered_sections(&input).iter().copied().collect();
+        assert_eq!(covered, [Interval::new(2, 9)]);
+        assert!(unassigned_sections(&input).is_empty());
+
+        let input = parse("1-3,10-12\n5-5,20-30\n").unwrap();
+        let unassigned: Vec<_> = unassigned_sections(&input).iter().copied().collect();
+        assert_eq!(
+            unassigned,
+            [
+                Interval::new(4, 4),
+                Interval::new(6, 9),
+                Interval::new(13, 19)
+            ]
+        );
+    }
+}
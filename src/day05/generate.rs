@@ -0,0 +1,94 @@
+use crate::day05::input::CRATE_STACK_SIZE;
+use crate::day05::{CrateStack, CrateStacks, Input, Instruction};
+use crate::random::Rng;
+use std::cell::RefCell;
+
+/// Generate a random arrangement of crates followed by `size` random instructions
+/// for moving them around. There are anywhere from 2 to 15 stacks, so the stack
+/// numbers can run past 9, and every instruction moves crates that are actually
+/// there to be moved.
+pub fn generate(rng: &mut Rng, size: usize) -> Input {
+    // Scatter the crates across the stacks, without using more crates than will
+    // fit on one stack.
+    let width = rng.range(2..=15);
+    let mut stacks = vec![CrateStack::default(); width];
+    for _ in 0..rng.range(1..=CRATE_STACK_SIZE) {
+        let label = rng.range(b'A'..=b'Z') as char;
+        stacks[rng.range(0..=width - 1)].push(label);
+    }
+    let crate_stacks = CrateStacks(stacks.iter().copied().map(RefCell::new).collect());
+
+    // Keep track of how tall each stack is as the crates get moved, so we only
+    // ever move crates from stacks that have enough of them.
+    let mut heights: Vec<_> = stacks.iter().map(|stack| stack.height).collect();
+    let mut instructions = Vec::new();
+    for _ in 0..size {
+        let stocked: Vec<_> = (0..width).filter(|idx| heights[*idx] > 0).collect();
+        let origin = *rng.pick(&stocked);
+        let mut destination = rng.range(0..=width - 2);
+        if destination >= origin {
+            destination += 1;
+        }
+        let count = rng.range(1..=heights[origin]);
+        heights[origin] -= count;
+        heights[destination] += count;
+        instructions.push(Instruction {
+            count: count as u8,
+            origin: origin as u8 + 1,
+            destination: destination as u8 + 1,
+        });
+    }
+
+    (crate_stacks, instructions)
+}
+
+/// Print the stacks of crates and the instructions the way they're drawn in the
+/// input, including the row of stack numbers under the crates.
+pub fn print(input: &Input) -> String {
+    let (crate_stacks, instructions) = input;
+    let stacks: Vec<_> = crate_stacks.0.iter().map(|stack| *stack.borrow()).collect();
+    let tallest = stacks
+        .iter()
+        .map(|stack| stack.height)
+        .max()
+        .unwrap_or_default();
+
+    let mut lines = Vec::new();
+    for level in (0..tallest).rev() {
+        let row: Vec<_> = stacks
+            .iter()
+            .map(|stack| match level < stack.height {
+                true => format!("[{}]", stack.crates[level]),
+                false => String::from("   "),
+            })
+            .collect();
+        lines.push(row.join(" "));
+    }
+    let labels: Vec<_> = (1..=stacks.len()).map(|n| format!("{n:^3}")).collect();
+    lines.push(labels.join(" "));
+    lines.push(String::new());
+    for Instruction {
+        count,
+        origin,
+        destination,
+    } in instructions
+    {
+        lines.push(format!("move {count} from {origin} to {destination}"));
+    }
+
+    let mut out = lines.join("\n");
+    out.push('\n');
+    out
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::day05::input::parse;
+    use crate::random::check_round_trip;
+
+    #[test]
+    fn check_generated_input() {
+        check_round_trip(generate, print, parse);
+    }
+}
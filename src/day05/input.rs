@@ -1,13 +1,17 @@
 use crate::day05::Input;
+use crate::{Error, Result};
 use std::cell::RefCell;
 use std::ops::{Index, IndexMut};
 
+/// The most crates a single stack can hold
+pub const CRATE_STACK_SIZE: usize = 64;
+
 /// Represents a stack of crates. Each crate is represented by the character
 /// given in the input. A stack is a 64-length array (enough room for all the
 /// crates if need be) that keeps track of the current height of the stack.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct CrateStack {
-    pub crates: [char; 64],
+    pub crates: [char; CRATE_STACK_SIZE],
     pub height: usize,
 }
 
@@ -18,22 +22,22 @@ pub struct CrateStack {
 impl Default for CrateStack {
     fn default() -> Self {
         Self {
-            crates: ['.'; 64],
+            crates: ['.'; CRATE_STACK_SIZE],
             height: 0,
         }
     }
 }
 
-/// Represents the collected stacks of crates. We know we have 9 total stacks from
-/// the input, so this data structure can accommodate up to 9 stacks of crates.
+/// Represents the collected stacks of crates. My input has 9 stacks, but there's
+/// no reason there couldn't be more, so this holds as many stacks as the input has.
 /// Each stack of crates is wrapped in `RefCell` to facilitate moving crates directly
 /// from one stack to the other without needing a buffer in between. We'll use this
 /// functionality in part two.
-#[derive(Debug, Default, Clone)]
-pub struct CrateStacks(pub [RefCell<CrateStack>; 9]);
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct CrateStacks(pub Vec<RefCell<CrateStack>>);
 
 /// Implement indexing into the `CrateStacks` to get a particular crate. Since stack
-/// numbers are given 1-9, we can adjust the index here to allow for 1-indexing for
+/// numbers start at 1, we can adjust the index here to allow for 1-indexing for
 /// getting a particular stack of crates.
 impl Index<usize> for CrateStacks {
     type Output = RefCell<CrateStack>;
@@ -54,7 +58,6 @@ impl IndexMut<usize> for CrateStacks {
 /// Module to wrap nom parsers for crates and stacks of crates
 mod parse_crates {
     use super::*;
-    use anyhow::{anyhow, Result};
     use nom::{
         branch::alt,
         bytes::complete::tag,
@@ -62,7 +65,7 @@ mod parse_crates {
         combinator::{map, value},
         multi::separated_list1,
         sequence::delimited,
-        IResult,
+        Finish, IResult,
     };
 
     /// Nom parser to parse "[A]" -> 'A'
@@ -88,8 +91,17 @@ mod parse_crates {
     /// Parses the first section of the input into a `CrateStacks`, where each
     /// `CrateStack` contained includes the crates from each column of the input.
     pub fn parse(s: &str) -> Result<CrateStacks> {
-        let (_, rows) = crate_rows(s).map_err(|_| anyhow!("Cannot parse crate rows!"))?;
-        let mut stacks = CrateStacks::default();
+        let (_, rows) = crate_rows(s).finish().map_err(|e| Error::nom(s, e))?;
+        let width = rows.iter().map(|row| row.len()).max().unwrap_or_default();
+        let mut stacks = CrateStacks(vec![RefCell::default(); width]);
+
+        // Every crate in the input could end up on the same stack, so there can't
+        // be more crates than fit in one stack.
+        let crates = rows.iter().flatten().flatten().count();
+        if crates > CRATE_STACK_SIZE {
+            let reason = format!("there can't be more than {CRATE_STACK_SIZE} crates");
+            return Err(Error::parse(s, "", reason));
+        }
 
         for row in rows.iter().rev() {
             for (idx, maybe_crate) in row.iter().enumerate() {
@@ -128,13 +140,13 @@ impl From<(u8, u8, u8)> for Instruction {
 /// Module wrapping nom parsers for instructions
 mod parse_instructions {
     use super::*;
-    use anyhow::{anyhow, Result};
     use nom::{
         bytes::complete::take_while,
         character::complete::u8,
         combinator::into,
+        error::Error as NomError,
         sequence::{preceded, tuple},
-        IResult,
+        Finish, IResult,
     };
 
     /// Nom parser for a string of non-digit characters
@@ -156,32 +168,66 @@ mod parse_instructions {
     }
 
     /// Parse a line of instruction into an `Instruction`
-    pub fn parse(s: &str) -> Result<Instruction> {
-        let (_, result) =
-            instruction(s).map_err(|_| anyhow!("Cannot parse line to an Instruction!"))?;
+    pub fn parse(s: &str) -> Result<Instruction, NomError<&str>> {
+        let (_, result) = instruction(s).finish()?;
         Ok(result)
     }
 }
 
 /// Include the input as a constant string slice
-const INPUT: &str = include_str!("../../input/05/input.txt");
+pub(crate) const INPUT: &str = include_str!("../../input/05/input.txt");
 
-/// Read the input from the file (string) and parse it.
-pub fn read() -> Input {
+/// Parse the text of an input file.
+pub fn parse(input: &str) -> Result<Input> {
     // Split the input on the empty line
-    let (mut first_chunk, mut second_chunk) = INPUT.split_once("\n\n").unwrap();
+    let Some((first_chunk, second_chunk)) = input.split_once("\n\n") else {
+        let reason = "expected crates and instructions separated by an empty line";
+        return Err(Error::parse(input, "", reason));
+    };
 
     // Parse the first section into a `CrateStacks`
-    let crate_stacks = parse_crates::parse(first_chunk).expect("Failed to parse crate stacks!");
+    let crate_stacks = parse_crates::parse(first_chunk)?;
 
     // Parse the second section into a list of `Instruction`s
     let instructions = second_chunk
         .lines()
-        .flat_map(parse_instructions::parse)
-        .collect();
+        .map(|line| parse_instructions::parse(line).map_err(|e| Error::nom(input, e)))
+        .collect::<Result<Vec<_>>>()?;
+
+    // Both parts move the same number of crates on and off of each stack, so we
+    // can make sure every instruction can actually be carried out before either
+    // part tries it. Crates only ever get moved around, so no stack can ever get
+    // too tall.
+    let mut heights: Vec<_> = crate_stacks.0.iter().map(|s| s.borrow().height).collect();
+    for (line, instruction) in second_chunk.lines().zip(instructions.iter()) {
+        let Instruction {
+            count,
+            origin,
+            destination,
+        } = *instruction;
+        let stacks = heights.len();
+        for stack in [origin, destination] {
+            if !(1..=stacks).contains(&(stack as usize)) {
+                let reason = format!("there's no stack {stack}, only stacks 1 through {stacks}");
+                return Err(Error::parse(input, line, reason));
+            }
+        }
+        let Some(left) = heights[origin as usize - 1].checked_sub(count as usize) else {
+            let height = heights[origin as usize - 1];
+            let reason = format!("can't move {count} crates from stack {origin}, which only has {height} by then");
+            return Err(Error::parse(input, line, reason));
+        };
+        heights[origin as usize - 1] = left;
+        heights[destination as usize - 1] += count as usize;
+    }
 
     // Return the pair of parsed input sections
-    (crate_stacks, instructions)
+    Ok((crate_stacks, instructions))
+}
+
+/// Read the input from the file (string) and parse it.
+pub fn read() -> Input {
+    parse(INPUT).expect("Could not parse input!")
 }
 
 #[cfg(test)]
@@ -228,4 +274,20 @@ mod test {
         let last_instruction_expected = Instruction::from((1, 1, 9));
         assert_eq!(last_instruction, last_instruction_expected);
     }
+
+    #[test]
+    fn check_impossible_moves() {
+        let crates = "    [D]    \n[N] [C]    \n[Z] [M] [P]\n 1   2   3 \n\n";
+        let too_many = format!("{crates}move 1 from 2 to 1\nmove 5 from 1 to 2\n");
+        let Err(Error::Parse { line, reason, .. }) = parse(&too_many) else {
+            panic!("Should fail to parse!");
+        };
+        assert_eq!(line, 7);
+        assert!(reason.contains("only has 3"));
+
+        let missing = format!("{crates}move 1 from 4 to 1\n");
+        assert!(parse(&missing).is_err());
+        let missing = format!("{crates}move 1 from 1 to 0\n");
+        assert!(parse(&missing).is_err());
+    }
 }
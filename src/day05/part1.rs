@@ -1,7 +1,7 @@
-use crate::day05::{CrateStack, CrateStacks, Input, Instruction, Output};
+use crate::day05::{CrateStack, CrateStacks, Input, Instruction, Output, Result};
 
 /// Solve Day 05, Part 1
-pub fn solve(input: &Input) -> Output {
+pub fn solve(input: &Input) -> Result<Output> {
     // Split up the input into the stacks of crates and the instructions,
     // then clone the crate stacks struct so we can have a mutable copy.
     let (crate_stacks, instructions) = input;
@@ -10,7 +10,7 @@ pub fn solve(input: &Input) -> Output {
     // Execute each instruction on the `CrateStacks`, and return the
     // String resulting from the top crate in each stack
     instructions.iter().for_each(|i| crate_stacks.execute(i));
-    crate_stacks.message().into()
+    Ok(crate_stacks.message().into())
 }
 
 /// Implement methods for popping crates from the top of a stack and
@@ -41,7 +41,7 @@ impl CrateStacks {
     /// Convenience method to pop from one stack out of all the stacks. Which
     /// stack to pop from is given by `crate_idx` as indicated by an `Instruction`.
     pub fn pop_from(&mut self, crate_idx: u8) -> char {
-        if !(1..=9).contains(&crate_idx) {
+        if !(1..=self.0.len()).contains(&(crate_idx as usize)) {
             return '.';
         }
         self[(crate_idx as usize)].borrow_mut().pop()
@@ -50,19 +50,21 @@ impl CrateStacks {
     /// Convenience method to push to one stack out of all the stacks. Which
     /// stack to push to is given by `crate_idx` as indicated by an `Instruction`.
     pub fn push_to(&mut self, crate_idx: u8, ch: char) {
-        if !(1..=9).contains(&crate_idx) {
+        if !(1..=self.0.len()).contains(&(crate_idx as usize)) {
             return;
         }
         self[(crate_idx as usize)].borrow_mut().push(ch);
     }
 
     /// Fetches the top character (crate) from each stack and builds a String
-    /// out of them.
+    /// out of them. Empty stacks don't have a top crate, so they're skipped.
     pub fn message(&mut self) -> String {
         let mut out = String::new();
         for stack in self.0.iter_mut() {
             let top_crate = stack.borrow_mut().pop();
-            out.push(top_crate);
+            if top_crate != '.' {
+                out.push(top_crate);
+            }
         }
         out
     }
@@ -1,9 +1,9 @@
 use std::ops::RangeFrom;
 
-use crate::day05::{CrateStack, CrateStacks, Input, Instruction, Output};
+use crate::day05::{CrateStack, CrateStacks, Input, Instruction, Output, Result};
 
 /// Solve Day 05, Part 2
-pub fn solve(input: &Input) -> Output {
+pub fn solve(input: &Input) -> Result<Output> {
     // Split up the input into the stacks of crates and the instructions,
     // then clone the crate stacks struct so we can have a mutable copy.
     let (crate_stacks, instructions) = input;
@@ -12,7 +12,7 @@ pub fn solve(input: &Input) -> Output {
     // Execute each instruction on the `CrateStacks`, and return the
     // String resulting from the top crate in each stack
     instructions.iter().for_each(|i| crate_stacks.execute(i));
-    crate_stacks.message().into()
+    Ok(crate_stacks.message().into())
 }
 
 impl CrateStack {
@@ -36,6 +36,11 @@ impl CrateStacks {
     /// to another. Essentially takes all the parameters from an `Instruction`
     /// to do it.
     pub fn transfer_many_between(&mut self, origin: u8, destination: u8, n: u8) {
+        // Moving crates onto the stack they're already on doesn't change anything,
+        // and we can't borrow the same stack twice to try it
+        if origin == destination {
+            return;
+        }
         let mut origin_stack = self[origin as usize].borrow_mut();
         let mut destination_stack = self[destination as usize].borrow_mut();
         origin_stack.transfer_many(&mut destination_stack, n);
@@ -1,13 +1,25 @@
 use anyhow::{bail, Error};
 
 use crate::day06::Input;
+use crate::Error as ParseError;
 
-const INPUT: &str = include_str!("../../input/06/input.txt");
+pub(crate) const INPUT: &str = include_str!("../../input/06/input.txt");
 
-/// Read in the input by converting each character to a `Signal` and returning
-/// the list.
+/// Parse the text of an input file by converting each character to a `Signal`
+/// and returning the list.
+pub fn parse(input: &str) -> crate::Result<Input> {
+    let mut signals = Vec::new();
+    for (idx, ch) in input.trim_end().char_indices() {
+        let signal =
+            Signal::try_from(ch).map_err(|e| ParseError::parse(input, &input[idx..], e))?;
+        signals.push(signal);
+    }
+    Ok(signals)
+}
+
+/// Read in the bundled input.
 pub fn read() -> Input {
-    Box::new(INPUT.chars().flat_map(Signal::try_from))
+    parse(INPUT).expect("Could not parse input!")
 }
 
 /// Represents a single signal received on our device. Each character from the
@@ -43,7 +55,7 @@ mod test {
 
     #[test]
     fn name() {
-        let input: Vec<_> = read().collect();
+        let input = read();
         assert_eq!(input.len(), 4095);
 
         let first_signal = *input.first().unwrap();
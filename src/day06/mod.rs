@@ -3,37 +3,56 @@ pub mod part1;
 pub mod part2;
 pub mod shared;
 
-use crate::{Output, Part};
+use crate::{Output, Part, Result, Solution, Source};
 use input::Signal;
 use shared::SequenceDetector;
 
-pub type Input = Box<dyn Iterator<Item = Signal>>;
+pub type Input = Vec<Signal>;
 
-pub fn run(part: Part) -> Output {
-    let input = input::read();
-    match part {
-        Part::One => part1::solve(input::read()),
-        Part::Two => part2::solve(input::read()),
+pub struct Day06;
+
+impl Solution for Day06 {
+    const DAY: u8 = 6;
+    const INPUT: &'static str = input::INPUT;
+    type Input<'a> = Input;
+
+    fn parse(text: &str) -> Result<Self::Input<'_>> {
+        input::parse(text)
     }
-}
 
-pub fn run_both() -> (Output, Output) {
-    (part1::solve(input::read()), part2::solve(input::read()))
+    fn part1(input: &Self::Input<'_>) -> Result<Output> {
+        part1::solve(input)
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Result<Output> {
+        part2::solve(input)
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::examples;
 
     #[test]
     fn check_answer_one() {
-        let result = run(Part::One);
+        let result = Day06::run(Part::One, Source::Bundled).unwrap();
         assert_eq!(result, 1647);
     }
 
     #[test]
     fn check_answer_two() {
-        let result = run(Part::Two);
+        let result = Day06::run(Part::Two, Source::Bundled).unwrap();
         assert_eq!(result, 2447);
     }
+
+    #[test]
+    fn check_example_one() {
+        examples::check::<Day06>(Part::One);
+    }
+
+    #[test]
+    fn check_example_two() {
+        examples::check::<Day06>(Part::Two);
+    }
 }
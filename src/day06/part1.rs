@@ -1,11 +1,12 @@
-use crate::day06::{Input, Output, SequenceDetector};
+use crate::day06::{Input, Output, Result, SequenceDetector};
+use crate::Error;
 
-pub fn solve(input: Input) -> Output {
+pub fn solve(input: &Input) -> Result<Output> {
     let mut detector: SequenceDetector<4> = SequenceDetector::new();
-    for (idx, signal) in input.enumerate() {
+    for (idx, signal) in input.iter().copied().enumerate() {
         if detector.detect(signal) {
-            return (idx as u32 + 1).into();
+            return Ok((idx as u32 + 1).into());
         }
     }
-    panic!("No start-of-packet marker detected!")
+    Err(Error::no_solution("No start-of-packet marker detected!"))
 }
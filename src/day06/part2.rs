@@ -1,16 +1,17 @@
-use crate::day06::{Input, Output, SequenceDetector};
+use crate::day06::{Input, Output, Result, SequenceDetector};
+use crate::Error;
 
 /// Solve Day 6, Part 2
-pub fn solve(input: Input) -> Output {
+pub fn solve(input: &Input) -> Result<Output> {
     // Instantiate a detector for sequences of length 14
     let mut detector: SequenceDetector<14> = SequenceDetector::new();
 
     // Pass each `Signal` in the input to the detector. Return early
     // with the index (plus one) if a unique sequence is detected.
-    for (idx, signal) in input.enumerate() {
+    for (idx, signal) in input.iter().copied().enumerate() {
         if detector.detect(signal) {
-            return (idx as u32 + 1).into();
+            return Ok((idx as u32 + 1).into());
         }
     }
-    panic!("No start-of-message marker detected!")
+    Err(Error::no_solution("No start-of-message marker detected!"))
 }
@@ -52,18 +52,16 @@ impl<const N: usize> SequenceDetector<N> {
     /// Given a `Signal`, indicates whether the most recent `N` signals detected
     /// comprises a unique sequence.
     pub fn detect(&mut self, signal: Signal) -> bool {
-        // Remove the oldest signal from the composite signal, add the current
-        // signal to the buffer and the composite signal, and bump the marker over
-        // by one to receive the next signal.
-        self.composite_signal ^= self.buffer[self.mark];
-
-        if self.composite_signal.count_components() < 3 {
-            self.composite_signal |= self.buffer[self.mark];
-        }
-        
-        self.composite_signal |= signal;
+        // Replace the oldest signal in the buffer with the current signal and bump
+        // the marker over by one to receive the next signal. The oldest signal
+        // can't just be XOR'd out of the composite signal, since the same signal
+        // might still be in the buffer, so rebuild the composite from the buffer.
         self.buffer[self.mark] = signal;
         self.mark = (self.mark + 1) % N;
+        self.composite_signal = Signal::default();
+        for signal in self.buffer {
+            self.composite_signal |= signal;
+        }
 
         // Return an indicator as to whether or not the buffer contains N unique
         // signals, indicated by the number of signals in the composite signal
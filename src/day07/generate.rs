@@ -0,0 +1,135 @@
+use crate::day07::input::{Dir, DirRef, File, FileSystem};
+use crate::random::Rng;
+use std::cell::RefCell;
+use std::rc::Rc;
+
+/// Names for directories, which can only have letters in them
+const DIR_NAMES: [&str; 16] = [
+    "a", "d", "e", "bfqzjjct", "cmpvgfgs", "dwfsh", "gnpd", "hbjmndt", "jsvchtd", "lhjmzsl",
+    "nwhpqp", "qdtjr", "rvmbqvzh", "tnrhfbq", "vlchvn", "zdwd",
+];
+
+/// Names for files, which can have just about anything in them
+const FILE_NAMES: [&str; 16] = [
+    "b.txt",
+    "c.dat",
+    "d.ext",
+    "d.log",
+    "f",
+    "g",
+    "h.lst",
+    "i",
+    "j",
+    "k",
+    "cdfqsj.pgr",
+    "fhnssh.hgj",
+    "gwwtjmm",
+    "pcbhs.zdg",
+    "qvhzqbdl.tpp",
+    "wlbzvmh",
+];
+
+/// Generate a random file system with (about) `size` files and directories in it,
+/// nested up to eight directories deep. Every directory has something in it, since
+/// listing an empty directory wouldn't print anything.
+pub fn generate(rng: &mut Rng, size: usize) -> FileSystem<'static> {
+    let mut remaining = size;
+    let root = directory(rng, "/", &mut remaining, 0);
+    let file_system = FileSystem(Rc::new(RefCell::new(root)));
+    file_system.calculate_directory_sizes();
+    file_system
+}
+
+/// Generate one directory, and recursively all the directories inside it. Each file
+/// and directory made takes one from the `remaining` count.
+fn directory(
+    rng: &mut Rng,
+    label: &'static str,
+    remaining: &mut usize,
+    depth: usize,
+) -> Dir<'static> {
+    let mut dir_names = DIR_NAMES;
+    let mut file_names = FILE_NAMES;
+    rng.shuffle(&mut dir_names);
+    rng.shuffle(&mut file_names);
+
+    let mut dirs = Vec::new();
+    let mut files = Vec::new();
+    let entries = rng.range(1..=6);
+    for _ in 0..entries {
+        if *remaining == 0 && !(dirs.is_empty() && files.is_empty()) {
+            break;
+        }
+        *remaining = remaining.saturating_sub(1);
+        if depth < 8 && rng.chance(0.4) {
+            let label = dir_names[dirs.len()];
+            let dir = directory(rng, label, remaining, depth + 1);
+            dirs.push(Rc::new(RefCell::new(dir)));
+        } else {
+            let label = file_names[files.len()];
+            files.push(File {
+                label,
+                size: rng.range(1..=300_000),
+            });
+        }
+    }
+
+    Dir {
+        label,
+        dirs,
+        files,
+        size: 0,
+    }
+}
+
+/// Print the terminal session that explores the whole file system, starting from
+/// the root and listing each directory before moving into the directories inside it.
+pub fn print(file_system: &FileSystem) -> String {
+    let mut out = String::from("$ cd /\n");
+    explore(&file_system.0, &mut out);
+    out
+}
+
+/// List a directory, then move into each directory inside it to do the same
+fn explore(dir: &DirRef, out: &mut String) {
+    let dir = dir.borrow();
+    *out += "$ ls\n";
+    for child in dir.dirs.iter() {
+        *out += &format!("dir {}\n", child.borrow().label);
+    }
+    for file in dir.files.iter() {
+        *out += &format!("{} {}\n", file.size, file.label);
+    }
+    for child in dir.dirs.iter() {
+        *out += &format!("$ cd {}\n", child.borrow().label);
+        explore(child, out);
+        *out += "$ cd ..\n";
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::day07::input::parse;
+
+    #[test]
+    fn check_generated_input() {
+        // The parsed file system borrows its labels from the input, so it can't be
+        // compared to the generated one directly. Print it back out instead.
+        for seed in 0..25 {
+            for size in [1, 2, 10, 100] {
+                let generated = generate(&mut Rng::new(seed), size);
+                let text = print(&generated);
+                for text in [text.as_str(), text.trim_end()] {
+                    let parsed = parse(text).unwrap();
+                    assert_eq!(
+                        print(&parsed),
+                        print(&generated),
+                        "seed {seed}, size {size}"
+                    );
+                    assert_eq!(parsed.0.borrow().size, generated.0.borrow().size);
+                }
+            }
+        }
+    }
+}
@@ -1,5 +1,5 @@
 use crate::day07::Input;
-use anyhow::{anyhow, bail, Error, Result};
+use crate::{Error, Result};
 use std::fmt::{Display, Formatter, Result as FmtResult};
 use std::{cell::RefCell, collections::HashMap, rc::Rc};
 
@@ -77,15 +77,15 @@ pub enum Cmd<'a> {
 /// Module to wrap the parsers needed to parse the input file into commands
 mod parser {
     use super::*;
-    use anyhow::{anyhow, Result};
+    use crate::error::{finish, Result};
     use nom::{
         branch::alt,
-        bytes::complete::{tag, take_until},
-        character::complete::{alpha1, space1, u32},
+        bytes::complete::tag,
+        character::complete::{alpha1, not_line_ending, space1, u32},
         combinator::map,
         multi::separated_list1,
         sequence::{preceded, separated_pair},
-        Finish, IResult,
+        IResult,
     };
 
     /// Nom parser for "dir bacon" -> Rc<RefCell<Dir { label: "bacon" }>>
@@ -97,7 +97,7 @@ mod parser {
 
     /// Nom parser for "123 eggs.txt" -> File { size: 123, label: "eggs.txt" }
     fn file(s: &str) -> IResult<&str, File> {
-        let (s, (size, label)) = separated_pair(u32, space1, take_until("\n"))(s)?;
+        let (s, (size, label)) = separated_pair(u32, space1, not_line_ending)(s)?;
         let file = File { size, label };
         Ok((s, file))
     }
@@ -114,7 +114,7 @@ mod parser {
 
     /// Nom parser for the various `cd` commands
     fn cd_cmd(s: &str) -> IResult<&str, Cmd> {
-        let (s, cmd_str) = preceded(tag("$ cd "), take_until("\n"))(s)?;
+        let (s, cmd_str) = preceded(tag("$ cd "), not_line_ending)(s)?;
         let cmd = match cmd_str {
             "/" => Cmd::MoveRoot,
             ".." => Cmd::MoveUp,
@@ -137,9 +137,7 @@ mod parser {
 
     /// Nom parser to parse all commands from the input into a list of Cmd
     pub fn commands(s: &str) -> Result<Vec<Cmd>> {
-        let result = separated_list1(tag("\n"), command)(s).finish();
-        let (_, cmds) = result.map_err(|_| anyhow!("Could not parse commands!"))?;
-        Ok(cmds)
+        finish(s, separated_list1(tag("\n"), command)(s))
     }
 }
 
@@ -148,8 +146,10 @@ mod parser {
 #[derive(Debug, Clone)]
 pub struct FileSystem<'a>(pub DirRef<'a>);
 
+/// Fails with the reason the commands don't make sense, along with the part of the
+/// input where things went wrong so that `parse` can report where it was.
 impl<'a> TryFrom<Vec<Cmd<'a>>> for FileSystem<'a> {
-    type Error = Error;
+    type Error = (&'a str, String);
 
     fn try_from(commands: Vec<Cmd<'a>>) -> Result<Self, Self::Error> {
         let root = Rc::new(RefCell::new(Dir::from("/")));
@@ -166,16 +166,20 @@ impl<'a> TryFrom<Vec<Cmd<'a>>> for FileSystem<'a> {
                 // from the current directory's contents and pushing that reference
                 // to the end of the list of open directories.
                 Cmd::MoveIn(dir) => {
-                    let dir = current_dir.borrow_mut().get_child(dir.label).unwrap();
-                    open_dirs.push(dir);
+                    let Some(child) = current_dir.borrow_mut().get_child(dir.label) else {
+                        let reason = format!("Cannot 'cd' into unlisted directory {}!", dir.label);
+                        return Err((dir.label, reason));
+                    };
+                    open_dirs.push(child);
                 }
 
                 // Move up out of the current directory by dropping the last directory
                 // from the list of open directories.
                 Cmd::MoveUp => {
-                    open_dirs
-                        .pop()
-                        .ok_or(anyhow!("Cannot 'cd ..' out of root!"))?;
+                    if open_dirs.len() == 1 {
+                        return Err(("", String::from("Cannot 'cd ..' out of root!")));
+                    }
+                    open_dirs.pop();
                 }
 
                 // Move to the root directory by dropping all but the first (root)
@@ -201,7 +205,7 @@ impl<'a> TryFrom<Vec<Cmd<'a>>> for FileSystem<'a> {
 impl FileSystem<'_> {
     /// Fill in the sizes of all the directories in the file system by recursively
     /// walking the file system.
-    fn calculate_directory_sizes(&self) {
+    pub(crate) fn calculate_directory_sizes(&self) {
         // Recursively walk the file system tree and fill in the sizes for
         // each directory.
         fn size(dir: DirRef) -> u32 {
@@ -229,16 +233,21 @@ impl FileSystem<'_> {
     }
 }
 
-const INPUT: &str = include_str!("../../input/07/input.txt");
+pub(crate) const INPUT: &str = include_str!("../../input/07/input.txt");
 
 /// Read the input by first parsing all the commands from the input file, then
 /// following those commands to build up a tree structure for the file system,
 /// finally filling in all the directory sizes and returning the file system struct.
-pub fn read<'a>() -> Input<'a> {
-    let commands = parser::commands(INPUT).expect("Could not parse input!");
-    let mut fs = FileSystem::try_from(commands).expect("Could not build filesystem!");
+pub fn parse(input: &str) -> Result<Input<'_>> {
+    let commands = parser::commands(input)?;
+    let fs =
+        FileSystem::try_from(commands).map_err(|(at, reason)| Error::parse(input, at, reason))?;
     fs.calculate_directory_sizes();
-    fs
+    Ok(fs)
+}
+
+pub fn read() -> Input<'static> {
+    parse(INPUT).expect("Could not parse input!")
 }
 
 #[cfg(test)]
@@ -1,38 +1,57 @@
+pub mod generate;
 pub mod input;
 pub mod part1;
 pub mod part2;
 
-use crate::{Output, Part};
+use crate::{Output, Part, Result, Solution, Source};
 use input::{DirRef, FileSystem, FileSystemObj};
 
 pub type Input<'a> = FileSystem<'a>;
 
-pub fn run(part: Part) -> Output {
-    let input = input::read();
-    match part {
-        Part::One => part1::solve(&input),
-        Part::Two => part2::solve(&input),
+pub struct Day07;
+
+impl Solution for Day07 {
+    const DAY: u8 = 7;
+    const INPUT: &'static str = input::INPUT;
+    type Input<'a> = Input<'a>;
+
+    fn parse(text: &str) -> Result<Self::Input<'_>> {
+        input::parse(text)
     }
-}
 
-pub fn run_both() -> (Output, Output) {
-    let input = input::read();
-    (part1::solve(&input), part2::solve(&input))
+    fn part1(input: &Self::Input<'_>) -> Result<Output> {
+        part1::solve(input)
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Result<Output> {
+        part2::solve(input)
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::examples;
 
     #[test]
     fn check_answer_one() {
-        let result = run(Part::One);
+        let result = Day07::run(Part::One, Source::Bundled).unwrap();
         assert_eq!(result, 1367870);
     }
 
     #[test]
     fn check_answer_two() {
-        let result = run(Part::Two);
+        let result = Day07::run(Part::Two, Source::Bundled).unwrap();
         assert_eq!(result, 549173);
     }
+
+    #[test]
+    fn check_example_one() {
+        examples::check::<Day07>(Part::One);
+    }
+
+    #[test]
+    fn check_example_two() {
+        examples::check::<Day07>(Part::Two);
+    }
 }
@@ -1,15 +1,15 @@
-use crate::day07::{DirRef, FileSystem, FileSystemObj, Input, Output};
+use crate::day07::{DirRef, FileSystem, FileSystemObj, Input, Output, Result};
 
 /// Solve Day 7, Part 1
-pub fn solve(input: &Input) -> Output {
+pub fn solve(input: &Input) -> Result<Output> {
     // From the list of directory sizes, keep only the sizes less than 100_000
     // and return the total of those directory sizes.
-    input
+    Ok(input
         .get_directory_sizes()
         .iter()
         .filter(|x| **x <= 100_000)
         .sum::<u32>()
-        .into()
+        .into())
 }
 
 impl FileSystem<'_> {
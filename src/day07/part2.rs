@@ -1,10 +1,13 @@
-use crate::day07::{FileSystem, FileSystemObj, Input, Output};
+use crate::day07::{FileSystem, FileSystemObj, Input, Output, Result};
+use crate::Error;
 
 /// Solve Day 7, Part 2
-pub fn solve(input: &Input) -> Output {
+pub fn solve(input: &Input) -> Result<Output> {
     // Calculate the space we need to free up as described by the puzzle
-    let space_remaining = 70_000_000 - input.total_size();
-    let space_needed = 30_000_000 - space_remaining;
+    let Some(space_remaining) = 70_000_000u32.checked_sub(input.total_size()) else {
+        return Err(Error::no_solution("The files won't fit on the device!"));
+    };
+    let space_needed = 30_000_000u32.saturating_sub(space_remaining);
 
     // Iterate through the directory sizes and find the ones whose size is at least
     // as large as the amount of space we need, and take the smallest size of those.
@@ -13,8 +16,8 @@ pub fn solve(input: &Input) -> Output {
         .into_iter()
         .filter(|x| *x >= space_needed)
         .min()
-        .unwrap()
-        .into()
+        .map(Output::from)
+        .ok_or(Error::no_solution("No directory is big enough to delete!"))
 }
 
 impl FileSystem<'_> {
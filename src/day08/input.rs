@@ -1,35 +1,41 @@
 use crate::day08::Input;
+use crate::grid::Grid;
+use crate::Result;
 
-/// Represents our overall view of the trees. Really just a two-dimensional vector
-/// of the input characters, converted to numbers, with the number of rows and
-/// columns brought along for the ride.
+/// Represents our overall view of the trees. Really just a grid of the input
+/// characters, converted to numbers, with the number of rows and columns brought
+/// along for the ride.
 #[derive(Debug)]
 pub struct TreeView {
     pub row_len: usize,
     pub col_len: usize,
-    pub trees: Vec<Vec<u8>>,
+    pub trees: Grid<u8>,
 }
 
-/// Parser a TreeView from the input file. No parser combinators today, since they're
-/// almost entirely unnecessary for this one.
-impl From<&str> for TreeView {
-    fn from(input: &str) -> Self {
-        let row_len = input.lines().count();
-        let col_len = input.lines().next().unwrap().len();
-        let line_to_nums = |s: &str| s.chars().map(|c| c as u8 - b'0').collect();
-        let trees: Vec<Vec<_>> = input.lines().map(line_to_nums).collect();
-
+/// Build a TreeView around a grid of tree heights
+impl From<Grid<u8>> for TreeView {
+    fn from(trees: Grid<u8>) -> Self {
         TreeView {
-            row_len,
-            col_len,
+            row_len: trees.rows(),
+            col_len: trees.cols(),
             trees,
         }
     }
 }
 
-const INPUT: &str = include_str!("../../input/08/input.txt");
+pub(crate) const INPUT: &str = include_str!("../../input/08/input.txt");
+
+/// Parse the text of an input file into a `TreeView`. No parser combinators today,
+/// since they're almost entirely unnecessary for this one.
+pub fn parse(input: &str) -> Result<Input> {
+    let trees = Grid::parse(input, |c| match c.to_digit(10) {
+        Some(height) => Ok(height as u8),
+        None => Err("tree heights must be digits"),
+    })?;
+    Ok(TreeView::from(trees))
+}
 
 /// Read the input file and convert it to a `TreeView`
 pub fn read() -> Input {
-    TreeView::from(INPUT)
+    parse(INPUT).expect("Could not parse input!")
 }
@@ -1,8 +1,8 @@
-use crate::day08::{Input, Output};
+use crate::day08::{Input, Output, Result};
 
 /// Solve Day 8, Part 1
 #[allow(clippy::needless_range_loop)]
-pub fn solve(input: &Input) -> Output {
+pub fn solve(input: &Input) -> Result<Output> {
     // Start with a two-dimensional vector the same size as the input,
     // filled with `false`. So far, we can't see any trees!
     let mut visibility_map = vec![vec![false; input.col_len]; input.row_len];
@@ -15,7 +15,7 @@ pub fn solve(input: &Input) -> Output {
         // For each column from left to right...
         for col_idx in 0..input.col_len {
             // Take the tree at that position
-            let tree = input.trees[row_idx][col_idx];
+            let tree = input.trees[(row_idx, col_idx)];
 
             // If it's taller than the current `tallest` OR it's on the left
             // or right edge, then set that tree to visible and update the
@@ -29,8 +29,8 @@ pub fn solve(input: &Input) -> Output {
         // Reset `tallest` and do the same thing again from right to left. There's
         // no need to attend to the edges again on this round.
         tallest = 0;
-        for col_idx in 0..input.col_len {
-            let tree = input.trees[row_idx][col_idx];
+        for col_idx in (0..input.col_len).rev() {
+            let tree = input.trees[(row_idx, col_idx)];
             if tree > tallest {
                 visibility_map[row_idx][col_idx] = true;
                 tallest = tree;
@@ -44,7 +44,7 @@ pub fn solve(input: &Input) -> Output {
         // on the top and bottom rows, as well.
         let mut tallest = 0;
         for row_idx in 0..input.row_len {
-            let tree = input.trees[row_idx][col_idx];
+            let tree = input.trees[(row_idx, col_idx)];
             if tree > tallest || row_idx == 0 || row_idx == (input.row_len - 1) {
                 visibility_map[row_idx][col_idx] = true;
                 tallest = tree;
@@ -55,7 +55,7 @@ pub fn solve(input: &Input) -> Output {
         // the you guessed right!
         tallest = 0;
         for row_idx in (0..input.row_len).rev() {
-            let tree = input.trees[row_idx][col_idx];
+            let tree = input.trees[(row_idx, col_idx)];
             if tree > tallest {
                 visibility_map[row_idx][col_idx] = true;
                 tallest = tree;
@@ -69,5 +69,5 @@ pub fn solve(input: &Input) -> Output {
         .flat_map(|row| row.iter())
         .filter(|x| **x)
         .count() as u32;
-    found.into()
+    Ok(found.into())
 }
@@ -1,8 +1,9 @@
-use crate::day08::{Input, Output};
+use crate::day08::{Input, Output, Result};
+use crate::Error;
 use std::cmp::{max, min};
 
 /// Solve Day 8, Part 2
-pub fn solve(input: &Input) -> Output {
+pub fn solve(input: &Input) -> Result<Output> {
     // This time, we'll instantiate a 2D vector the same size and shape as our
     // view of the trees, just filled with zeros!
     let mut scenic_score_map = vec![vec![0u32; input.col_len]; input.row_len];
@@ -23,14 +24,14 @@ pub fn solve(input: &Input) -> Output {
         }
 
         // The tree at our current location
-        let tree = input.trees[row_idx][col_idx];
+        let tree = input.trees[(row_idx, col_idx)];
 
         // From our tree, loop up and count the number of trees visible. We do this
         // by iterating over the positions in the same column and in rows above our
         // current tree until we either reach the edge or hit a tree our own height.
         let mut can_see_up = 0;
         for seek_idx in (0..row_idx).rev() {
-            let found = input.trees[seek_idx][col_idx];
+            let found = input.trees[(seek_idx, col_idx)];
             can_see_up += 1;
             if found >= tree {
                 break;
@@ -40,7 +41,7 @@ pub fn solve(input: &Input) -> Output {
         // Same deal, just looking down.
         let mut can_see_down = 0;
         for seek_idx in (row_idx + 1)..input.row_len {
-            let found = input.trees[seek_idx][col_idx];
+            let found = input.trees[(seek_idx, col_idx)];
             can_see_down += 1;
             if found >= tree {
                 break;
@@ -50,7 +51,7 @@ pub fn solve(input: &Input) -> Output {
         // Same deal, just looking left
         let mut can_see_left = 0;
         for seek_idx in (0..col_idx).rev() {
-            let found = input.trees[row_idx][seek_idx];
+            let found = input.trees[(row_idx, seek_idx)];
             can_see_left += 1;
             if found >= tree {
                 break;
@@ -60,7 +61,7 @@ pub fn solve(input: &Input) -> Output {
         // Same deal, just looking right
         let mut can_see_right = 0;
         for seek_idx in (col_idx + 1)..input.col_len {
-            let found = input.trees[row_idx][seek_idx];
+            let found = input.trees[(row_idx, seek_idx)];
             can_see_right += 1;
             if found >= tree {
                 break;
@@ -79,6 +80,6 @@ pub fn solve(input: &Input) -> Output {
         .flat_map(|row| row.iter())
         .copied()
         .max()
-        .unwrap()
-        .into()
+        .map(Output::from)
+        .ok_or(Error::no_solution("There aren't any trees!"))
 }
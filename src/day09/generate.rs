@@ -0,0 +1,48 @@
+use crate::day09::{Input, Motion};
+use crate::random::Rng;
+
+/// Generate `size` random motions for the head of the rope. Most are short, like the
+/// ones in the puzzle input, but some go as far as 1000 steps.
+pub fn generate(rng: &mut Rng, size: usize) -> Input {
+    (0..size)
+        .map(|_| {
+            let steps = if rng.chance(0.9) {
+                rng.range(1..=20)
+            } else {
+                rng.range(1..=1000)
+            };
+            match rng.range(0..=3) {
+                0 => Motion::Up(steps),
+                1 => Motion::Down(steps),
+                2 => Motion::Left(steps),
+                _ => Motion::Right(steps),
+            }
+        })
+        .collect()
+}
+
+/// Print the motions the way they're written in the input
+pub fn print(input: &Input) -> String {
+    let mut out = String::new();
+    for motion in input {
+        out += &match motion {
+            Motion::Up(steps) => format!("U {steps}\n"),
+            Motion::Down(steps) => format!("D {steps}\n"),
+            Motion::Left(steps) => format!("L {steps}\n"),
+            Motion::Right(steps) => format!("R {steps}\n"),
+        };
+    }
+    out
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::day09::input::parse;
+    use crate::random::check_round_trip;
+
+    #[test]
+    fn check_generated_input() {
+        check_round_trip(generate, print, parse);
+    }
+}
@@ -1,59 +1,61 @@
 use crate::day09::Input;
+use crate::Result;
 
 /// Represents one of the motions specified in the input, either up,
 /// down, left, or right by a given distance (or number of steps).
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum Motion {
-    Up(u8),
-    Down(u8),
-    Left(u8),
-    Right(u8),
+    Up(u32),
+    Down(u32),
+    Left(u32),
+    Right(u32),
 }
 
 /// Module wrapping the parser for today's puzzle. Produces a `Vec<Motion>`.
 mod parser {
     use super::*;
-    use anyhow::{anyhow, Result};
+    use crate::error::{finish, Result};
     use nom::{
-        branch::alt, bytes::complete::tag, character::complete::u8, combinator::map,
-        multi::separated_list1, sequence::preceded, Finish, IResult,
+        branch::alt, bytes::complete::tag, character::complete::u32, combinator::map,
+        multi::separated_list1, sequence::preceded, IResult,
     };
 
     /// Nom parser for "U 5" -> Motion::Up(5)
     fn up(s: &str) -> IResult<&str, Motion> {
-        map(preceded(tag("U "), u8), Motion::Up)(s)
+        map(preceded(tag("U "), u32), Motion::Up)(s)
     }
 
     /// Nom parser for "D 5" -> Motion::Down(5)
     fn down(s: &str) -> IResult<&str, Motion> {
-        map(preceded(tag("D "), u8), Motion::Down)(s)
+        map(preceded(tag("D "), u32), Motion::Down)(s)
     }
 
     /// Nom parser for "L 5" -> Motion::Left(5)
     fn left(s: &str) -> IResult<&str, Motion> {
-        map(preceded(tag("L "), u8), Motion::Left)(s)
+        map(preceded(tag("L "), u32), Motion::Left)(s)
     }
 
     /// Nom parser for "R 5" -> Motion::Right(5)
     fn right(s: &str) -> IResult<&str, Motion> {
-        map(preceded(tag("R "), u8), Motion::Right)(s)
+        map(preceded(tag("R "), u32), Motion::Right)(s)
     }
 
     /// Nom parser to take all the lines of the input and produce a vector
     /// of `Motion`s
     pub fn parse(s: &str) -> Result<Vec<Motion>> {
         let result = separated_list1(tag("\n"), alt((up, down, left, right)))(s);
-        let (_, motions) = result
-            .finish()
-            .map_err(|_| anyhow!("Could not parse motions!"))?;
-        Ok(motions)
+        finish(s, result)
     }
 }
 
-const INPUT: &str = include_str!("../../input/09/input.txt");
+pub(crate) const INPUT: &str = include_str!("../../input/09/input.txt");
+
+pub fn parse(input: &str) -> Result<Input> {
+    parser::parse(input)
+}
 
 pub fn read() -> Input {
-    parser::parse(INPUT).unwrap()
+    parse(INPUT).unwrap()
 }
 
 #[cfg(test)]
@@ -72,5 +74,9 @@ mod test {
         let last_found = *input.last().unwrap();
         let last_expected = Motion::Right(3);
         assert_eq!(last_found, last_expected);
+
+        // Motions can be a lot longer than the ones in my input
+        let long = parse("R 1000000\nU 70000").unwrap();
+        assert_eq!(long, [Motion::Right(1_000_000), Motion::Up(70_000)]);
     }
 }
@@ -1,40 +1,60 @@
+pub mod generate;
 pub mod input;
 pub mod part1;
 pub mod part2;
 pub mod shared;
+pub mod visualize;
 
-use crate::{Output, Part};
+use crate::{Output, Part, Result, Solution, Source};
 use input::Motion;
 use shared::Knot;
 
 pub type Input = Vec<Motion>;
 
-pub fn run(part: Part) -> Output {
-    let input = input::read();
-    match part {
-        Part::One => part1::solve(&input),
-        Part::Two => part2::solve(&input),
+pub struct Day09;
+
+impl Solution for Day09 {
+    const DAY: u8 = 9;
+    const INPUT: &'static str = input::INPUT;
+    type Input<'a> = Input;
+
+    fn parse(text: &str) -> Result<Self::Input<'_>> {
+        input::parse(text)
     }
-}
 
-pub fn run_both() -> (Output, Output) {
-    let input = input::read();
-    (part1::solve(&input), part2::solve(&input))
+    fn part1(input: &Self::Input<'_>) -> Result<Output> {
+        part1::solve(input)
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Result<Output> {
+        part2::solve(input)
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::examples;
 
     #[test]
     fn check_answer_one() {
-        let result = run(Part::One);
+        let result = Day09::run(Part::One, Source::Bundled).unwrap();
         assert_eq!(result, 6175);
     }
 
     #[test]
     fn check_answer_two() {
-        let result = run(Part::Two);
+        let result = Day09::run(Part::Two, Source::Bundled).unwrap();
         assert_eq!(result, 2578);
     }
+
+    #[test]
+    fn check_example_one() {
+        examples::check::<Day09>(Part::One);
+    }
+
+    #[test]
+    fn check_example_two() {
+        examples::check::<Day09>(Part::Two);
+    }
 }
@@ -1,8 +1,10 @@
-use crate::day09::{Input, Knot, Motion, Output};
+use crate::day09::shared::too_far;
+use crate::day09::{Input, Knot, Motion, Output, Result};
+use crate::grid::Offset;
 use std::collections::HashSet;
 use std::ops::AddAssign;
 
-pub fn solve(input: &Input) -> Output {
+pub fn solve(input: &Input) -> Result<Output> {
     // Brand new `RopeSimulator`(tm)
     let mut simulator = RopeSimulator::new();
 
@@ -11,7 +13,7 @@ pub fn solve(input: &Input) -> Output {
 
     // Return the number of unique tail positions from the simulator
     let unique_tail_pos = simulator.hist.len() as u32;
-    unique_tail_pos.into()
+    Ok(unique_tail_pos.into())
 }
 
 /// A struct to encapsulate the state of the rope, with a HashSet to keep up with
@@ -67,10 +69,10 @@ impl RopeSimulator {
         // steps from the `Motion`, and the offset indicates how the `Position`
         // of the head is changed on each step.
         let (steps, offset) = match motion {
-            Motion::Up(steps) => (steps, (0, -1)),
-            Motion::Down(steps) => (steps, (0, 1)),
-            Motion::Left(steps) => (steps, (-1, 0)),
-            Motion::Right(steps) => (steps, (1, 0)),
+            Motion::Up(steps) => (steps, Offset::UP),
+            Motion::Down(steps) => (steps, Offset::DOWN),
+            Motion::Left(steps) => (steps, Offset::LEFT),
+            Motion::Right(steps) => (steps, Offset::RIGHT),
         };
 
         // Now we move the head one step at a time, adjusting the head by
@@ -78,7 +80,7 @@ impl RopeSimulator {
         // tail, we adjust the tail and record its new position.
         for _ in 0..*steps {
             self.head += offset;
-            if self.head.too_far(&self.tail) {
+            if too_far(&self.head, &self.tail) {
                 self.move_tail();
             }
         }
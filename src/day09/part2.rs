@@ -1,9 +1,11 @@
-use crate::day09::{Input, Knot, Motion, Output};
+use crate::day09::shared::too_far;
+use crate::day09::{Input, Knot, Motion, Output, Result};
+use crate::grid::Offset;
 use itertools::Itertools;
 use std::collections::HashSet;
 use std::ops::AddAssign;
 
-pub fn solve(input: &Input) -> Output {
+pub fn solve(input: &Input) -> Result<Output> {
     // Brand new `RopeSimulator`(tm)
     let mut simulator: RopeSimulator<10> = RopeSimulator::new();
 
@@ -12,7 +14,7 @@ pub fn solve(input: &Input) -> Output {
 
     // Return the number of unique tail positions from the simulator
     let unique_tail_pos = simulator.hist.len() as u32;
-    unique_tail_pos.into()
+    Ok(unique_tail_pos.into())
 }
 
 // New and improved! I realize we only _needed_ to support a rope with 10
@@ -22,17 +24,33 @@ pub struct RopeSimulator<const N: usize> {
     hist: HashSet<Knot>,
 }
 
+impl<const N: usize> Default for RopeSimulator<N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl<const N: usize> RopeSimulator<N> {
     // New RopeSimulator, this time we're keeping all the knots in an array
     // of length N (10 for our case). Note that the order of these knots
     // matters, since `knots[0]` will be the head and `knots[N - 1]` will be
     // the tail.
-    fn new() -> Self {
+    pub fn new() -> Self {
         let knots = [Knot::default(); N];
         let hist = HashSet::from([Knot::default()]);
         RopeSimulator { knots, hist }
     }
 
+    // The knots, from the head to the tail
+    pub fn knots(&self) -> &[Knot; N] {
+        &self.knots
+    }
+
+    // Every position the tail has been in so far
+    pub fn visited(&self) -> &HashSet<Knot> {
+        &self.hist
+    }
+
     // This time, instead of hard-coding the head and the tail, we pass in
     // the index of the `leader` knot and the `follower` knot. For our
     // implementation, follower == leader + 1;
@@ -61,15 +79,15 @@ impl<const N: usize> RopeSimulator<N> {
         }
     }
 
-    fn move_head(&mut self, motion: &Motion) {
+    pub fn move_head(&mut self, motion: &Motion) {
         // Generate a specification for moving the head. We get the number of
         // steps from the `Motion`, and the offset indicates how the `Knot`
         // of the head is changed on each step.
         let (reps, offset) = match motion {
-            Motion::Up(reps) => (reps, (0, -1)),
-            Motion::Down(reps) => (reps, (0, 1)),
-            Motion::Left(reps) => (reps, (-1, 0)),
-            Motion::Right(reps) => (reps, (1, 0)),
+            Motion::Up(reps) => (reps, Offset::UP),
+            Motion::Down(reps) => (reps, Offset::DOWN),
+            Motion::Left(reps) => (reps, Offset::LEFT),
+            Motion::Right(reps) => (reps, Offset::RIGHT),
         };
 
         // For each step in the motion, move the first knot in the `knots` array
@@ -83,7 +101,7 @@ impl<const N: usize> RopeSimulator<N> {
             for (leader, follower) in (0..N).tuple_windows() {
                 // If the first knot is too far away from the knot behind it, move
                 // the follower.
-                if self.knots[leader].too_far(&self.knots[follower]) {
+                if too_far(&self.knots[leader], &self.knots[follower]) {
                     self.follow(leader, follower);
                 }
             }
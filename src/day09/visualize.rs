@@ -0,0 +1,86 @@
+use crate::day09::part2::RopeSimulator;
+use crate::day09::{Input, Knot, Result};
+use crate::visualize::{Frame, Sink};
+use std::collections::HashSet;
+
+/// Pull the ten-knot rope from part two around, drawing it after each motion along
+/// with everywhere the tail has been. The rope wanders a long way, so the first
+/// time through is just to find a window big enough to hold every frame.
+pub fn visualize(input: &Input, sink: &mut dyn Sink) -> Result<()> {
+    let mut simulator: RopeSimulator<10> = RopeSimulator::new();
+    let (mut min, mut max) = (Knot::default(), Knot::default());
+    for motion in input {
+        simulator.move_head(motion);
+        for Knot(x, y) in simulator.knots().iter().chain(simulator.visited()) {
+            min = Knot(min.0.min(*x), min.1.min(*y));
+            max = Knot(max.0.max(*x), max.1.max(*y));
+        }
+    }
+
+    let rows = max.1.abs_diff(min.1) + 1;
+    let cols = max.0.abs_diff(min.0) + 1;
+    let mut simulator: RopeSimulator<10> = RopeSimulator::new();
+    let frame = |simulator: &RopeSimulator<10>| Rope {
+        knots: *simulator.knots(),
+        visited: simulator.visited().clone(),
+        corner: min,
+        size: (rows, cols),
+    };
+    sink.frame(&frame(&simulator))?;
+    for motion in input {
+        simulator.move_head(motion);
+        sink.frame(&frame(&simulator))?;
+    }
+    Ok(())
+}
+
+/// The rope, drawn like the puzzle description draws it. The head is `H`, the
+/// knots behind it are numbered, `s` is where the rope started, and `#` is
+/// everywhere the tail has been.
+struct Rope {
+    knots: [Knot; 10],
+    visited: HashSet<Knot>,
+    corner: Knot,
+    size: (usize, usize),
+}
+
+impl Frame for Rope {
+    fn size(&self) -> (usize, usize) {
+        self.size
+    }
+
+    fn glyph(&self, row: usize, col: usize) -> char {
+        let Knot(x, y) = self.corner;
+        let position = Knot(x + col as isize, y + row as isize);
+
+        // Knots closer to the head are drawn on top of the ones behind them
+        match self.knots.iter().position(|knot| *knot == position) {
+            Some(0) => 'H',
+            Some(idx) => char::from_digit(idx as u32, 10).unwrap_or('?'),
+            None if position == Knot::default() => 's',
+            None if self.visited.contains(&position) => '#',
+            None => '.',
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::day09::input::parse;
+    use crate::visualize::Frames;
+
+    #[test]
+    fn check_larger_example() {
+        let input = parse("R 5\nU 8\nL 8\nD 3\nR 17\nD 10\nL 25\nU 20\n").unwrap();
+        let mut frames = Frames::default();
+        visualize(&input, &mut frames).unwrap();
+        assert_eq!(frames.0.len(), 9);
+
+        // The head ends up in the top left corner, and the tail has been to 36
+        // positions, counting the start and where it is now
+        let last = &frames.0[8];
+        assert!(last.starts_with("H...."));
+        assert_eq!(last.matches('#').count() + 2, 36);
+    }
+}
@@ -0,0 +1,37 @@
+use crate::day10::{Input, Instruction};
+use crate::random::Rng;
+
+/// Generate `size` random instructions for the device. The values added to the
+/// register can be negative, and can run to more than one digit either way.
+pub fn generate(rng: &mut Rng, size: usize) -> Input {
+    (0..size)
+        .map(|_| match rng.chance(0.4) {
+            true => Instruction::Noop,
+            false => Instruction::Addx(rng.range(-40..=40)),
+        })
+        .collect()
+}
+
+/// Print the instructions the way they're written in the input
+pub fn print(input: &Input) -> String {
+    let mut out = String::new();
+    for instruction in input {
+        out += &match instruction {
+            Instruction::Noop => String::from("noop\n"),
+            Instruction::Addx(value) => format!("addx {value}\n"),
+        };
+    }
+    out
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::day10::input::parse;
+    use crate::random::check_round_trip;
+
+    #[test]
+    fn check_generated_input() {
+        check_round_trip(generate, print, parse);
+    }
+}
@@ -1,4 +1,5 @@
 use crate::day10::Input;
+use crate::Result;
 
 /// Represents an instruction to our handheld device.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -10,7 +11,7 @@ pub enum Instruction {
 /// Module wrapping the parser for the instructions from the input.
 mod parser {
     use super::*;
-    use anyhow::{anyhow, Result};
+    use crate::error::{finish, Result};
     use nom::{
         branch::alt,
         bytes::complete::tag,
@@ -18,7 +19,7 @@ mod parser {
         combinator::{map, value},
         multi::separated_list1,
         sequence::preceded,
-        Finish, IResult,
+        IResult,
     };
 
     /// Nom parser for "noop" -> Instruction:Noop
@@ -39,16 +40,20 @@ mod parser {
     /// Nom parser for all instruction lines -> Vec<Instruction>
     pub fn parse(s: &str) -> Result<Vec<Instruction>> {
         let result = separated_list1(tag("\n"), instruction)(s);
-        let (_, instrs) = result.finish().map_err(|e| anyhow!("{e}"))?;
-        Ok(instrs)
+        finish(s, result)
     }
 }
 
-const INPUT: &str = include_str!("../../input/10/input.txt");
+pub(crate) const INPUT: &str = include_str!("../../input/10/input.txt");
+
+/// Parse the text of an input file
+pub fn parse(input: &str) -> Result<Input> {
+    parser::parse(input)
+}
 
 /// Parse that input!
 pub fn read() -> Input {
-    parser::parse(INPUT).unwrap()
+    parse(INPUT).unwrap()
 }
 
 #[cfg(test)]
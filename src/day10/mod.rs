@@ -1,45 +1,87 @@
+pub mod generate;
 pub mod input;
+pub mod ocr;
 pub mod part1;
 pub mod part2;
 
-use crate::{Output, Part};
+use crate::{Output, Part, Result, Solution, Source};
 use input::Instruction;
 
 pub type Input = Vec<Instruction>;
 
-pub fn run(part: Part) -> Output {
-    let input = input::read();
-    match part {
-        Part::One => part1::solve(&input),
-        Part::Two => part2::solve(&input),
+pub struct Day10;
+
+impl Solution for Day10 {
+    const DAY: u8 = 10;
+    const INPUT: &'static str = input::INPUT;
+    type Input<'a> = Input;
+
+    fn parse(text: &str) -> Result<Self::Input<'_>> {
+        input::parse(text)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Result<Output> {
+        part1::solve(input)
     }
-}
 
-pub fn run_both() -> (Output, Output) {
-    let input = input::read();
-    (part1::solve(&input), part2::solve(&input))
+    fn part2(input: &Self::Input<'_>) -> Result<Output> {
+        part2::solve(input)
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::{examples, Error};
 
     #[test]
     fn check_answer_one() {
-        let result = run(Part::One);
+        let result = Day10::run(Part::One, Source::Bundled).unwrap();
         assert_eq!(result, 17020);
     }
 
     #[test]
     fn check_answer_two() {
-        let result = run(Part::Two);
-        let expected = "";
+        let result = Day10::run(Part::Two, Source::Bundled).unwrap();
+        assert_eq!(result, "RLEZFLGE");
+    }
+
+    #[test]
+    fn check_screen() {
+        let screen = part2::draw(&input::read()).unwrap();
         let expected = "###  #    #### #### #### #     ##  #### \n\
                         #  # #    #       # #    #    #  # #    \n\
                         #  # #    ###    #  ###  #    #    ###  \n\
                         ###  #    #     #   #    #    # ## #    \n\
                         # #  #    #    #    #    #    #  # #    \n\
                         #  # #### #### #### #    ####  ### #### ";
-        assert_eq!(result, expected);
+        assert_eq!(screen, expected);
+    }
+
+    #[test]
+    fn check_example_one() {
+        examples::check::<Day10>(Part::One);
+    }
+
+    #[test]
+    fn check_example_two() {
+        // The example draws a test pattern instead of letters, so there's nothing
+        // for the OCR to read. Check the screen instead.
+        let screen = part2::draw(&input::parse(&examples::text(10)).unwrap()).unwrap();
+        let expected = "##  ##  ##  ##  ##  ##  ##  ##  ##  ##  \n\
+                        ###   ###   ###   ###   ###   ###   ### \n\
+                        ####    ####    ####    ####    ####    \n\
+                        #####     #####     #####     #####     \n\
+                        ######      ######      ######      ####\n\
+                        #######       #######       #######     ";
+        assert_eq!(screen, expected);
+    }
+
+    #[test]
+    fn check_long_program() {
+        // One more instruction than the screen has room for
+        let input = vec![Instruction::Noop; 241];
+        assert!(matches!(part2::solve(&input), Err(Error::NoSolution(_))));
+        assert!(part2::draw(&input[..240].to_vec()).is_ok());
     }
 }
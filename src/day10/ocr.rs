@@ -0,0 +1,126 @@
+use std::fmt::{Display, Formatter, Result as FmtResult};
+
+/// Letters on the CRT are drawn in a 4x6 pixel font, with one blank column
+/// between letters, so each letter takes up five columns of the screen.
+const GLYPH_WIDTH: usize = 4;
+const GLYPH_HEIGHT: usize = 6;
+const GLYPH_STRIDE: usize = GLYPH_WIDTH + 1;
+
+/// The letters of the Advent of Code font that have shown up in puzzles so far,
+/// drawn row by row with `#` for lit pixels.
+const FONT: [(char, &str); 18] = [
+    ('A', ".##.#..##..######..##..#"),
+    ('B', "###.#..####.#..##..####."),
+    ('C', ".##.#..##...#...#..#.##."),
+    ('E', "#####...###.#...#...####"),
+    ('F', "#####...###.#...#...#..."),
+    ('G', ".##.#..##...#.###..#.###"),
+    ('H', "#..##..######..##..##..#"),
+    ('I', ".###..#...#...#...#..###"),
+    ('J', "..##...#...#...##..#.##."),
+    ('K', "#..##.#.##..#.#.#.#.#..#"),
+    ('L', "#...#...#...#...#...####"),
+    ('O', ".##.#..##..##..##..#.##."),
+    ('P', "###.#..##..####.#...#..."),
+    ('R', "###.#..##..####.#.#.#..#"),
+    ('S', ".####...#....##....####."),
+    ('U', "#..##..##..##..##..#.##."),
+    ('Y', "#..##..#.##...#...#...#."),
+    ('Z', "####...#..#..#..#...####"),
+];
+
+/// Pack a glyph into the low 24 bits of a u32, one bit per pixel, row by row.
+fn pack(pixels: impl Iterator<Item = bool>) -> u32 {
+    pixels.fold(0, |bits, lit| (bits << 1) | lit as u32)
+}
+
+/// Find the letter matching a packed glyph, if there is one.
+fn recognize(glyph: u32) -> Option<char> {
+    FONT.iter()
+        .find(|(_, art)| pack(art.chars().map(|c| c == '#')) == glyph)
+        .map(|(letter, _)| *letter)
+}
+
+/// The ways reading the screen can go wrong
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OcrError {
+    /// The pixels don't make six lines of the given width
+    ScreenSize { pixels: usize, width: usize },
+    /// The letter at this index on the screen isn't one we know
+    UnknownLetter(usize),
+}
+
+impl Display for OcrError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match self {
+            OcrError::ScreenSize { pixels, width } => write!(
+                f,
+                "{pixels} pixels don't make a screen of {GLYPH_HEIGHT} lines {width} pixels wide"
+            ),
+            OcrError::UnknownLetter(idx) => {
+                write!(f, "couldn't read letter {} on the screen", idx + 1)
+            }
+        }
+    }
+}
+
+/// Read the letters drawn on a six-line screen of `width`-pixel lines, given as
+/// one flat slice of pixels. Fails if the screen isn't that size, or with the
+/// index of the first letter on the screen that can't be recognized.
+pub fn read(pixels: &[bool], width: usize) -> Result<String, OcrError> {
+    if pixels.len() != width * GLYPH_HEIGHT {
+        let pixels = pixels.len();
+        return Err(OcrError::ScreenSize { pixels, width });
+    }
+
+    // Letters are separated by a blank column, but the last letter on the screen
+    // doesn't need one, so round up when counting them.
+    let letters = (width + 1) / GLYPH_STRIDE;
+    (0..letters)
+        .map(|idx| {
+            let left = idx * GLYPH_STRIDE;
+            let glyph = pack((0..GLYPH_HEIGHT).flat_map(|row| {
+                let start = row * width + left;
+                pixels[start..start + GLYPH_WIDTH].iter().copied()
+            }));
+            recognize(glyph).ok_or(OcrError::UnknownLetter(idx))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    // Lay out the given letters on a screen, the way the CRT would draw them.
+    fn draw(letters: &str) -> (Vec<bool>, usize) {
+        let width = letters.len() * GLYPH_STRIDE;
+        let mut pixels = vec![false; width * GLYPH_HEIGHT];
+        for (idx, letter) in letters.chars().enumerate() {
+            let (_, art) = FONT.iter().find(|(c, _)| *c == letter).unwrap();
+            for (pos, c) in art.chars().enumerate() {
+                let (row, col) = (pos / GLYPH_WIDTH, pos % GLYPH_WIDTH);
+                pixels[row * width + idx * GLYPH_STRIDE + col] = c == '#';
+            }
+        }
+        (pixels, width)
+    }
+
+    #[test]
+    fn check_font() {
+        let letters: String = FONT.iter().map(|(letter, _)| *letter).collect();
+        let (pixels, width) = draw(&letters);
+        assert_eq!(read(&pixels, width), Ok(letters));
+    }
+
+    #[test]
+    fn check_unknown_letter() {
+        let (mut pixels, width) = draw("ABC");
+        pixels[GLYPH_STRIDE] = !pixels[GLYPH_STRIDE];
+        assert_eq!(read(&pixels, width), Err(OcrError::UnknownLetter(1)));
+        assert!(matches!(
+            read(&pixels[1..], width),
+            Err(OcrError::ScreenSize { .. })
+        ));
+    }
+}
@@ -1,14 +1,14 @@
-use crate::day10::{Input, Instruction, Output};
+use crate::day10::{Input, Instruction, Output, Result};
 
 /// Solve Day 10, Part 1
-pub fn solve(input: &Input) -> Output {
+pub fn solve(input: &Input) -> Result<Output> {
     // Instantiate a new device, run all the instructions on it, then return
     // the total signal strength from that device.
     let mut device = Device::new();
     input
         .iter()
         .for_each(|instruction| device.execute(instruction));
-    device.signal_strength.into()
+    Ok(device.signal_strength.into())
 }
 
 /// Represents our handheld computing device, complete with signal strength
@@ -0,0 +1,81 @@
+use crate::day11::{Input, Monkey, Operation, Rule};
+use crate::random::Rng;
+
+/// The monkeys only ever check whether your worry level is divisible by a prime
+const PRIMES: [u64; 9] = [2, 3, 5, 7, 11, 13, 17, 19, 23];
+
+/// Generate `size` random monkeys (or two, if `size` is smaller than that, so that
+/// they've got someone to throw to). Each monkey starts with at least one item and
+/// throws to two different monkeys, neither of which is itself.
+pub fn generate(rng: &mut Rng, size: usize) -> Input {
+    let count = size.max(2);
+    (0..count)
+        .map(|id| {
+            let items = (0..rng.range(1..=8)).map(|_| rng.range(1..=99)).collect();
+            let operation = match rng.range(0..=2) {
+                0 => Operation::Add(rng.range(1..=9)),
+                1 => Operation::Mult(rng.range(2..=19)),
+                _ => Operation::Square,
+            };
+
+            // Pick two other monkeys to throw to
+            let mut others: Vec<_> = (0..count).filter(|other| *other != id).collect();
+            rng.shuffle(&mut others);
+            let success = others[0];
+            let fail = *others.get(1).unwrap_or(&others[0]);
+            let rule = Rule {
+                divisor: *rng.pick(&PRIMES),
+                success,
+                fail,
+            };
+
+            Monkey {
+                id,
+                items,
+                operation,
+                rule,
+                inspected: 0,
+            }
+        })
+        .collect()
+}
+
+/// Print the monkeys the way they're described in the input
+pub fn print(input: &Input) -> String {
+    let mut monkeys = Vec::new();
+    for monkey in input {
+        let items: Vec<_> = monkey.items.iter().map(u64::to_string).collect();
+        let operation = match monkey.operation {
+            Operation::Add(value) => format!("+ {value}"),
+            Operation::Mult(value) => format!("* {value}"),
+            Operation::Square => String::from("* old"),
+        };
+        monkeys.push(format!(
+            "Monkey {}:\n  \
+               Starting items: {}\n  \
+               Operation: new = old {}\n  \
+               Test: divisible by {}\n    \
+                 If true: throw to monkey {}\n    \
+                 If false: throw to monkey {}\n",
+            monkey.id,
+            items.join(", "),
+            operation,
+            monkey.rule.divisor,
+            monkey.rule.success,
+            monkey.rule.fail,
+        ));
+    }
+    monkeys.join("\n")
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::day11::input::parse;
+    use crate::random::check_round_trip;
+
+    #[test]
+    fn check_generated_input() {
+        check_round_trip(generate, print, parse);
+    }
+}
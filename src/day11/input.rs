@@ -1,11 +1,12 @@
 use crate::day11::Input;
+use crate::{Error, Result};
 
 /// Represents one of those mischeivous monkies! Contains fields
 /// for the items the monkey is currently holding, the operation
 /// performed when the monkey inspects an item, the rule the monkey
 /// uses to decide who to throw the item to, and the number of
 /// items the monkey has inspected so far.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub struct Monkey {
     pub id: usize,
     pub items: Vec<u64>,
@@ -16,7 +17,7 @@ pub struct Monkey {
 
 /// Represents the operation performed to determine what happens to your
 /// worry level over a particular item inspected by a monkey.
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum Operation {
     Add(u64),
     Mult(u64),
@@ -25,7 +26,7 @@ pub enum Operation {
 
 /// Represents the rule used by a monkey to determine which other monkey to
 /// throw your item to, based on your worry level.
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct Rule {
     pub divisor: u64,
     pub success: usize,
@@ -35,7 +36,7 @@ pub struct Rule {
 /// Wraps the parser combinators for parsing our input into a list of pesky monkies.
 mod parser {
     use super::*;
-    use anyhow::{anyhow, Result};
+    use crate::error::{finish, Result};
     use nom::{
         branch::alt,
         bytes::complete::tag,
@@ -43,7 +44,7 @@ mod parser {
         combinator::{map, value},
         multi::separated_list1,
         sequence::{delimited, preceded, separated_pair, terminated, tuple},
-        Finish, IResult,
+        IResult,
     };
 
     /// Nom parser for "Monkey 3:" -> 3usize
@@ -139,18 +140,50 @@ mod parser {
     /// successful or the relevant nom Error if not.
     pub fn parse(s: &str) -> Result<Vec<Monkey>> {
         let result = separated_list1(tag("\n\n"), monkey)(s);
-        let (s, monkeys) = result
-            .finish()
-            .map_err(|e| anyhow!("Failed to parse monkeys with error {e}"))?;
-        Ok(monkeys)
+        finish(s, result)
     }
 }
 
-const INPUT: &str = include_str!("../../input/11/input.txt");
+pub(crate) const INPUT: &str = include_str!("../../input/11/input.txt");
+
+/// Parse the text of an input file
+pub fn parse(input: &str) -> Result<Input> {
+    let monkeys = parser::parse(input)?;
+
+    // The parser can't tell whether the numbers in each monkey's test make sense,
+    // so check them here. Each monkey's chunk of the input is used to point at the
+    // line with the problem.
+    let count = monkeys.len();
+    for (monkey, chunk) in monkeys.iter().zip(input.split("\n\n")) {
+        let line_with = |label: &str| {
+            chunk
+                .lines()
+                .find(|line| line.contains(label))
+                .unwrap_or(chunk)
+        };
+        let Rule {
+            divisor,
+            success,
+            fail,
+        } = monkey.rule;
+        if divisor == 0 {
+            let reason = "monkeys can't test whether worry levels are divisible by 0";
+            return Err(Error::parse(input, line_with("Test:"), reason));
+        }
+        for (target, label) in [(success, "If true:"), (fail, "If false:")] {
+            if target >= count {
+                let reason =
+                    format!("there's no monkey {target} to throw to, only {count} monkeys");
+                return Err(Error::parse(input, line_with(label), reason));
+            }
+        }
+    }
+    Ok(monkeys)
+}
 
 /// Parse that input!
 pub fn read() -> Input {
-    parser::parse(INPUT).unwrap()
+    parse(INPUT).unwrap()
 }
 
 #[cfg(test)]
@@ -162,4 +195,25 @@ mod tests {
         let monkeys = parser::parse(INPUT.trim()).unwrap();
         assert_eq!(monkeys.len(), 8);
     }
+
+    #[test]
+    fn check_bad_rules() {
+        let example = include_str!("../../input/11/test.txt");
+        let divisor = example.replacen("divisible by 23", "divisible by 0", 1);
+        let Err(Error::Parse { line, .. }) = parse(&divisor) else {
+            panic!("Should fail to parse!");
+        };
+        assert_eq!(line, 4);
+
+        let target = example.replacen(
+            "If false: throw to monkey 3",
+            "If false: throw to monkey 7",
+            1,
+        );
+        let Err(Error::Parse { line, reason, .. }) = parse(&target) else {
+            panic!("Should fail to parse!");
+        };
+        assert_eq!(line, 6);
+        assert!(reason.contains("no monkey 7"));
+    }
 }
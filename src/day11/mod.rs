@@ -1,39 +1,59 @@
+pub mod generate;
 pub mod input;
 pub mod part1;
 pub mod part2;
+pub mod reference;
 pub mod shared;
 
-use crate::{Output, Part};
+use crate::{Output, Part, Result, Solution, Source};
 use input::{Monkey, Operation, Rule};
 
 pub type Input = Vec<Monkey>;
 
-pub fn run(part: Part) -> Output {
-    let input = input::read();
-    match part {
-        Part::One => part1::solve(&input),
-        Part::Two => part2::solve(&input),
+pub struct Day11;
+
+impl Solution for Day11 {
+    const DAY: u8 = 11;
+    const INPUT: &'static str = input::INPUT;
+    type Input<'a> = Input;
+
+    fn parse(text: &str) -> Result<Self::Input<'_>> {
+        input::parse(text)
     }
-}
 
-pub fn run_both() -> (Output, Output) {
-    let input = input::read();
-    (part1::solve(&input), part2::solve(&input))
+    fn part1(input: &Self::Input<'_>) -> Result<Output> {
+        part1::solve(input)
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Result<Output> {
+        part2::solve(input)
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::examples;
 
     #[test]
     fn check_answer_one() {
-        let result = run(Part::One);
+        let result = Day11::run(Part::One, Source::Bundled).unwrap();
         assert_eq!(result, 56350u64);
     }
 
     #[test]
     fn check_answer_two() {
-        let result = run(Part::Two);
+        let result = Day11::run(Part::Two, Source::Bundled).unwrap();
         assert_eq!(result, 13954061248u64);
     }
+
+    #[test]
+    fn check_example_one() {
+        examples::check::<Day11>(Part::One);
+    }
+
+    #[test]
+    fn check_example_two() {
+        examples::check::<Day11>(Part::Two);
+    }
 }
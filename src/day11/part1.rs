@@ -1,8 +1,8 @@
-use crate::day11::{Input, Monkey, Output};
+use crate::day11::{Input, Monkey, Output, Result};
 use itertools::Itertools;
 
 /// Solve Day 11, Part 1
-pub fn solve(input: &Input) -> Output {
+pub fn solve(input: &Input) -> Result<Output> {
     // Initiate a cruel, cruel game played by monkeys
     let mut monkey_game: CruelGame = CruelGame::from(input);
 
@@ -11,7 +11,7 @@ pub fn solve(input: &Input) -> Output {
 
     // Return the maximum monkey business, AKA the product of the
     // number of items handled by the two most rambunctious monkeys.
-    monkey_game.max_monkey_business().into()
+    Ok(monkey_game.max_monkey_business().into())
 }
 
 /// Represents the cruel and insensitive game being played by the monkeys,
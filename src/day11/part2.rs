@@ -1,8 +1,8 @@
-use crate::day11::{Input, Monkey, Operation, Output};
+use crate::day11::{Input, Monkey, Operation, Output, Result};
 use itertools::Itertools;
 
 /// Solve Day 11, Part 2
-pub fn solve(input: &Input) -> Output {
+pub fn solve(input: &Input) -> Result<Output> {
     // Similar to last time, but somehow worse...
     let mut monkey_game = WorseGame::from(input);
 
@@ -10,7 +10,7 @@ pub fn solve(input: &Input) -> Output {
     (0..10_000).for_each(|_| monkey_game.play_rough());
 
     // Calculate and return the extreme level of monkey business
-    monkey_game.max_monkey_business().into()
+    Ok(monkey_game.max_monkey_business().into())
 }
 
 /// Represents the more intense version of the cruel, cruel game being played by
@@ -33,8 +33,9 @@ impl WorseGame {
         // identify the periodicity of the item worry values. This turns out to
         // be the least common multiple of all the monkey rule divisors by which
         // the monkeys check where to fling your things. Since all these divisors
-        // are prime, this is the product of all the divisors.
-        let absolute_limit = monkeys.iter().map(|m| m.rule.divisor).product();
+        // are prime, this is the product of all the (distinct) divisors. Some monkeys
+        // can share a divisor, and counting it twice would overflow sooner.
+        let absolute_limit = monkeys.iter().map(|m| m.rule.divisor).unique().product();
 
         WorseGame {
             items_in_flight,
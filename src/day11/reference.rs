@@ -0,0 +1,68 @@
+//! Slow, straightforward solutions to check the clever ones against. Part two here
+//! doesn't lean on the product of all the divisors. Instead, it keeps track of each
+//! item's worry level modulo every monkey's divisor separately, which works no
+//! matter what the divisors are.
+use crate::day11::{Input, Operation, Output, Result};
+use itertools::Itertools;
+use std::collections::VecDeque;
+
+/// Solve part two by playing 10,000 rounds the way the puzzle describes them: each
+/// monkey, in order, inspects its items from first to last and throws each one to
+/// the end of another monkey's list.
+pub fn part2(input: &Input) -> Result<Output> {
+    let divisors: Vec<_> = input.iter().map(|m| m.rule.divisor).collect();
+
+    // Each item is a list of its worry level modulo each monkey's divisor
+    let mut items: Vec<VecDeque<Vec<u64>>> = input
+        .iter()
+        .map(|monkey| {
+            let residues = |item: &u64| divisors.iter().map(|d| item % d).collect();
+            monkey.items.iter().map(residues).collect()
+        })
+        .collect();
+    let mut inspected = vec![0u64; input.len()];
+
+    for _ in 0..10_000 {
+        for (id, monkey) in input.iter().enumerate() {
+            while let Some(mut item) = items[id].pop_front() {
+                for (residue, divisor) in item.iter_mut().zip(divisors.iter()) {
+                    let (worry, divisor) = (*residue as u128, *divisor as u128);
+                    let worry = match monkey.operation {
+                        Operation::Add(n) => worry + n as u128,
+                        Operation::Mult(n) => worry * n as u128,
+                        Operation::Square => worry * worry,
+                    };
+                    *residue = (worry % divisor) as u64;
+                }
+                let target = match item[id] == 0 {
+                    true => monkey.rule.success,
+                    false => monkey.rule.fail,
+                };
+                items[target].push_back(item);
+                inspected[id] += 1;
+            }
+        }
+    }
+
+    let monkey_business = inspected.iter().sorted().rev().take(2).product::<u64>();
+    Ok(monkey_business.into())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::day11::generate::generate;
+    use crate::day11::{input, part2};
+    use crate::random::check_against_reference;
+
+    #[test]
+    fn check_answer_two() {
+        let result = part2(&input::read()).unwrap();
+        assert_eq!(result, 13954061248u64);
+    }
+
+    #[test]
+    fn check_part_two() {
+        check_against_reference(generate, &[2, 4, 7], part2::solve, part2);
+    }
+}
@@ -1,4 +1,6 @@
 use crate::day12::Input;
+use crate::grid::{Grid, Offset, Point};
+use crate::{Error, Result};
 use std::collections::HashMap;
 
 /// Represents a hill on the map. Wraps the hill height and indicates
@@ -42,78 +44,55 @@ impl Hill {
 }
 
 // Type alias we'll use to refer to hills that can be reached from the current hill
-type Neighbors = [Option<(usize, usize)>; 4];
+type Neighbors = [Option<Point>; 4];
 
-/// Represents a map of all the hills in the area. Includes the two-dimensional
-/// listing of hills, the positions of the starting hill and the end hill, and
-/// a hashmap that will serve as an adjacency list for hills and the neighbors
-/// that can be reached from them.
+/// Represents a map of all the hills in the area. Includes the grid of hills, the
+/// positions of the starting hill and the end hill, and a hashmap that will serve
+/// as an adjacency list for hills and the neighbors that can be reached from them.
 pub struct HillMap {
-    pub hills: Vec<Vec<Hill>>,
-    pub graph: HashMap<(usize, usize), Neighbors>,
-    pub start_at: (usize, usize),
-    pub end_at: (usize, usize),
+    pub hills: Grid<Hill>,
+    pub graph: HashMap<Point, Neighbors>,
+    pub start_at: Point,
+    pub end_at: Point,
 }
 
-/// Convert the input string into a `HillMap`.
-impl From<&str> for HillMap {
-    fn from(value: &str) -> Self {
-        // Start by building up the 2D vector of hills, in the same shape as the
-        // characters in the input.
-        let hills: Vec<Vec<_>> = value
-            .lines()
-            .map(|row| row.chars().map(Hill::from).collect())
-            .collect();
-
-        // Now we convert the vector of hills into a mapping we can use for a
+/// Convert a grid of hills into a `HillMap`.
+impl From<Grid<Hill>> for HillMap {
+    fn from(hills: Grid<Hill>) -> Self {
+        // Now we convert the grid of hills into a mapping we can use for a
         // nice graph algorithm (more easily). We'll precompute which neighbors
         // are reachable here to save processing later.
         let mut graph = HashMap::new();
 
-        // Make sure we know the dimensions of the grid
-        let last_row = hills.len().saturating_sub(1);
-        let last_col = hills
-            .first()
-            .map(|r| r.len())
-            .unwrap_or_default()
-            .saturating_sub(1);
-
         // Prepare to identify the start and end locations
-        let mut start_at = (0, 0);
-        let mut end_at = (0, 0);
-
-        // For each row and column in our vector of hills...
-        for (row_idx, row) in hills.iter().enumerate() {
-            for (col_idx, hill) in row.iter().enumerate() {
-                // Create and fill in the array of neighbors in order of direction
-                // from up, left, down, and right. I'm using arrays here because I
-                // believe in my heart that arrays are more efficient that vectors,
-                // and I won't be convinced otherwise! (today) We're doing our
-                // bounds checking here as well, just to save on possible edge cases
-                // later on.
-                let mut neighbors = [None; 4];
-                if row_idx > 0 && hill.can_reach(&hills[row_idx - 1][col_idx]) {
-                    neighbors[0] = Some((row_idx - 1, col_idx));
-                }
-                if col_idx > 0 && hill.can_reach(&hills[row_idx][col_idx - 1]) {
-                    neighbors[1] = Some((row_idx, col_idx - 1));
-                }
-                if row_idx < last_row && hill.can_reach(&hills[row_idx + 1][col_idx]) {
-                    neighbors[2] = Some((row_idx + 1, col_idx));
-                }
-                if col_idx < last_col && hill.can_reach(&hills[row_idx][col_idx + 1]) {
-                    neighbors[3] = Some((row_idx, col_idx + 1));
+        let mut start_at = Point::default();
+        let mut end_at = Point::default();
+
+        // For each hill in our grid of hills...
+        for (pos, hill) in hills.iter() {
+            // Create and fill in the array of neighbors in order of direction
+            // from up, right, down, and left. I'm using arrays here because I
+            // believe in my heart that arrays are more efficient that vectors,
+            // and I won't be convinced otherwise! (today) The grid does our
+            // bounds checking for us, so there's nothing to reach off the edges.
+            let mut neighbors = [None; 4];
+            for (slot, offset) in neighbors.iter_mut().zip(Offset::ORTHOGONAL) {
+                let neighbor = pos + offset;
+                if let Some(other) = hills.get(neighbor) {
+                    if hill.can_reach(other) {
+                        *slot = Some(neighbor);
+                    }
                 }
+            }
 
-                // When we encounter the start and end hills, we mark those as special.
-                if let Hill::Start(_) = hill {
-                    start_at = (row_idx, col_idx);
-                }
-                if let Hill::End(_) = hill {
-                    end_at = (row_idx, col_idx);
-                }
-                graph.insert((row_idx, col_idx), neighbors);
+            // When we encounter the start and end hills, we mark those as special.
+            if let Hill::Start(_) = hill {
+                start_at = pos;
             }
+            if let Hill::End(_) = hill {
+                end_at = pos;
+            }
+            graph.insert(pos, neighbors);
         }
 
         // All done!
@@ -126,11 +105,28 @@ impl From<&str> for HillMap {
     }
 }
 
-const INPUT: &str = include_str!("../../input/12/input.txt");
+pub(crate) const INPUT: &str = include_str!("../../input/12/input.txt");
+
+/// Parse the text of an input file
+pub fn parse(input: &str) -> Result<Input> {
+    // `HillMap::from` expects a rectangle of hills with one start and one end,
+    // so check for that before handing it over.
+    let hills = Grid::parse(input, |c| match c {
+        'S' | 'E' | 'a'..='z' => Ok(Hill::from(c)),
+        _ => Err("not a hill"),
+    })?;
+    for (marker, name) in [('S', "start"), ('E', "end")] {
+        if input.matches(marker).count() != 1 {
+            let reason = format!("the map needs exactly one {name} hill ('{marker}')");
+            return Err(Error::parse(input, "", reason));
+        }
+    }
+    Ok(HillMap::from(hills))
+}
 
 /// Parse that input!
 pub fn read() -> Input {
-    HillMap::from(INPUT)
+    parse(INPUT).unwrap()
 }
 
 #[cfg(test)]
@@ -139,16 +135,19 @@ mod test {
 
     #[test]
     fn name() {
-        let hill_map = HillMap::from(INPUT);
-        assert_eq!(
-            hill_map.hills.iter().flat_map(|r| r.iter()).count(),
-            hill_map.graph.keys().len()
-        );
-        assert_eq!(hill_map.hills[20][0], Hill::Start(0));
-        assert_eq!(hill_map.hills[20][132], Hill::End(25));
+        let hill_map = read();
+        assert_eq!(hill_map.hills.iter().count(), hill_map.graph.keys().len());
+        assert_eq!(hill_map.hills[Point(0, 20)], Hill::Start(0));
+        assert_eq!(hill_map.hills[Point(132, 20)], Hill::End(25));
+        assert_eq!(hill_map.start_at, Point(0, 20));
         assert_eq!(
-            *hill_map.graph.get(&(20, 0)).unwrap(),
-            [Some((19, 0)), None, Some((21, 0)), Some((20, 1))]
+            *hill_map.graph.get(&Point(0, 20)).unwrap(),
+            [
+                Some(Point(0, 19)),
+                Some(Point(1, 20)),
+                Some(Point(0, 21)),
+                None
+            ]
         );
     }
 }
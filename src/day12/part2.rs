@@ -1,10 +1,13 @@
-use crate::day12::{Hill, HillMap, Input, Output};
-use core::cmp::Reverse;
+use crate::day12::route::{self, Route};
+use crate::day12::{Hill, HillMap, Input, Output, Result};
+use crate::grid::{Grid, Point};
+use crate::search::{self, Visited};
+use crate::Error;
 use std::cmp::min;
-use std::collections::{BinaryHeap, HashMap};
+use std::collections::HashMap;
 
 /// Solve Day 12, Part 2
-pub fn solve(input: &Input) -> Output {
+pub fn solve(input: &Input) -> Result<Output> {
     // Turns out we need to 'invert' our `HillMap` in order to efficiently find the
     // shortest path to _any_ hill with a height of 0.
     let descent_map = DescentMap::from(input);
@@ -17,25 +20,29 @@ pub fn solve(input: &Input) -> Output {
     // it's the shortest path to a short hill found so far. If so, record it!
     let mut shortest_path = u32::MAX;
     for (pos, steps_to_pos) in steps_to_short_hills.iter() {
-        let (row, col) = *pos;
-        let Hill::Hill(0) = descent_map.hills[row][col] else { continue; };
+        let Hill::Hill(0) = descent_map.hills[*pos] else { continue; };
         shortest_path = min(shortest_path, *steps_to_pos);
     }
 
-    // Return the shortest path to a short hill
-    shortest_path.into()
+    // Return the shortest path to a short hill, if we found one
+    if shortest_path == u32::MAX {
+        return Err(Error::no_solution(
+            "There's no path from the summit to a short hill!",
+        ));
+    }
+    Ok(shortest_path.into())
 }
 
 // Type alias we'll use here to refer to the hills that can reach the current hill
-type Neighbors = [Option<(usize, usize)>; 4];
+type Neighbors = [Option<Point>; 4];
 
 // Very much like the `HillMap`. The biggest difference is that now `graph` represents
 // relationships between hills that can be moved _to_ and the hills that can reach
 // them (the reverse of the relationship for `HillMap`).
 struct DescentMap {
-    hills: Vec<Vec<Hill>>,
-    graph: HashMap<(usize, usize), Neighbors>,
-    summit: (usize, usize),
+    hills: Grid<Hill>,
+    graph: HashMap<Point, Neighbors>,
+    summit: Point,
 }
 
 // Produce a `DescentMap` from a reference to a `HillMap`.
@@ -44,7 +51,7 @@ impl From<&HillMap> for DescentMap {
         // We need to invert the graph, so that we can essentially walk backwards
         // starting from the summit (our previous end point) down to all those
         // short hills.
-        let mut graph: HashMap<(usize, usize), Neighbors> = HashMap::new();
+        let mut graph: HashMap<Point, Neighbors> = HashMap::new();
 
         // For each entry in the `HillMap`s graph...
         for (pos, neighbors) in hill_map.graph.iter() {
@@ -70,7 +77,7 @@ impl From<&HillMap> for DescentMap {
         }
 
         // Copy the `hills` and `end_at` fields from the `HillMap`
-        let hills = hill_map.hills.to_vec();
+        let hills = hill_map.hills.clone();
         let summit = hill_map.end_at;
 
         // Return the new `DescentMap` with the inverted graph.
@@ -86,35 +93,38 @@ impl DescentMap {
     /// Identify and return the minimum number of steps every other hill is from
     /// the summit as a HashMap where the keys are hill positions and the values
     /// are the number of steps from the summit.
-    pub fn shortest_paths_from_summit(&self) -> HashMap<(usize, usize), u32> {
-        // The procedure here is the same Dijkstra's algorithm from part one, just
-        // walking down from the summit instead of up from the start space.
-        let start_at = self.summit;
-        let mut open = BinaryHeap::from([(Reverse(0), start_at)]);
-        let mut steps = HashMap::from([(start_at, 0)]);
-
-        // While there are still hills to explore...
-        while let Some((_, pos)) = open.pop() {
-            // No need for an early return here, we want to find a path to _all_ the
-            // other hills.
+    pub fn shortest_paths_from_summit(&self) -> HashMap<Point, u32> {
+        // Returns a mapping of the fewest steps to every hill from the summit
+        let visited = self.search_from_summit();
+        visited.costs().map(|(pos, steps)| (*pos, *steps)).collect()
+    }
 
-            // As before, we check all the neighbors and any time we're able to
-            // reach that neighbor by the shortest path found so far, we add that
-            // neighbor to the open set.
-            let Some(neighbors) = self.graph.get(&pos) else { continue; };
-            for maybe_neighbor in neighbors {
-                let Some(neighbor) = maybe_neighbor else { continue; };
-                let next_steps: u32 = steps.get(&pos).unwrap() + 1;
-                let curr_steps: u32 = *steps.get(neighbor).unwrap_or(&u32::MAX);
-                if next_steps >= curr_steps {
-                    continue;
-                }
-                open.push((Reverse(next_steps), *neighbor));
-                steps.insert(*neighbor, next_steps);
-            }
-        }
+    /// The procedure here is the same search as part one, just walking down
+    /// from the summit instead of up from the start space, and not stopping
+    /// until we've found a path to _all_ the other hills.
+    fn search_from_summit(&self) -> Visited<Point, u32> {
+        let neighbors = |pos: &Point| {
+            let reachable_from = self.graph.get(pos).into_iter().flatten().flatten();
+            reachable_from.map(|neighbor| (*neighbor, 1))
+        };
+        search::reachable(self.summit, neighbors)
+    }
+}
 
-        // Returns a mapping of the fewest steps to every hill from the summit
-        steps
+impl HillMap {
+    /// The shortest route up to the summit from any of the short hills, found by
+    /// walking down from the summit like `solve` does and then turning the way
+    /// back to the closest short hill around. If more than one short hill is just
+    /// as close, the one nearest the top left wins.
+    pub fn route_from_lowest_hill(&self) -> Option<Route> {
+        let descent_map = DescentMap::from(self);
+        let visited = descent_map.search_from_summit();
+        let (closest, _) = visited
+            .costs()
+            .filter(|(pos, _)| descent_map.hills[**pos] == Hill::Hill(0))
+            .min_by_key(|(pos, steps)| (**steps, pos.1, pos.0))?;
+        let mut path = visited.path_to(closest)?;
+        path.reverse();
+        Some(route::from_points(path))
     }
 }
@@ -0,0 +1,87 @@
+use crate::day12::{Hill, HillMap};
+use crate::grid::{Offset, Point};
+
+/// A route over the hills, as the (row, col) position of every hill along the way,
+/// from the first hill to the last one (both included)
+pub type Route = Vec<(usize, usize)>;
+
+/// Turn the points the search hands back into (row, col) positions
+pub(crate) fn from_points(points: impl IntoIterator<Item = Point>) -> Route {
+    points
+        .into_iter()
+        .map(|Point(col, row)| (row as usize, col as usize))
+        .collect()
+}
+
+impl Hill {
+    /// The letter for this hill on the map
+    pub fn glyph(&self) -> char {
+        match self {
+            Hill::Start(_) => 'S',
+            Hill::End(_) => 'E',
+            Hill::Hill(height) => (b'a' + height) as char,
+        }
+    }
+}
+
+impl HillMap {
+    /// Draw the map with a `route` over it, like the picture in the puzzle. Every
+    /// hill on the route gets an arrow pointing to the next hill, except the last
+    /// one, which keeps its letter (so you can see where the route ends). Hills off
+    /// the route keep their letters too, so you can see why the route goes where it
+    /// goes.
+    pub fn render_route(&self, route: &[(usize, usize)]) -> String {
+        let mut glyphs = self.hills.map(Hill::glyph);
+        for pair in route.windows(2) {
+            let [(row, col), (next_row, next_col)] = [pair[0], pair[1]];
+            let step = Offset(
+                next_col as isize - col as isize,
+                next_row as isize - row as isize,
+            );
+            glyphs[(row, col)] = match step {
+                Offset::UP => '^',
+                Offset::RIGHT => '>',
+                Offset::DOWN => 'v',
+                Offset::LEFT => '<',
+                _ => '?',
+            };
+        }
+        glyphs.render(|glyph| *glyph)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::day12::input::parse;
+
+    const EXAMPLE: &str = include_str!("../../input/12/test.txt");
+
+    #[test]
+    fn check_routes() {
+        let hill_map = parse(EXAMPLE).unwrap();
+
+        // The route up from the start is the one from the puzzle, give or take
+        // which of the equally short ways it picks through the first few hills
+        let climb = hill_map.route_to_summit(hill_map.start_at).unwrap();
+        assert_eq!(climb.len(), 32);
+        assert_eq!((climb[0], climb[31]), ((0, 0), (2, 5)));
+        let drawn = hill_map.render_route(&climb);
+        assert_eq!(drawn.lines().nth(2).unwrap().chars().nth(5), Some('E'));
+        assert_eq!(drawn.matches(['^', '>', 'v', '<']).count(), 31);
+
+        // Every step is to a neighbor the graph says we can climb to
+        for pair in climb.windows(2) {
+            let [(row, col), (next_row, next_col)] = [pair[0], pair[1]];
+            let here = Point(col as isize, row as isize);
+            let next = Point(next_col as isize, next_row as isize);
+            assert!(hill_map.graph[&here].contains(&Some(next)));
+        }
+
+        // The best route from a lowest hill starts at an 'a' and is 29 steps long
+        let hike = hill_map.route_from_lowest_hill().unwrap();
+        assert_eq!(hike.len(), 30);
+        assert_eq!(hill_map.hills[hike[0]], Hill::Hill(0));
+        assert_eq!(hike[29], (2, 5));
+    }
+}
@@ -0,0 +1,55 @@
+use crate::day13::{Input, Packet, PacketPair};
+use crate::random::Rng;
+
+/// Generate `size` random pairs of packets. Packets are lists nested up to five deep,
+/// and include empty lists and integers of more than one digit.
+pub fn generate(rng: &mut Rng, size: usize) -> Input {
+    (0..size)
+        .map(|_| PacketPair(list(rng, 0), list(rng, 0)))
+        .collect()
+}
+
+/// Generate a random list of packets, at the given depth of nesting
+fn list(rng: &mut Rng, depth: usize) -> Packet {
+    let length = rng.range(0..=5);
+    let contents = (0..length)
+        .map(|_| match depth < 5 && rng.chance(0.3) {
+            true => list(rng, depth + 1),
+            false if rng.chance(0.9) => Packet::Integer(rng.range(0..=10)),
+            false => Packet::Integer(rng.range(0..=u8::MAX)),
+        })
+        .collect();
+    Packet::List(contents)
+}
+
+/// Print the pairs of packets the way they're written in the input
+pub fn print(input: &Input) -> String {
+    let pairs: Vec<_> = input
+        .iter()
+        .map(|PacketPair(first, second)| format!("{}\n{}\n", packet(first), packet(second)))
+        .collect();
+    pairs.join("\n")
+}
+
+/// Print a single packet
+fn packet(packet: &Packet) -> String {
+    match packet {
+        Packet::Integer(value) => value.to_string(),
+        Packet::List(contents) => {
+            let contents: Vec<_> = contents.iter().map(self::packet).collect();
+            format!("[{}]", contents.join(","))
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::day13::input::parse;
+    use crate::random::check_round_trip;
+
+    #[test]
+    fn check_generated_input() {
+        check_round_trip(generate, print, parse);
+    }
+}
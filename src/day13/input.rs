@@ -1,4 +1,5 @@
 use crate::day13::Input;
+use crate::Result;
 
 /// Represnts a Packet. Packet data consists of lists and integer (that's what the
 /// puzzle says, anyway).
@@ -9,14 +10,14 @@ pub enum Packet {
 }
 
 /// Represents a pair of packets. Riveting stuff!
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub struct PacketPair(pub Packet, pub Packet);
 
 /// Here's where the magic happens. This module wraps the parsers for the list of
 /// packet pairs presented in the input.
 pub mod parser {
     use super::*;
-    use anyhow::{anyhow, Result};
+    use crate::error::{finish, Result};
     use nom::{
         branch::alt,
         bytes::complete::tag,
@@ -24,7 +25,7 @@ pub mod parser {
         combinator::map,
         multi::{separated_list0, separated_list1},
         sequence::{delimited, separated_pair},
-        Finish, IResult,
+        IResult,
     };
 
     /// Nom parser for "2" -> Packet::Integer(2)
@@ -58,17 +59,20 @@ pub mod parser {
 
     /// Parses a list of packet pairs separated by an empty line into a `Vec<PacketPair>`
     pub fn parse(s: &str) -> Result<Vec<PacketPair>> {
-        let result = separated_list1(tag("\n\n"), packet_pair)(s).finish();
-        let (_, pair_list) = result.map_err(|e| anyhow!("{e}"))?;
-        Ok(pair_list)
+        finish(s, separated_list1(tag("\n\n"), packet_pair)(s))
     }
 }
 
-const INPUT: &str = include_str!("../../input/13/input.txt");
+pub(crate) const INPUT: &str = include_str!("../../input/13/input.txt");
+
+/// Parse the text of an input file
+pub fn parse(input: &str) -> Result<Input> {
+    parser::parse(input)
+}
 
 /// Parse that input!
 pub fn read() -> Input {
-    parser::parse(INPUT).unwrap()
+    parse(INPUT).unwrap()
 }
 
 #[cfg(test)]
@@ -1,39 +1,58 @@
+pub mod generate;
 pub mod input;
 pub mod part1;
 pub mod part2;
 pub mod shared;
 
-use crate::{Output, Part};
+use crate::{Output, Part, Result, Solution, Source};
 use input::{parser, Packet, PacketPair};
 
 pub type Input = Vec<PacketPair>;
 
-pub fn run(part: Part) -> Output {
-    let input = input::read();
-    match part {
-        Part::One => part1::solve(&input),
-        Part::Two => part2::solve(&input),
+pub struct Day13;
+
+impl Solution for Day13 {
+    const DAY: u8 = 13;
+    const INPUT: &'static str = input::INPUT;
+    type Input<'a> = Input;
+
+    fn parse(text: &str) -> Result<Self::Input<'_>> {
+        input::parse(text)
     }
-}
 
-pub fn run_both() -> (Output, Output) {
-    let input = input::read();
-    (part1::solve(&input), part2::solve(&input))
+    fn part1(input: &Self::Input<'_>) -> Result<Output> {
+        part1::solve(input)
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Result<Output> {
+        part2::solve(input)
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::examples;
 
     #[test]
     fn check_answer_one() {
-        let result = run(Part::One);
+        let result = Day13::run(Part::One, Source::Bundled).unwrap();
         assert_eq!(result, 6072);
     }
 
     #[test]
     fn check_answer_two() {
-        let result = run(Part::Two);
+        let result = Day13::run(Part::Two, Source::Bundled).unwrap();
         assert_eq!(result, 22184);
     }
+
+    #[test]
+    fn check_example_one() {
+        examples::check::<Day13>(Part::One);
+    }
+
+    #[test]
+    fn check_example_two() {
+        examples::check::<Day13>(Part::Two);
+    }
 }
@@ -1,7 +1,7 @@
-use crate::day13::{Input, Output, PacketPair};
+use crate::day13::{Input, Output, PacketPair, Result};
 
 /// Solve Day 13, Part 1
-pub fn solve(input: &Input) -> Output {
+pub fn solve(input: &Input) -> Result<Output> {
     let mut total = 0; // The total index value of proper sorted pairs
 
     // For each pair of packets...
@@ -14,7 +14,7 @@ pub fn solve(input: &Input) -> Output {
         // Otherwise, add the value of its index to the total
         total += (idx as u32) + 1; // Packets are 1-indexed
     }
-    total.into()
+    Ok(total.into())
 }
 
 impl PacketPair {
@@ -1,7 +1,7 @@
-use crate::day13::{parser, Input, Output, Packet, PacketPair};
+use crate::day13::{parser, Input, Output, Packet, PacketPair, Result};
 
 /// Solve Day 13, Part 2
-pub fn solve(input: &Input) -> Output {
+pub fn solve(input: &Input) -> Result<Output> {
     use Packet::*; // For syntax
 
     // Define the two divider packets and put them in an array to add them
@@ -31,7 +31,7 @@ pub fn solve(input: &Input) -> Output {
             total *= (idx as u32) + 1;
         }
     }
-    total.into()
+    Ok(total.into())
 }
 
 // It's easier to flatten the packet pairs into a 1D list when we can
@@ -0,0 +1,71 @@
+use crate::day14::Point;
+use crate::random::Rng;
+
+/// Generate `size` random paths of rock. Each path is a few straight lines, either
+/// horizontal or vertical, somewhere below and around where the sand falls in at
+/// (500, 0).
+pub fn generate(rng: &mut Rng, size: usize) -> Vec<Vec<Point>> {
+    (0..size)
+        .map(|_| {
+            let mut point = Point(rng.range(450..=550), rng.range(1..=170));
+            let mut path = vec![point];
+            for _ in 0..rng.range(1..=5) {
+                point = match rng.chance(0.5) {
+                    true => Point(rng.range(450..=550), point.1),
+                    false => Point(point.0, rng.range(1..=170)),
+                };
+                path.push(point);
+            }
+            path
+        })
+        .collect()
+}
+
+/// Print the paths of rock the way they're written in the input
+pub fn print(paths: &[Vec<Point>]) -> String {
+    let mut out = String::new();
+    for path in paths {
+        let points: Vec<_> = path.iter().map(|Point(x, y)| format!("{x},{y}")).collect();
+        out += &points.join(" -> ");
+        out.push('\n');
+    }
+    out
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::day14::input::parse;
+    use std::collections::HashSet;
+
+    /// Fill in every point along each path of rock
+    fn rocks(paths: Vec<Vec<Point>>) -> HashSet<Point> {
+        let mut rocks = HashSet::new();
+        for path in paths {
+            for pair in path.windows(2) {
+                let (Point(x1, y1), Point(x2, y2)) = (pair[0], pair[1]);
+                for x in x1.min(x2)..=x1.max(x2) {
+                    for y in y1.min(y2)..=y1.max(y2) {
+                        rocks.insert(Point(x, y));
+                    }
+                }
+            }
+        }
+        rocks
+    }
+
+    #[test]
+    fn check_generated_input() {
+        // The input gets parsed into the set of points with rocks in them, so
+        // compare against the points along the generated paths.
+        for seed in 0..25 {
+            for size in [1, 2, 10, 100] {
+                let paths = generate(&mut Rng::new(seed), size);
+                let text = print(&paths);
+                for text in [text.as_str(), text.trim_end()] {
+                    assert_eq!(parse(text).unwrap(), rocks(paths.clone()), "seed {seed}");
+                }
+            }
+        }
+    }
+}
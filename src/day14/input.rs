@@ -1,68 +1,25 @@
 use crate::day14::Input;
+use crate::grid::{Offset, Point};
+use crate::Result;
 use itertools::Itertools;
-use std::cmp::Ordering;
 use std::collections::HashSet;
-use std::ops::{Add, AddAssign};
-
-/// Represents a point on the 2D grid where the rocks are
-#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
-pub struct Point(pub u32, pub u32);
-
-/// An offset to a point, used to adjust `Point`s
-#[derive(Debug, Default, Clone, Copy)]
-pub struct Offset(pub i32, pub i32);
-
-impl Point {
-    /// Calculate the unit offset from one `Point` to another. This represents
-    /// the incremental step that, if taken repeatedly, will take you from
-    /// `other` to `self`.
-    fn offset_from(&self, other: &Self) -> Offset {
-        let Point(x1, y1) = self;
-        let Point(x2, y2) = other;
-        match (x1.cmp(x2), y1.cmp(y2)) {
-            (Ordering::Less, Ordering::Less) => Offset(-1, -1),
-            (Ordering::Less, Ordering::Equal) => Offset(-1, 0),
-            (Ordering::Less, Ordering::Greater) => Offset(-1, 1),
-            (Ordering::Equal, Ordering::Less) => Offset(0, -1),
-            (Ordering::Equal, Ordering::Equal) => Offset(0, 0),
-            (Ordering::Equal, Ordering::Greater) => Offset(0, 1),
-            (Ordering::Greater, Ordering::Less) => Offset(1, -1),
-            (Ordering::Greater, Ordering::Equal) => Offset(1, 0),
-            (Ordering::Greater, Ordering::Greater) => Offset(1, 1),
-        }
-    }
-}
-
-/// Implementation to allow adding an `Offset` to a `Point` to
-/// move the `Point` in 2D space.
-impl Add<Offset> for Point {
-    type Output = Point;
-
-    fn add(self, rhs: Offset) -> Self::Output {
-        let Point(px, py) = self;
-        let Offset(ox, oy) = rhs;
-        let x = px.saturating_add_signed(ox);
-        let y = py.saturating_add_signed(oy);
-        Point(x, y)
-    }
-}
 
 /// Module wrapping the input parser to parse lines from the input.
 mod parser {
     use super::*;
-    use anyhow::{anyhow, Result};
+    use crate::error::{finish, Result};
     use nom::{
         bytes::complete::tag,
         character::complete::{newline, u32},
         multi::separated_list1,
         sequence::separated_pair,
-        Finish, IResult,
+        IResult,
     };
 
     /// Nom parser for "15,30" -> Point(15, 30)
     fn point(s: &str) -> IResult<&str, Point> {
         let (s, (first, second)) = separated_pair(u32, tag(","), u32)(s)?;
-        Ok((s, Point(first, second)))
+        Ok((s, Point(first as isize, second as isize)))
     }
 
     /// Nom parser to convert a list like
@@ -78,8 +35,7 @@ mod parser {
 
     /// Parse the input file into a list of lists of `Point`s
     pub fn parse(s: &str) -> Result<Vec<Vec<Point>>> {
-        let (_, result) = point_lists(s).finish().map_err(|e| anyhow!("{e}"))?;
-        Ok(result)
+        finish(s, point_lists(s))
     }
 }
 
@@ -104,7 +60,9 @@ trait RockLine {
 impl RockLine for (Point, Point) {
     fn rock_line(self) -> RockLineIter {
         let (start, end) = self;
-        let offset = end.offset_from(&start);
+        // The unit step that, if taken repeatedly, will take you from `start`
+        // to `end`
+        let offset = (end - start).signum();
         RockLineIter {
             start,
             end,
@@ -138,12 +96,12 @@ impl Iterator for RockLineIter {
     }
 }
 
-const INPUT: &str = include_str!("../../input/14/input.txt");
+pub(crate) const INPUT: &str = include_str!("../../input/14/input.txt");
 
-/// Parse the input!
-pub fn read() -> Input {
+/// Parse the text of an input file
+pub fn parse(input: &str) -> Result<Input> {
     // List of lists of `Point`s, basically the input file
-    let point_lists = parser::parse(INPUT).unwrap();
+    let point_lists = parser::parse(input)?;
 
     // The set of points that contain obstacles (rocks)
     let mut obstacles = HashSet::new();
@@ -161,5 +119,10 @@ pub fn read() -> Input {
     }
 
     // Return our set of points that sand can't cross
-    obstacles
+    Ok(obstacles)
+}
+
+/// Parse the input!
+pub fn read() -> Input {
+    parse(INPUT).unwrap()
 }
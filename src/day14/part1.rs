@@ -1,8 +1,9 @@
-use crate::day14::{Input, Offset, Output, Point};
+use crate::day14::{Input, Offset, Output, Point, Result};
+use crate::Error;
 use std::collections::HashSet;
 
 /// Solve Day 14, Part 1
-pub fn solve(input: &Input) -> Output {
+pub fn solve(input: &Input) -> Result<Output> {
     // Turn that set of impassable points into a `CaveMap`
     let mut cave_map = CaveMap::new(input.clone());
 
@@ -15,19 +16,21 @@ pub fn solve(input: &Input) -> Output {
         // abyss, we stop and return the current grain count minus one as
         // the number of grains _before_ this poor soul was lost to the void.
         if let GrainStatus::LostToTheAbyss = cave_map.add_sand() {
-            return (grains - 1).into();
+            return Ok((grains - 1).into());
         }
     }
 
     // If we ever get here, something has gone horribly wrong
-    unreachable!()
+    Err(Error::no_solution(
+        "No sand fell into the abyss after 10,000 grains!",
+    ))
 }
 
 /// This enum represents the status of a grain of sand flowing down from the
 /// ceiling. May represent the result of a single step or the entire flow of
 /// that grain from start to finish.
 #[derive(Debug)]
-enum GrainStatus {
+pub enum GrainStatus {
     MovedTo(Point),
     StoppedAt(Point),
     LostToTheAbyss,
@@ -41,7 +44,7 @@ enum GrainStatus {
 pub struct CaveMap {
     pub obstacles: HashSet<Point>,
     pub entrypoint: Point,
-    pub depth: u32,
+    pub depth: isize,
 }
 
 impl CaveMap {
@@ -67,7 +70,7 @@ impl CaveMap {
 
     /// Add one grain of sand to the map and follow it as it flows down. Returns
     /// the final status of the grain.
-    fn add_sand(&mut self) -> GrainStatus {
+    pub fn add_sand(&mut self) -> GrainStatus {
         let mut sand = self.entrypoint; // Sand flows in from here
 
         // Infinite loop!!! It'll stop eventually (we hope).
@@ -1,8 +1,8 @@
-use crate::day14::{CaveMap, Input, Offset, Output, Point};
+use crate::day14::{CaveMap, Input, Offset, Output, Point, Result};
 use std::collections::{HashSet, VecDeque};
 
 /// Solve Day 14, Part 2
-pub fn solve(input: &Input) -> Output {
+pub fn solve(input: &Input) -> Result<Output> {
     // Turn that set of impassable points into a `CaveMap`
     let cave_map = CaveMap::new(input.clone());
 
@@ -12,7 +12,7 @@ pub fn solve(input: &Input) -> Output {
 
     // Pour sand into the cave until it fills up to the entrypoint
     // and report the number of grains it took to do so.
-    fill_map.sand_capacity().into()
+    Ok(fill_map.sand_capacity().into())
 }
 
 /// A slight variation on the `CaveMap`. Mostly using a new struct for different
@@ -21,7 +21,7 @@ pub fn solve(input: &Input) -> Output {
 pub struct FillMap {
     obstacles: HashSet<Point>,
     entrypoint: Point,
-    depth: u32,
+    depth: isize,
 }
 
 impl FillMap {
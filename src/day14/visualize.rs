@@ -0,0 +1,85 @@
+use crate::day14::part1::GrainStatus;
+use crate::day14::{CaveMap, Input, Point, Result};
+use crate::visualize::{Frame, Sink};
+
+/// Pour sand into the cave from part one, one grain at a time, drawing the cave
+/// each time a grain comes to rest. Stops when the first grain falls into the abyss.
+pub fn visualize(input: &Input, sink: &mut dyn Sink) -> Result<()> {
+    let mut cave_map = CaveMap::new(input.clone());
+    sink.frame(&Cave::new(input, &cave_map))?;
+    while let GrainStatus::StoppedAt(_) = cave_map.add_sand() {
+        sink.frame(&Cave::new(input, &cave_map))?;
+    }
+    Ok(())
+}
+
+/// The cave, drawn like the puzzle description draws it. Everything in the map
+/// that isn't rock must be sand.
+struct Cave<'a> {
+    rock: &'a Input,
+    cave_map: &'a CaveMap,
+    left: isize,
+    width: usize,
+}
+
+impl<'a> Cave<'a> {
+    fn new(rock: &'a Input, cave_map: &'a CaveMap) -> Self {
+        // Sand can only come to rest between the leftmost and rightmost rock
+        let xs = rock.iter().map(|Point(x, _)| *x);
+        let left = xs.clone().min().unwrap_or(500).min(500);
+        let right = xs.max().unwrap_or(500).max(500);
+        let width = (right - left + 1) as usize;
+        Cave {
+            rock,
+            cave_map,
+            left,
+            width,
+        }
+    }
+}
+
+impl Frame for Cave<'_> {
+    fn size(&self) -> (usize, usize) {
+        (self.cave_map.depth as usize + 1, self.width)
+    }
+
+    fn glyph(&self, row: usize, col: usize) -> char {
+        let point = Point(self.left + col as isize, row as isize);
+        match point {
+            _ if self.rock.contains(&point) => '#',
+            _ if self.cave_map.obstacles.contains(&point) => 'o',
+            _ if point == self.cave_map.entrypoint => '+',
+            _ => '.',
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::day14::input::parse;
+    use crate::visualize::Frames;
+
+    #[test]
+    fn check_example() {
+        let input = parse(include_str!("../../input/14/test.txt")).unwrap();
+        let mut frames = Frames::default();
+        visualize(&input, &mut frames).unwrap();
+
+        // One frame for the empty cave, and one for each of the 24 grains that
+        // come to rest. The last one should match the puzzle description.
+        assert_eq!(frames.0.len(), 25);
+        let expected = "\
+            ......+...\n\
+            ..........\n\
+            ......o...\n\
+            .....ooo..\n\
+            ....#ooo##\n\
+            ...o#ooo#.\n\
+            ..###ooo#.\n\
+            ....oooo#.\n\
+            .o.ooooo#.\n\
+            #########.\n";
+        assert_eq!(frames.0[24], expected);
+    }
+}
@@ -0,0 +1,82 @@
+use crate::day15::{Input, Point, Sensor};
+use crate::random::Rng;
+
+/// Generate `size` random sensors, each with the beacon closest to it. Sensors and
+/// beacons can be anywhere from -1,000,000 to 5,000,000 in either direction, so
+/// plenty of coordinates come out negative. The sensors are sorted, same as `parse`
+/// leaves them.
+pub fn generate(rng: &mut Rng, size: usize) -> Input {
+    let mut sensors: Vec<_> = (0..size)
+        .map(|_| {
+            let location = Point(
+                rng.range(-1_000_000..=5_000_000),
+                rng.range(-1_000_000..=5_000_000),
+            );
+            let Point(x, y) = location;
+            let beacon = Point(
+                x + rng.range(-1_000_000..=1_000_000),
+                y + rng.range(-1_000_000..=1_000_000),
+            );
+            Sensor::from((location, beacon))
+        })
+        .collect();
+    sensors.sort_unstable();
+    sensors
+}
+
+/// Generate sensors that cover every position with coordinates from 0 to `size`
+/// except one, where the distress beacon must be. Sensors keep getting placed on
+/// positions that aren't covered yet, reaching out as far as they can without
+/// covering the distress beacon, until it's the only position left. The beacons
+/// are only there to set each sensor's range, so they're not always the closest
+/// beacon to their sensor the way the puzzle promises.
+pub fn generate_search_area(rng: &mut Rng, size: usize) -> Input {
+    let max = size as isize;
+    let distress = Point(rng.range(0..=max), rng.range(0..=max));
+    let mut sensors: Vec<Sensor> = Vec::new();
+    loop {
+        let uncovered: Vec<_> = (0..=max)
+            .flat_map(|x| (0..=max).map(move |y| Point(x, y)))
+            .filter(|point| *point != distress)
+            .filter(|point| !sensors.iter().any(|sensor| sensor.can_detect(point)))
+            .collect();
+        if uncovered.is_empty() {
+            break;
+        }
+
+        let location = *rng.pick(&uncovered);
+        let reach = location.manhattan(&distress) as isize - 1;
+        let range = rng.range(reach / 2..=reach);
+        let dx = rng.range(-range..=range);
+        let dy = (range - dx.abs()) * *rng.pick(&[-1, 1]);
+        let Point(x, y) = location;
+        sensors.push(Sensor::from((location, Point(x + dx, y + dy))));
+    }
+    sensors.sort_unstable();
+    sensors
+}
+
+/// Print the sensors and beacons the way they're reported in the input
+pub fn print(input: &Input) -> String {
+    let mut out = String::new();
+    for Sensor {
+        location, beacon, ..
+    } in input
+    {
+        let (Point(sx, sy), Point(bx, by)) = (location, beacon);
+        out += &format!("Sensor at x={sx}, y={sy}: closest beacon is at x={bx}, y={by}\n");
+    }
+    out
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::day15::input::parse;
+    use crate::random::check_round_trip;
+
+    #[test]
+    fn check_generated_input() {
+        check_round_trip(generate, print, parse);
+    }
+}
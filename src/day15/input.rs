@@ -1,26 +1,6 @@
 use crate::day15::Input;
-
-/// Represents a point in the 2D plane where our sensors are located
-#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
-pub struct Point(pub isize, pub isize);
-
-impl Point {
-    // Calculate the Manhattan Distance from one Point to another Point
-    pub fn distance_to(&self, other: &Self) -> usize {
-        let Point(x1, y1) = self;
-        let Point(x2, y2) = other;
-        x1.abs_diff(*x2) + y1.abs_diff(*y2)
-    }
-}
-
-/// Easily create a Point from a tuple of i32's. This is mostly here because
-/// the `nom` parsers don't provide isize directly.
-impl From<(i32, i32)> for Point {
-    fn from(value: (i32, i32)) -> Self {
-        let (x, y) = value;
-        Point(x as isize, y as isize)
-    }
-}
+use crate::grid::Point;
+use crate::Result;
 
 /// Represents one of our sensors. Encapsulates the location of the beacon it is
 /// detecting and the Senso's detection range.
@@ -33,7 +13,7 @@ pub struct Sensor {
 
 impl Sensor {
     fn new(location: Point, beacon: Point) -> Self {
-        let range = location.distance_to(&beacon);
+        let range = location.manhattan(&beacon);
         Sensor {
             location,
             beacon,
@@ -54,7 +34,7 @@ impl From<(Point, Point)> for Sensor {
 /// Internal module wrapping the `nom` parser for the input
 mod parser {
     use super::*;
-    use anyhow::{anyhow, Result};
+    use crate::error::{finish, Result};
     use nom::{
         bytes::complete::take_till,
         character::{
@@ -64,7 +44,7 @@ mod parser {
         combinator::{map, recognize},
         multi::separated_list0,
         sequence::{pair, preceded},
-        Finish, IResult,
+        IResult,
     };
 
     /// Nom parser to skip everything that's not a number or minus sign
@@ -77,9 +57,12 @@ mod parser {
         preceded(till_number, i32)(s)
     }
 
-    /// Nom parser to take the first two found numbers and return a Point from them
+    /// Nom parser to take the first two found numbers and return a Point from them.
+    /// The numbers are parsed as i32's, since `nom` doesn't provide isize directly.
     fn point(s: &str) -> IResult<&str, Point> {
-        map(pair(prefixed_number, prefixed_number), Point::from)(s)
+        map(pair(prefixed_number, prefixed_number), |(x, y)| {
+            Point(x as isize, y as isize)
+        })(s)
     }
 
     /// Get the two Points from an input line
@@ -94,18 +77,22 @@ mod parser {
 
     /// Parse the input, returns a list of Sensors
     pub fn parse(s: &str) -> Result<Vec<Sensor>> {
-        let (_, result) = sensors(s).finish().map_err(|e| anyhow!("{e}"))?;
-        Ok(result)
+        finish(s, sensors(s))
     }
 }
 
-const INPUT: &str = include_str!("../../input/15/input.txt");
+pub(crate) const INPUT: &str = include_str!("../../input/15/input.txt");
+
+/// Parse the text of an input file
+pub fn parse(input: &str) -> Result<Input> {
+    let mut input = parser::parse(input)?;
+    input.sort_unstable();
+    Ok(input)
+}
 
 /// Parse that input!
 pub fn read() -> Input {
-    let mut input = parser::parse(INPUT).unwrap();
-    input.sort_unstable();
-    input
+    parse(INPUT).unwrap()
 }
 
 #[cfg(test)]
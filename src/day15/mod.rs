@@ -1,38 +1,65 @@
+pub mod generate;
 pub mod input;
 pub mod part1;
 pub mod part2;
+pub mod reference;
 
-use crate::{Output, Part};
-use input::{Point, Sensor};
+use crate::grid::Point;
+use crate::{Output, Part, Result, Solution, Source};
+use input::Sensor;
 
 pub type Input = Vec<Sensor>;
 
-pub fn run(part: Part) -> Output {
-    let input = input::read();
-    match part {
-        Part::One => part1::solve(&input),
-        Part::Two => part2::solve(&input),
+pub struct Day15;
+
+impl Solution for Day15 {
+    const DAY: u8 = 15;
+    const INPUT: &'static str = input::INPUT;
+    type Input<'a> = Input;
+
+    fn parse(text: &str) -> Result<Self::Input<'_>> {
+        input::parse(text)
     }
-}
 
-pub fn run_both() -> (Output, Output) {
-    let input = input::read();
-    (part1::solve(&input), part2::solve(&input))
+    fn part1(input: &Self::Input<'_>) -> Result<Output> {
+        part1::solve(input)
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Result<Output> {
+        part2::solve(input)
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::examples;
 
     #[test]
     fn check_answer_one() {
-        let result = run(Part::One);
+        let result = Day15::run(Part::One, Source::Bundled).unwrap();
         assert_eq!(result, 5299855);
     }
 
     #[test]
     fn check_answer_two() {
-        let result = run(Part::Two);
+        let result = Day15::run(Part::Two, Source::Bundled).unwrap();
         assert_eq!(result, 13615843289729u64);
     }
+
+    #[test]
+    fn check_example_one() {
+        // The example asks about row 10 instead of row 2,000,000
+        let input = input::parse(&examples::text(15)).unwrap();
+        let result = part1::solve_for_row(&input, 10).unwrap();
+        assert_eq!(result, examples::expected(15, Part::One));
+    }
+
+    #[test]
+    fn check_example_two() {
+        // The example searches up to 20 instead of up to 4,000,000
+        let input = input::parse(&examples::text(15)).unwrap();
+        let result = part2::solve_for_area(&input, 20).unwrap();
+        assert_eq!(result, examples::expected(15, Part::Two));
+    }
 }
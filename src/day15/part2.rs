@@ -1,70 +1,96 @@
-use crate::day15::{Input, Output, Point, Sensor};
+use crate::day15::{Input, Output, Point, Result, Sensor};
+use crate::interval::{Interval, IntervalSet};
+use crate::Error;
 use itertools::Itertools;
 
 /// Solve Day 15, Part 2
 ///
 /// Well, I think this is my first "what the heck is he doing" comment of the year.
-/// Yay! So, this solution relies on some assumptions, the biggest of which is that
-/// the beacon we're searching for will lie one space outside the range of a Sensor.
-/// Why can we assume that? Well, the only possible way that a gap of larger than
-/// one could contain the beacon is if the beacon were located in one of the four
-/// corner points of the allowed range. That would kind of suck, though, so it's
-/// probably not how it is. If it turns out to be the case, then we only have four
-/// possible answers to try, so it's a win-win. So, given that the beacon most
-/// likely lies in a one-wide gap between sensor ranges, we can treat that gap like
-/// a line. The other assumption is that there's more than one one-wide gap.
-/// Otherwise, again, we'd have multiple unscanned spaces. That other gap would
-/// necessarily have a slope with opposite sign of the first gap, making an X with
-/// the center on the beacon. X marks the spot! So, if we can identify all the
-/// one-wide gaps in the range of the sensors, we can identify all the places where
-/// these gap-lines intersect. If we can find one intersection that can't be
-/// detected by any sensor, that's the one we want.
-pub fn solve(input: &Input) -> Output {
-    // Identify all the diagonal gaps between sensor detection ranges that are only
-    // one space wide.
-    let mut diagonal_gaps = Vec::new();
-    for (sensor1, sensor2) in input.iter().tuple_combinations() {
-        let Some(gap) = sensor1.gap_size(sensor2) else { continue; };
-        if gap == 1 {
-            diagonal_gaps.push(sensor1.diagonal_between(sensor2));
-        }
-    }
+/// Yay! So, this solution relies on the fact that there's only one position in the
+/// search area that no sensor can detect. Every neighbor of that position is either
+/// detected by some sensor or outside the search area, which means the beacon lies
+/// one space outside the range of a Sensor, right along one of the diagonal edges of
+/// its detection diamond. Those edges are lines, and (usually) the beacon is boxed
+/// in by edges with both slopes, making an X with the center on the beacon. X marks
+/// the spot! So, if we identify the lines just outside every sensor's range, we can
+/// identify all the places where these lines intersect with each other (or with the
+/// edges of the search area, in case the beacon is pushed up against one). If we can
+/// find one intersection in the search area that can't be detected by any sensor,
+/// that's the one we want. There are a few odd layouts where the beacon is boxed in
+/// without being on an X, so if none of the intersections pan out, we fall back to
+/// checking the search area one row at a time.
+pub fn solve(input: &Input) -> Result<Output> {
+    solve_for_area(input, 4_000_000) // Our hard-coded search area
+}
 
-    // Identify all the points where these one-wide gaps intersect.
-    let intersects = diagonal_gaps
+/// Find the tuning frequency of the only position with both coordinates between 0
+/// and `max` that no sensor can detect. The puzzle searches up to 4,000,000, but the
+/// example from the puzzle description only searches up to 20.
+pub fn solve_for_area(input: &Input, max: isize) -> Result<Output> {
+    // Identify the lines just outside the detection range of each sensor, sorted by
+    // slope. Duplicates are pretty common, since sensors tend to line up with each
+    // other, so skip those.
+    let (positive, negative): (Vec<_>, Vec<_>) = input
         .iter()
-        .tuple_combinations()
-        .flat_map(|(diag1, diag2)| diag1.intersect(diag2))
+        .flat_map(|sensor| sensor.edges())
         .unique()
-        .collect_vec();
+        .partition(|diagonal| matches!(diagonal, Diagonal::Positive(_)));
+
+    // Identify all the points where these lines intersect each other, the points
+    // where they run into the edges of the search area, and the corners of the
+    // search area.
+    let intersects = positive
+        .iter()
+        .cartesian_product(negative.iter())
+        .flat_map(|(diag1, diag2)| diag1.intersect(diag2))
+        .chain(
+            positive
+                .iter()
+                .chain(negative.iter())
+                .flat_map(|d| d.borders(max)),
+        )
+        .chain([Point(0, 0), Point(0, max), Point(max, 0), Point(max, max)]);
 
     // Check the intersections against all the sensors to identify the intersection
-    // that cannot be detected by any Sensor. Now, for my input, there was only one
-    // intersection that made it this far, but this check should make the solution
-    // more robust for other inputs.
-    'outer: for intersect in intersects {
-        for sensor in input.iter() {
-            if sensor.can_detect(&intersect) {
-                continue 'outer;
-            }
-        }
-        return intersect.tuning_frequency().into();
+    // that cannot be detected by any Sensor.
+    let in_area = |Point(x, y): &Point| (0..=max).contains(x) && (0..=max).contains(y);
+    let undetected = |point: &Point| !input.iter().any(|sensor| sensor.can_detect(point));
+    let found = intersects
+        .filter(in_area)
+        .find(undetected)
+        .or_else(|| scan_rows(input, max));
+    if let Some(beacon) = found {
+        return Ok(beacon.tuning_frequency().into());
     }
 
-    // Freak out if we can't find an intersection that can't be detected.
-    panic!("Could not find the beacon!");
+    // Freak out if we can't find a position that can't be detected.
+    Err(Error::no_solution("Could not find the beacon!"))
+}
+
+/// The slow way to find the beacon, just in case. For each row in the search area,
+/// gather up the ranges of the row that the sensors can detect and look for a gap.
+fn scan_rows(input: &Input, max: isize) -> Option<Point> {
+    for row in 0..=max {
+        let sensed: IntervalSet<isize> =
+            input.iter().flat_map(|s| s.row_range_sensed(row)).collect();
+        if let Some(gap) = sensed.gaps(Interval::new(0, max)).intervals().first() {
+            return Some(Point(gap.start, row));
+        }
+    }
+    None
 }
 
 /// Represents a diagonal line. The Positive variant indicates a line with a slope
 /// of 1 and the Negative variant indicates a line with a slope of -1.
-#[derive(Debug)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 enum Diagonal {
     Positive(isize),
     Negative(isize),
 }
 
 impl Diagonal {
-    /// Identify the point where two Diagonal lines intersect.
+    /// Identify the point where two Diagonal lines intersect, if they intersect on
+    /// a point with integer coordinates.
     fn intersect(&self, other: &Self) -> Option<Point> {
         // It's simple geometry! Which explains why it was so hard for me
         // to implement. Uses the formula for the two lines to calculate the
@@ -78,54 +104,47 @@ impl Diagonal {
             (Positive(_), Positive(_)) => return None,
             (Negative(_), Negative(_)) => return None,
         };
+        if (neg - pos) % 2 != 0 {
+            return None;
+        }
         let x = (neg - pos) / 2;
         let y = x + pos;
         Some(Point(x, y))
     }
-}
 
-impl Sensor {
-    /// Find the size of the gap in the sensor range between two Sensors.
-    /// If there's no gap (they overlap), return None.
-    fn gap_size(&self, other: &Self) -> Option<usize> {
-        let distance = self.location.distance_to(&other.location);
-        let total_range = self.range + other.range;
-        if total_range >= distance {
-            return None;
+    /// Identify the points where this line crosses the edges of the search area,
+    /// which runs from 0 to `max` in both directions.
+    fn borders(&self, max: isize) -> [Point; 4] {
+        match *self {
+            Diagonal::Positive(b) => [
+                Point(0, b),
+                Point(max, max + b),
+                Point(-b, 0),
+                Point(max - b, max),
+            ],
+            Diagonal::Negative(b) => [
+                Point(0, b),
+                Point(max, b - max),
+                Point(b, 0),
+                Point(b - max, max),
+            ],
         }
-        Some(distance - total_range - 1)
     }
+}
 
-    /// Calculate the formula for the line that lies in the gap between two
-    /// Sensor detection ranges. The line will lie diagonally just outside
-    /// the range of `self`.
-    fn diagonal_between(&self, other: &Self) -> Diagonal {
-        let Point(x1, y1) = self.location;
-        let Point(x2, y2) = other.location;
-        let offset = self.range + 1;
-
-        // Here, we identify two points on the diagonal line. We'll pick points just
-        // outside the cardinal direction points of the `self` sensor range.
-        let (p1x, p1y) = if x2 > x1 {
-            (x1.saturating_add_unsigned(offset), y1)
-        } else {
-            (x1.saturating_sub_unsigned(offset), y1)
-        };
-        let (p2x, p2y) = if y2 > y1 {
-            (x1, y1.saturating_add_unsigned(offset))
-        } else {
-            (x1, y1.saturating_sub_unsigned(offset))
-        };
-
-        // We know that the slope will either be 1 or -1, since these lines
-        // are diagonals.
-        let slope = (p2x - p1x) / (p2y - p1y);
-        let intercept = p1y - (slope * p1x);
-        if slope > 0 {
-            Diagonal::Positive(intercept)
-        } else {
-            Diagonal::Negative(intercept)
-        }
+impl Sensor {
+    /// Calculate the formulas for the four lines that run along the diagonal edges
+    /// just outside the detection range of this Sensor. Each line is given by its
+    /// y-intercept, since we already know the slopes.
+    fn edges(&self) -> [Diagonal; 4] {
+        let Point(x, y) = self.location;
+        let offset = self.range as isize + 1;
+        [
+            Diagonal::Positive(y - x + offset),
+            Diagonal::Positive(y - x - offset),
+            Diagonal::Negative(y + x + offset),
+            Diagonal::Negative(y + x - offset),
+        ]
     }
 }
 
@@ -0,0 +1,73 @@
+//! Slow, straightforward solutions to check the clever ones against. Both parts just
+//! check every position they need to against every sensor, which is hopeless for
+//! the real input but fine for small, generated ones.
+use crate::day15::{Input, Output, Point, Result};
+use crate::Error;
+
+/// Count the positions on the given `row` that can't contain a beacon by checking
+/// each position from the left edge of the leftmost sensor range to the right edge
+/// of the rightmost one.
+pub fn part1(input: &Input, row: isize) -> Result<Output> {
+    let left = input.iter().map(|s| s.location.0 - s.range as isize).min();
+    let right = input.iter().map(|s| s.location.0 + s.range as isize).max();
+    let (Some(left), Some(right)) = (left, right) else { return Ok(0u32.into()) };
+
+    let not_beacons = (left..=right)
+        .map(|x| Point(x, row))
+        .filter(|point| input.iter().all(|sensor| sensor.beacon != *point))
+        .filter(|point| input.iter().any(|sensor| sensor.can_detect(point)))
+        .count();
+    Ok((not_beacons as u32).into())
+}
+
+/// Find the position with both coordinates between 0 and `max` that no sensor can
+/// detect by checking every one of them. Errors unless there's exactly one.
+pub fn part2(input: &Input, max: isize) -> Result<Output> {
+    let mut undetected = (0..=max)
+        .flat_map(|x| (0..=max).map(move |y| Point(x, y)))
+        .filter(|point| !input.iter().any(|sensor| sensor.can_detect(point)));
+    match (undetected.next(), undetected.next()) {
+        (Some(Point(x, y)), None) => Ok((4_000_000 * x as u64 + y as u64).into()),
+        (None, _) => Err(Error::no_solution("Every position can be detected!")),
+        (Some(_), Some(_)) => Err(Error::no_solution("More than one position is hidden!")),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::day15::generate::generate_search_area;
+    use crate::day15::{part1, part2};
+    use crate::examples;
+    use crate::random::check_against_reference;
+    use crate::Part;
+
+    #[test]
+    fn check_example() {
+        let input = crate::day15::input::parse(&examples::text(15)).unwrap();
+        assert_eq!(
+            part1(&input, 10).unwrap(),
+            examples::expected(15, Part::One)
+        );
+        assert_eq!(
+            part2(&input, 20).unwrap(),
+            examples::expected(15, Part::Two)
+        );
+    }
+
+    #[test]
+    fn check_part_one() {
+        let generate = |rng: &mut _, size| (generate_search_area(rng, size), size as isize);
+        let solve = |(input, size): &(Input, isize)| part1::solve_for_row(input, size / 2);
+        let reference = |(input, size): &(Input, isize)| part1(input, size / 2);
+        check_against_reference(generate, &[1, 5, 20, 50], solve, reference);
+    }
+
+    #[test]
+    fn check_part_two() {
+        let generate = |rng: &mut _, size| (generate_search_area(rng, size), size as isize);
+        let solve = |(input, max): &(Input, isize)| part2::solve_for_area(input, *max);
+        let reference = |(input, max): &(Input, isize)| part2(input, *max);
+        check_against_reference(generate, &[1, 5, 20, 50], solve, reference);
+    }
+}
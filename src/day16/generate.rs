@@ -0,0 +1,120 @@
+use crate::day16::input::ParsedInput;
+use crate::day16::ValveMap;
+use crate::random::Rng;
+
+/// A valve as it's described in the input, with its own copies of the labels
+#[derive(Debug, Clone)]
+pub struct ValveReport {
+    pub label: String,
+    pub flow: u32,
+    pub leads_to: Vec<String>,
+}
+
+/// Generate a random network of (about) `size` valves, including valve "AA", all
+/// connected by tunnels in both directions. Labels are two to four letters long.
+/// At most 15 valves release any pressure, like in the puzzle input, so there's
+/// a chance of actually solving it.
+pub fn generate(rng: &mut Rng, size: usize) -> Vec<ValveReport> {
+    // Come up with a unique label for each valve, one of which is "AA"
+    let count = size.max(2);
+    let mut labels = vec![String::from("AA")];
+    while labels.len() < count {
+        let label: String = (0..rng.range(2..=4))
+            .map(|_| rng.range(b'A'..=b'Z') as char)
+            .collect();
+        if !labels.contains(&label) {
+            labels.push(label);
+        }
+    }
+    rng.shuffle(&mut labels);
+
+    // Connect each valve to one that came before it, so every valve can be reached,
+    // then throw in a few more tunnels for good measure.
+    let mut tunnels = vec![Vec::new(); count];
+    let mut connect = |from: usize, to: usize| {
+        if from != to && !tunnels[from].contains(&to) {
+            tunnels[from].push(to);
+            tunnels[to].push(from);
+        }
+    };
+    for idx in 1..count {
+        connect(idx, rng.range(0..=idx - 1));
+    }
+    for _ in 0..count / 2 {
+        connect(rng.range(0..=count - 1), rng.range(0..=count - 1));
+    }
+
+    // Let a few of the valves (but never "AA") release some pressure
+    let mut working = 0;
+    let mut valves = Vec::new();
+    for (label, leads_to) in labels.iter().zip(tunnels) {
+        let flow = match label != "AA" && working < 15 && rng.chance(0.3) {
+            true => rng.range(1..=25),
+            false => 0,
+        };
+        working += (flow > 0) as usize;
+        let leads_to = leads_to.iter().map(|idx| labels[*idx].clone()).collect();
+        valves.push(ValveReport {
+            label: label.clone(),
+            flow,
+            leads_to,
+        });
+    }
+    valves
+}
+
+/// Print the valves the way they're described in the input, with the right tense
+/// for valves with only one tunnel.
+pub fn print(valves: &[ValveReport]) -> String {
+    let mut out = String::new();
+    for ValveReport {
+        label,
+        flow,
+        leads_to,
+    } in valves
+    {
+        let tunnels = match leads_to.len() {
+            1 => "tunnel leads to valve",
+            _ => "tunnels lead to valves",
+        };
+        out += &format!(
+            "Valve {label} has flow rate={flow}; {tunnels} {}\n",
+            leads_to.join(", ")
+        );
+    }
+    out
+}
+
+/// Build the `ValveMap` for the valves, the same way parsing the input would
+pub fn valve_map(valves: &[ValveReport]) -> ValveMap {
+    let entries = valves
+        .iter()
+        .map(|valve| {
+            let leads_to = valve.leads_to.iter().map(String::as_str).collect();
+            ParsedInput::from((valve.label.as_str(), valve.flow, leads_to))
+        })
+        .collect::<Vec<_>>();
+    ValveMap::from(entries)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::day16::input::parse;
+
+    #[test]
+    fn check_generated_input() {
+        // The parsed `ValveMap` is a condensed version of the valves in the input,
+        // so compare it against the same condensed version of the generated valves.
+        // Condensing takes a while for big networks, so keep them smallish.
+        for seed in 0..25 {
+            for size in [1, 2, 10, 40] {
+                let valves = generate(&mut Rng::new(seed), size);
+                let text = print(&valves);
+                for text in [text.as_str(), text.trim_end()] {
+                    assert_eq!(parse(text).unwrap(), valve_map(&valves), "seed {seed}");
+                }
+            }
+        }
+    }
+}
@@ -1,4 +1,5 @@
 use crate::day16::Input;
+use crate::{Error, Result};
 use itertools::Itertools;
 use std::collections::HashMap;
 
@@ -34,21 +35,21 @@ impl<'a> From<(&'a str, u32, Vec<&'a str>)> for ParsedInput<'a> {
 /// Module wrapping the parsing functions for parsing the input.
 mod parser {
     use super::*;
-    use anyhow::{anyhow, Result};
+    use crate::error::{finish, Result};
     use nom::{
         branch::alt,
-        bytes::complete::tag,
-        character::complete::{alpha1, newline, u32},
-        combinator::{map, verify},
+        bytes::complete::{tag, take_while1},
+        character::complete::{newline, u32},
+        combinator::map,
         multi::separated_list1,
         sequence::{delimited, preceded, tuple},
-        Finish, IResult,
+        IResult,
     };
 
-    /// Parses a valve label, which is always a string of two capital letters.
+    /// Parses a valve label, which is a string of capital letters. They're always
+    /// two letters long in the puzzle input, but there's no need to insist.
     fn label(s: &str) -> IResult<&str, &str> {
-        let ver_fn = |s: &str| s.len() == 2 && s.chars().all(|c| c.is_ascii_uppercase());
-        verify(alpha1, ver_fn)(s)
+        take_while1(|c: char| c.is_ascii_uppercase())(s)
     }
 
     /// Nom parser for "Valve AA" -> "AA"
@@ -82,15 +83,16 @@ mod parser {
     /// Main parsing function, attemtps to read the input file as a string into
     /// a list of `ParsedInput`s.
     pub fn parse(s: &str) -> Result<Vec<ParsedInput>> {
-        let (_, result) = valve_map_entries(s).finish().map_err(|e| anyhow!("{e}"))?;
-        Ok(result)
+        finish(s, valve_map_entries(s))
     }
 }
 
 /// Represents a valve. Includes fields for its ID and flow rate. The ID for
 /// a valve is a u64 with a single bit set. This makes keeping up with which
 /// valves have been opened as easy as bitwise -or- between multiple valves.
-#[derive(Debug, Copy, Clone)]
+/// Only valves that release pressure are worth opening, so valves with a flow
+/// rate of zero get an ID of zero, leaving room for up to 64 working valves.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
 pub struct Valve {
     pub id: u64,
     pub flow: u32,
@@ -110,7 +112,7 @@ impl Valve {
 /// The nodes are just the list of `Valve`s at an index that corresponds to an index
 /// in `edges`. For example, if nodes[0] is valve "AA", then edges[0] indicates the
 /// valves that can be reached from valve "AA".
-#[derive(Debug)]
+#[derive(Debug, PartialEq, Eq)]
 pub struct ValveMap {
     pub edges: Vec<Vec<(usize, u32)>>,
     pub nodes: Vec<Valve>,
@@ -124,13 +126,25 @@ impl ValveMap {
 
 /// Convert a list of parsed input lines into a ValveMap
 impl From<Vec<ParsedInput<'_>>> for ValveMap {
-    fn from(value: Vec<ParsedInput>) -> Self {
+    fn from(mut value: Vec<ParsedInput>) -> Self {
+        // We always start from valve "AA", and the solutions expect the starting
+        // valve to be the first one. So, move it to the front.
+        value.sort_by_key(|entry| entry.label != "AA");
+
         // The first part is the easy part, the nodes (valves). We set each ID
         // sequentially and extract the flow rate from the parsed input line.
         let mut nodes = Vec::new();
-        for (idx, entry) in value.iter().enumerate() {
-            let id = 2u64.pow(idx as u32);
+        let mut next_id = 1;
+        for entry in value.iter() {
             let flow = entry.flow;
+            let id = match flow {
+                0 => 0,
+                _ => {
+                    let id = next_id;
+                    next_id <<= 1;
+                    id
+                }
+            };
             let valve = Valve::new(id, flow);
             nodes.push(valve);
         }
@@ -203,9 +217,31 @@ impl From<Vec<ParsedInput<'_>>> for ValveMap {
     }
 }
 
-const INPUT: &str = include_str!("../../input/16/input.txt");
+pub(crate) const INPUT: &str = include_str!("../../input/16/input.txt");
+
+pub fn parse(input: &str) -> Result<Input> {
+    let entries = parser::parse(input)?;
+
+    // Every valve that's worth opening needs a bit in a u64 for its ID, and every
+    // tunnel needs to lead somewhere.
+    if entries.iter().filter(|entry| entry.flow > 0).count() > 64 {
+        let reason = "there can't be more than 64 valves with a flow rate";
+        return Err(Error::parse(input, "", reason));
+    }
+    if !entries.iter().any(|entry| entry.label == "AA") {
+        return Err(Error::parse(input, "", "there's no valve AA to start from"));
+    }
+    for entry in entries.iter() {
+        for label in entry.leads_to.iter() {
+            if !entries.iter().any(|other| other.label == *label) {
+                return Err(Error::parse(input, label, "no valve by that name"));
+            }
+        }
+    }
+
+    Ok(ValveMap::from(entries))
+}
 
 pub fn read() -> Input {
-    let entries = parser::parse(INPUT).unwrap();
-    ValveMap::from(entries)
+    parse(INPUT).unwrap()
 }
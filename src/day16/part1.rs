@@ -1,4 +1,4 @@
-use crate::day16::{Input, Output, ValveMap};
+use crate::day16::{Input, Output, Result, ValveMap};
 
 /// Solve Day 16, Part 1
 ///
@@ -8,7 +8,7 @@ use crate::day16::{Input, Output, ValveMap};
 /// that there's an A* implementation that could do this much more efficiently, but
 /// I'm struggling to develop an appropriate penalty function. I'll come back to this
 /// one.
-pub fn solve(input: &Input) -> Output {
+pub fn solve(input: &Input) -> Result<Output> {
     // Start at valve "AA"
     let state = TravelState::default();
     let mut open = vec![state];
@@ -35,7 +35,7 @@ pub fn solve(input: &Input) -> Output {
     }
 
     // Return the maximum pressure released by any path through the valves
-    max_released.into()
+    Ok(max_released.into())
 }
 
 /// Represents the state of a path through the valves. Indicates current location
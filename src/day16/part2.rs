@@ -1,29 +1,33 @@
 use super::part1::TravelState;
-use crate::day16::{Input, Output, ValveMap};
+use crate::day16::{Input, Output, Result, ValveMap};
+use crate::Error;
+use std::cmp::Reverse;
+use std::collections::HashMap;
 
 /// Solve Day 16, Part 2
 ///
-/// This part is also a bit hacky. Here we're again producing every possible end
-/// state from walking through and opening valves, just in 26 minutes instead of
-/// 30. With that list of possible paths through the valves, we check the paths
-/// that release the most pressure for two that open different sets of valves.
-/// The total pressure released from the most pressure-releasing pair of
-/// non-overlapping paths is the most pressure that two actors can release working
-/// together.
-pub fn solve(input: &Input) -> Output {
+/// Here we're again walking through every possible path through the valves, just in
+/// 26 minutes instead of 30. Since we've got help now, any point along a path is a
+/// fine place to stop and leave the rest of the valves to the elephant, so every
+/// state along the way counts as a possible result. For each set of valves opened,
+/// we keep the most pressure released by any path that opens exactly that set. The
+/// most pressure that two actors can release working together is the best total
+/// from any two of those sets that don't share a valve.
+pub fn solve(input: &Input) -> Result<Output> {
     // Now the initial state starts with only 26 minutes remaining.
-    let mut state = TravelState {
+    let state = TravelState {
         remaining: 26,
         ..Default::default()
     };
     let mut open = vec![state];
 
-    // Instead of tracking the most pressure released, we're collecting all possible
-    // final states.
-    let mut best_results = Vec::new();
+    // Instead of tracking the most pressure released, we're tracking the most
+    // pressure released for each set of open valves.
+    let mut best_results: HashMap<u64, u32> = HashMap::new();
     while let Some(state) = open.pop() {
+        let best = best_results.entry(state.valves_open).or_default();
+        *best = (*best).max(state.released);
         if state.remaining == 0 {
-            best_results.push(state);
             continue;
         }
 
@@ -32,47 +36,28 @@ pub fn solve(input: &Input) -> Output {
         }
     }
 
-    // Now we sort the final states by pressure released
-    best_results.sort_unstable();
-
-    // How many of the most productive states should be considered? This is the
-    // 1337 hax. If we don't check enough of the most productive states, we'll
-    // get the wrong answer. So, how many should we check? Enough until we get the
-    // right answer! Honestly I started high and just started reducing the number
-    // until I got low enough that this function started producing the wrong
-    // answer.
-    let depth = 285;
-
-    // Notice that we're iterating backwards over the list of results, since
-    // it's sorted by ascending total pressure released. For each top result, we
-    // check down the list for the next result that doesn't overlap. There's a
-    // possibility that this wouldn't work and we'd need to find a few pairs
-    // this way and get their maximum, but for my input the first pair found
-    // through this strategy produces the correct result.
-    for (idx, result1) in best_results.iter().rev().enumerate().take(depth) {
-        for result2 in best_results.iter().rev().skip(idx + 1).take(depth) {
-            if result1.valves_open & result2.valves_open > 0 {
-                continue;
+    // Check pairs of valve sets for two that don't overlap, going from the sets
+    // that release the most pressure to the ones that release the least. Once the
+    // first set of a pair plus the best possible partner can't beat the best pair
+    // found so far, none of the pairs after that can, either. The set where
+    // nothing gets opened is in there, too, so this covers the case where one actor
+    // can do all the work alone. If nothing is worth opening, though, that's the
+    // only set and there aren't any pairs, so check each set on its own, too.
+    let mut by_released: Vec<_> = best_results.into_iter().collect();
+    by_released.sort_unstable_by_key(|(_, released)| Reverse(*released));
+    let mut most_released = by_released.first().map(|(_, released)| *released);
+    for (idx, (valves1, released1)) in by_released.iter().enumerate() {
+        for (valves2, released2) in by_released.iter().skip(idx + 1) {
+            let best = most_released.unwrap_or_default();
+            if released1 + released2 <= best {
+                break;
+            }
+            if valves1 & valves2 == 0 {
+                most_released = Some(released1 + released2);
             }
-            let max = result1.released + result2.released;
-            return max.into();
         }
     }
-
-    // If we get this far, we've failed.
-    panic!("Could not solve part two!");
-}
-
-/// Implement ordering for travel states so we can sort the list above. We'll
-/// sort by total pressure released.
-impl Ord for TravelState {
-    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
-        self.released.cmp(&other.released)
-    }
-}
-
-impl PartialOrd for TravelState {
-    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
-        Some(self.cmp(other))
-    }
+    most_released
+        .map(Output::from)
+        .ok_or_else(|| Error::no_solution("Could not solve part two!"))
 }
@@ -0,0 +1,173 @@
+//! Slow, straightforward solutions to check the clever ones against. Instead of
+//! hopping between the valves worth opening, these walk through the tunnels one
+//! minute at a time, trying every move (or two moves, with the elephant helping)
+//! each minute. They work from the valves as they're described in the input, so
+//! they don't depend on how `ValveMap` condenses the tunnels, either.
+use crate::day16::generate::ValveReport;
+use crate::day16::{Output, Result};
+use crate::Error;
+use std::collections::HashMap;
+
+/// Find the most pressure one actor can release in 30 minutes
+pub fn part1(valves: &[ValveReport]) -> Result<Output> {
+    let tunnels = Tunnels::from(valves)?;
+    let mut memo = HashMap::new();
+    Ok(tunnels
+        .most_released(&mut memo, 30, tunnels.start, 0)
+        .into())
+}
+
+/// Find the most pressure two actors can release in 26 minutes
+pub fn part2(valves: &[ValveReport]) -> Result<Output> {
+    let tunnels = Tunnels::from(valves)?;
+    let mut memo = HashMap::new();
+    let start = (tunnels.start, tunnels.start);
+    Ok(tunnels
+        .most_released_together(&mut memo, 26, start, 0)
+        .into())
+}
+
+/// The tunnels between valves, with each valve given by its index in the input.
+/// Valves that release pressure get a bit to mark them as open.
+struct Tunnels {
+    start: usize,
+    leads_to: Vec<Vec<usize>>,
+    flows: Vec<u32>,
+    bits: Vec<u64>,
+}
+
+impl Tunnels {
+    fn from(valves: &[ValveReport]) -> Result<Self> {
+        let index = |label: &String| {
+            valves
+                .iter()
+                .position(|valve| valve.label == *label)
+                .ok_or_else(|| Error::no_solution(format!("There's no valve {label}!")))
+        };
+        let start = index(&String::from("AA"))?;
+        let leads_to = valves
+            .iter()
+            .map(|valve| valve.leads_to.iter().map(index).collect())
+            .collect::<Result<_>>()?;
+        let flows = valves.iter().map(|valve| valve.flow).collect();
+        let mut working = 0;
+        let bits = valves
+            .iter()
+            .map(|valve| match valve.flow > 0 {
+                true => {
+                    working += 1;
+                    1 << (working - 1)
+                }
+                false => 0,
+            })
+            .collect();
+        Ok(Tunnels {
+            start,
+            leads_to,
+            flows,
+            bits,
+        })
+    }
+
+    /// Everything one actor could do in the next minute from `valve`: open it (if
+    /// it's worth opening and not open yet), move through any tunnel, or stay put.
+    /// Each move comes with the bit of the valve it opens, if any.
+    fn moves(&self, valve: usize, open: u64) -> Vec<(usize, u64)> {
+        let mut moves = vec![(valve, 0)];
+        if self.bits[valve] & !open > 0 {
+            moves.push((valve, self.bits[valve]));
+        }
+        moves.extend(self.leads_to[valve].iter().map(|next| (*next, 0)));
+        moves
+    }
+
+    /// The pressure released by opening the valves marked in `opened` with
+    /// `remaining` minutes left. The valves take a minute to open, then release
+    /// pressure for every minute after that.
+    fn released(&self, opened: u64, remaining: u32) -> u32 {
+        let opened = (0..self.bits.len()).filter(|idx| self.bits[*idx] & opened > 0);
+        opened.map(|idx| self.flows[idx] * (remaining - 1)).sum()
+    }
+
+    /// The most pressure one actor at `valve` can release in the `remaining` minutes
+    fn most_released(
+        &self,
+        memo: &mut HashMap<(u32, usize, u64), u32>,
+        remaining: u32,
+        valve: usize,
+        open: u64,
+    ) -> u32 {
+        if remaining == 0 {
+            return 0;
+        }
+        if let Some(released) = memo.get(&(remaining, valve, open)) {
+            return *released;
+        }
+        let mut best = 0;
+        for (next, opened) in self.moves(valve, open) {
+            let released = self.released(opened, remaining)
+                + self.most_released(memo, remaining - 1, next, open | opened);
+            best = best.max(released);
+        }
+        memo.insert((remaining, valve, open), best);
+        best
+    }
+
+    /// The most pressure two actors at `valves` can release in the `remaining`
+    /// minutes, working together.
+    fn most_released_together(
+        &self,
+        memo: &mut HashMap<(u32, (usize, usize), u64), u32>,
+        remaining: u32,
+        valves: (usize, usize),
+        open: u64,
+    ) -> u32 {
+        if remaining == 0 {
+            return 0;
+        }
+
+        // It doesn't matter which actor is where, so always put them in order
+        let (me, elephant) = valves;
+        let valves = (me.min(elephant), me.max(elephant));
+        if let Some(released) = memo.get(&(remaining, valves, open)) {
+            return *released;
+        }
+
+        let mut best = 0;
+        for (my_next, my_opened) in self.moves(me, open) {
+            for (elephant_next, elephant_opened) in self.moves(elephant, open) {
+                // Both actors can't open the same valve
+                if my_opened > 0 && my_opened == elephant_opened {
+                    continue;
+                }
+                let opened = my_opened | elephant_opened;
+                let next = (my_next, elephant_next);
+                let released = self.released(opened, remaining)
+                    + self.most_released_together(memo, remaining - 1, next, open | opened);
+                best = best.max(released);
+            }
+        }
+        memo.insert((remaining, valves, open), best);
+        best
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::day16::generate::{generate, valve_map};
+    use crate::day16::{part1, part2};
+    use crate::random::check_against_reference;
+
+    #[test]
+    fn check_part_one() {
+        let solve = |valves: &Vec<ValveReport>| part1::solve(&valve_map(valves));
+        check_against_reference(generate, &[1, 2, 5, 10], solve, |v| part1(v));
+    }
+
+    #[test]
+    fn check_part_two() {
+        let solve = |valves: &Vec<ValveReport>| part2::solve(&valve_map(valves));
+        check_against_reference(generate, &[1, 2, 5, 10], solve, |v| part2(v));
+    }
+}
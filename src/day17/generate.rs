@@ -0,0 +1,36 @@
+use crate::day17::{GasJetIter, Gust, Input};
+use crate::random::Rng;
+
+/// Generate a random cycle of `size` gas jets
+pub fn generate(rng: &mut Rng, size: usize) -> Input {
+    let gusts: Vec<_> = (0..size.max(1))
+        .map(|_| *rng.pick(&[Gust::Left, Gust::Right]))
+        .collect();
+    GasJetIter::from(gusts)
+}
+
+/// Print the gas jets the way they're written in the input
+pub fn print(input: &Input) -> String {
+    let mut out: String = input
+        .gusts()
+        .iter()
+        .map(|gust| match gust {
+            Gust::Left => '<',
+            Gust::Right => '>',
+        })
+        .collect();
+    out.push('\n');
+    out
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::day17::input::parse;
+    use crate::random::check_round_trip;
+
+    #[test]
+    fn check_generated_input() {
+        check_round_trip(generate, print, parse);
+    }
+}
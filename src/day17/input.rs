@@ -1,14 +1,15 @@
 use crate::day17::Input;
+use crate::Result;
 
 /// Represents a gust from the jets of gas, either to the left or right.
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum Gust {
     Left,
     Right,
 }
 
 /// An iterator that produces gusts of gas in an repeating cycle
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub struct GasJetIter {
     iter: Vec<Gust>,
     pub idx: usize,
@@ -26,6 +27,13 @@ impl Iterator for GasJetIter {
     }
 }
 
+impl GasJetIter {
+    /// The gusts in one full cycle of the gas jets, in order
+    pub fn gusts(&self) -> &[Gust] {
+        &self.iter
+    }
+}
+
 /// Convert a list of `Gust`s into a `GasJetIter`
 impl From<Vec<Gust>> for GasJetIter {
     fn from(iter: Vec<Gust>) -> Self {
@@ -39,13 +47,13 @@ impl From<Vec<Gust>> for GasJetIter {
 /// Module to wrap the parsing functions for today's puzzle
 mod parser {
     use super::*;
-    use anyhow::{anyhow, Result};
+    use crate::error::{finish, Result};
     use nom::{
         branch::alt,
         character::complete::char,
         combinator::{map, value},
         multi::many1,
-        Finish, IResult,
+        IResult,
     };
 
     /// Nom parser for '<' -> Gust::Left
@@ -76,31 +84,29 @@ mod parser {
     /// Main parsing function, attempts to parse the input into a GasJetIter and
     /// return it.
     pub fn parse(s: &str) -> Result<GasJetIter> {
-        let (_, result) = gas_jet_iter(s).finish().map_err(|e| anyhow!("{e}"))?;
-        Ok(result)
+        finish(s, gas_jet_iter(s))
     }
 }
 
-const INPUT: &str = include_str!("../../input/17/input.txt");
+pub(crate) const INPUT: &str = include_str!("../../input/17/input.txt");
+
+/// Parse the text of an input file
+pub fn parse(input: &str) -> Result<Input> {
+    parser::parse(input)
+}
 
 /// Parse that input!
 pub fn read() -> Input {
-    parser::parse(INPUT).unwrap()
+    parse(INPUT).unwrap()
 }
 
 #[cfg(test)]
 mod test {
     use super::*;
 
-    impl GasJetIter {
-        fn len(&self) -> usize {
-            self.iter.len()
-        }
-    }
-
     #[test]
     fn check_input() {
         let input = read();
-        assert_eq!(input.len(), 10091);
+        assert_eq!(input.gusts().len(), 10091);
     }
 }
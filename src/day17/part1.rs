@@ -1,7 +1,7 @@
-use crate::day17::{GasJetIter, Gust, Input, Output};
+use crate::day17::{GasJetIter, Gust, Input, Output, Result};
 
 /// Solve Day 17, Part 1
-pub fn solve(input: &Input) -> Output {
+pub fn solve(input: &Input) -> Result<Output> {
     // We need an owned copy of the iterator so we can use it in both parts
     let mut gas_jets = input.to_owned();
     let total_rocks = 2022;
@@ -17,7 +17,7 @@ pub fn solve(input: &Input) -> Output {
     }
 
     // Repor the total height of the rocks in the chamber
-    (chamber.height() as u32).into()
+    Ok((chamber.height() as u32).into())
 }
 
 // You'll note that we're storing rocks as u32 integers. This makes collision
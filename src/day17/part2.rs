@@ -1,17 +1,25 @@
 use super::part1::{Chamber, Rock};
-use crate::day17::{Input, Output};
+use crate::day17::{Input, Output, Result};
 use std::collections::HashMap;
 
 /// Solve Day 17, Part 2
-pub fn solve(input: &Input) -> Output {
+pub fn solve(input: &Input) -> Result<Output> {
+    let total_rocks = 1_000_000_000_000; // That's a _lot_ of rocks...
+    Ok((height_after(input, total_rocks) as u64).into())
+}
+
+/// Find the height of the tower after `total_rocks` rocks have fallen, skipping
+/// ahead once the top of the tower starts repeating.
+pub fn height_after(input: &Input, total_rocks: usize) -> usize {
     // We need an owned copy of the iterator so we can use it in both parts
     let mut gas_jets = input.to_owned();
-    let total_rocks = 1_000_000_000_000; // That's a _lot_ of rocks...
     let mut rocks_added = 0; // Number of rocks added to the chamber
 
     // Keep up with the states we've seen of the chamber before. The state includes
-    // the top 8 levels of the chamber, the shape of the last rock added to the
-    // chamber, and the current internal index of the `gas_jets`.
+    // the surface of the chamber (every open space a falling rock could still reach),
+    // the shape of the last rock added to the chamber, and the current internal
+    // index of the `gas_jets`. Those are all that matter for where the next rocks
+    // land, so if they repeat, everything after them repeats, too.
     let mut seen = HashMap::with_capacity(2048);
 
     // Why use 2048 as the capacity now? It's larger than the repeat length of the
@@ -31,19 +39,14 @@ pub fn solve(input: &Input) -> Output {
 
     // Until we've added all gajillion rocks...
     while rocks_added < total_rocks {
-        // Get the next rock, add it to the chamber, account for it, and if the
-        // chamber has fewer than 8 levels, do it again. We're checking the state
-        // based on the top 8 levels, so no checking until we have at least 8 levels.
+        // Get the next rock, add it to the chamber, and account for it.
         let rock = rock_types.next().unwrap();
         chamber.add_rock(&mut gas_jets, rock);
         rocks_added += 1;
-        if chamber.height() < 8 {
-            continue;
-        }
 
-        // Check to see if we've seen this state of the top 8 levels of the chamber
+        // Check to see if we've seen this state of the surface of the chamber
         // before. If so, time to use that cycle!
-        let state = (chamber.skyline(), rock, gas_jets.idx);
+        let state = (chamber.surface(), rock, gas_jets.idx);
         if let Some((prev_rocks_added, prev_height)) = seen.get(&state) {
             // The number of rocks added in each repeating cycle.
             let repeat_len: usize = rocks_added - prev_rocks_added;
@@ -71,25 +74,50 @@ pub fn solve(input: &Input) -> Output {
     // the cycles. The chamber will contain all the rocks dropped up to the start
     // of the second repetition of the cycle, then all the rocks that would be
     // dropped after the last full cycle ended.
-    (chamber.height() as u64 + accumulated_height as u64).into()
+    chamber.height() + accumulated_height
 }
 
+/// How far down from the top of the chamber to look for open spaces when taking the
+/// surface of the chamber. See `Chamber::surface()`.
+const SURFACE_DEPTH: usize = 64;
+
 impl Chamber {
-    /// Get the 'skyline' of the top of the chamber. Really, it's just a u64 with
-    /// bits representing the top 8 levels of the chamber.
-    fn skyline(&self) -> Option<u64> {
-        // If the chamber is less than 8 levels tall, we can't take a skyline
-        if self.height() < 8 {
-            return None;
-        }
+    /// Get the 'surface' of the top of the chamber, meaning every open space that a
+    /// rock dropped in from above could still reach. Used to be just the top 8 levels
+    /// of the chamber, but two chambers can match at the top and still differ in a
+    /// way that a rock can reach. Instead, this fills in the open spaces from above
+    /// the top of the chamber and gives one byte for each level, counting down from
+    /// the top, with bits marking the spaces that got filled. If a column never gets
+    /// filled in, though, the open spaces go all the way down to the floor and the
+    /// surface would never repeat, so we stop looking `SURFACE_DEPTH` levels down.
+    fn surface(&self) -> Vec<u8> {
+        let height = self.height();
+        let is_open = |level: usize, bit: u8| level == height || self.0[level] & bit == 0;
 
-        // Take the top 8 levels of the chamber and fold them into a 64-bit integer.
-        let result = self
-            .0
-            .iter()
-            .rev()
-            .take(8)
-            .fold(0u64, |acc, byte| (acc << 8) | *byte as u64);
-        Some(result)
+        // Start with the whole (empty) level above the top of the chamber
+        let mut surface = vec![0x7F];
+        let mut stack: Vec<_> = (0..7).map(|col| (height, 1u8 << col)).collect();
+        while let Some((level, bit)) = stack.pop() {
+            let next = [
+                (bit < 0x40).then_some((level, bit << 1)),
+                (bit > 0x01).then_some((level, bit >> 1)),
+                level.checked_sub(1).map(|below| (below, bit)),
+                (level < height).then_some((level + 1, bit)),
+            ];
+            for (level, bit) in next.into_iter().flatten() {
+                let depth = height - level;
+                if depth > SURFACE_DEPTH {
+                    continue;
+                }
+                if depth == surface.len() {
+                    surface.push(0);
+                }
+                if surface[depth] & bit == 0 && is_open(level, bit) {
+                    surface[depth] |= bit;
+                    stack.push((level, bit));
+                }
+            }
+        }
+        surface
     }
 }
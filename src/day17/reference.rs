@@ -0,0 +1,79 @@
+//! A slow, straightforward simulation to check the clever one against. Every rock
+//! is a handful of points, the settled rocks are a set of points, and there's no
+//! skipping ahead. That's fine for a few thousand rocks, but it would never get
+//! through part two's trillion.
+use crate::day17::{Gust, Input};
+use std::collections::HashSet;
+
+/// The five rock shapes, in the order they fall, as points up and to the right of
+/// their bottom left corner.
+const ROCKS: [&[(usize, usize)]; 5] = [
+    &[(0, 0), (1, 0), (2, 0), (3, 0)],
+    &[(1, 0), (0, 1), (1, 1), (2, 1), (1, 2)],
+    &[(0, 0), (1, 0), (2, 0), (2, 1), (2, 2)],
+    &[(0, 0), (0, 1), (0, 2), (0, 3)],
+    &[(0, 0), (1, 0), (0, 1), (1, 1)],
+];
+
+/// The chamber is seven units wide
+const WIDTH: usize = 7;
+
+/// Drop `total_rocks` rocks one at a time and report the height of the tower.
+pub fn height_after(input: &Input, total_rocks: usize) -> usize {
+    let mut gas_jets = input.to_owned();
+    let mut settled: HashSet<(usize, usize)> = HashSet::new();
+    let mut height = 0;
+
+    for rock in ROCKS.iter().cycle().take(total_rocks) {
+        // A rock fits at (x, y) if none of its points are outside the chamber or
+        // overlapping a rock that's already settled.
+        let fits = |x: usize, y: usize| {
+            rock.iter()
+                .all(|(dx, dy)| x + dx < WIDTH && !settled.contains(&(x + dx, y + dy)))
+        };
+
+        // Each rock appears two units from the left wall and three units above
+        // the top of the tower, then alternates being pushed and falling.
+        let (mut x, mut y): (usize, usize) = (2, height + 3);
+        loop {
+            let pushed = match gas_jets.next().unwrap() {
+                Gust::Left => x.checked_sub(1),
+                Gust::Right => Some(x + 1),
+            };
+            if let Some(pushed) = pushed.filter(|pushed| fits(*pushed, y)) {
+                x = pushed;
+            }
+            match y.checked_sub(1).filter(|below| fits(x, *below)) {
+                Some(below) => y = below,
+                None => break,
+            }
+        }
+
+        for (dx, dy) in rock.iter() {
+            settled.insert((x + dx, y + dy));
+            height = height.max(y + dy + 1);
+        }
+    }
+    height
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::day17::generate::generate;
+    use crate::day17::{input, part2};
+    use crate::random::check_against_reference;
+
+    #[test]
+    fn check_answer_one() {
+        assert_eq!(height_after(&input::read(), 2022), 3147);
+    }
+
+    #[test]
+    fn check_part_two() {
+        // Enough rocks for the tower to start repeating, with some left over after
+        let solve = |input: &Input| Ok(part2::height_after(input, 5_000));
+        let reference = |input: &Input| Ok(height_after(input, 5_000));
+        check_against_reference(generate, &[1, 2, 10, 40], solve, reference);
+    }
+}
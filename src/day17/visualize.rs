@@ -0,0 +1,66 @@
+use crate::day17::part1::{Chamber, Rock};
+use crate::day17::{Input, Result};
+use crate::visualize::{Frame, Sink};
+
+/// Drop the 2022 rocks from part one into the chamber, drawing the chamber after
+/// each one comes to rest
+pub fn visualize(input: &Input, sink: &mut dyn Sink) -> Result<()> {
+    let mut gas_jets = input.to_owned();
+    let mut chamber = Chamber::default();
+    sink.frame(&chamber)?;
+    for rock in Rock::all().iter().cycle().take(2022) {
+        chamber.add_rock(&mut gas_jets, *rock);
+        sink.frame(&chamber)?;
+    }
+    Ok(())
+}
+
+/// The chamber, drawn like the puzzle description draws it, with the walls and the
+/// floor. The top level of the chamber is the first row.
+impl Frame for Chamber {
+    fn size(&self) -> (usize, usize) {
+        (self.height() + 1, 9)
+    }
+
+    fn glyph(&self, row: usize, col: usize) -> char {
+        let height = self.height();
+        match (row, col) {
+            (row, 0 | 8) if row == height => '+',
+            (row, _) if row == height => '-',
+            (_, 0 | 8) => '|',
+            (row, col) => {
+                // The leftmost column of the chamber is the highest bit
+                let level = self.0[height - row - 1];
+                match level & (0x40 >> (col - 1)) {
+                    0 => '.',
+                    _ => '#',
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::day17::input::parse;
+    use crate::visualize::Frames;
+
+    #[test]
+    fn check_example() {
+        let input = parse(include_str!("../../input/17/test.txt")).unwrap();
+        let mut frames = Frames::default();
+        visualize(&input, &mut frames).unwrap();
+        assert_eq!(frames.0.len(), 2023);
+        assert_eq!(frames.0[0], "+-------+\n");
+
+        // The chamber after the first two rocks, from the puzzle description
+        let expected = "\
+            |...#...|\n\
+            |..###..|\n\
+            |...#...|\n\
+            |..####.|\n\
+            +-------+\n";
+        assert_eq!(frames.0[2], expected);
+    }
+}
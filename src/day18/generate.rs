@@ -0,0 +1,39 @@
+use crate::day18::input::Cube;
+use crate::day18::Input;
+use crate::random::Rng;
+use std::collections::HashSet;
+
+/// Generate a droplet of `size` cubes, packed into a box about twice that size so
+/// there are plenty of air pockets trapped inside. All the coordinates are at least
+/// one, so there's never any lava at (0, 0, 0).
+pub fn generate(rng: &mut Rng, size: usize) -> Input {
+    let side = ((size * 2) as f64).cbrt().ceil() as i32;
+    let mut cubes = HashSet::with_capacity(size);
+    while cubes.len() < size {
+        let mut coordinate = || rng.range(1..=side);
+        cubes.insert(Cube::new(coordinate(), coordinate(), coordinate()));
+    }
+    cubes
+}
+
+/// Print the cubes the way they're listed in the input
+pub fn print(input: &Input) -> String {
+    let mut out = String::new();
+    for cube in input {
+        let (x, y, z) = cube.inner();
+        out += &format!("{x},{y},{z}\n");
+    }
+    out
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::day18::input::parse;
+    use crate::random::check_round_trip;
+
+    #[test]
+    fn check_generated_input() {
+        check_round_trip(generate, print, parse);
+    }
+}
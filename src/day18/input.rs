@@ -1,4 +1,5 @@
 use crate::day18::Input;
+use crate::Result;
 
 /// Represents a 1x1x1 cube in 3D space
 #[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
@@ -27,7 +28,7 @@ impl From<(i32, i32, i32)> for Cube {
 /// Module wrapping the parsing functions for today's puzzle input
 mod parser {
     use super::*;
-    use anyhow::{anyhow, Result};
+    use crate::error::{finish, Result};
     use nom::{
         branch::alt,
         bytes::complete::tag,
@@ -35,7 +36,7 @@ mod parser {
         combinator::map,
         multi::separated_list0,
         sequence::{terminated, tuple},
-        Finish, IResult,
+        IResult,
     };
     use std::collections::HashSet;
 
@@ -63,16 +64,21 @@ mod parser {
 
     /// Parses the input file into a HashSet of Cubes
     pub fn parse(s: &str) -> Result<HashSet<Cube>> {
-        let (_, result) = cubes(s).finish().map_err(|e| anyhow!("{e}"))?;
+        let result = finish(s, cubes(s))?;
         Ok(result.into_iter().collect::<HashSet<_>>())
     }
 }
 
-const INPUT: &str = include_str!("../../input/18/input.txt");
+pub(crate) const INPUT: &str = include_str!("../../input/18/input.txt");
+
+/// Parse the text of an input file
+pub fn parse(input: &str) -> Result<Input> {
+    parser::parse(input)
+}
 
 /// Parse that input!
 pub fn read() -> Input {
-    parser::parse(INPUT).unwrap()
+    parse(INPUT).unwrap()
 }
 
 #[cfg(test)]
@@ -1,10 +1,10 @@
 use super::input::Cube;
-use crate::day18::{Input, Output};
+use crate::day18::{Input, Output, Result};
 use std::collections::HashSet;
 use std::ops::Add;
 
 /// Solve Day 18, Part 1
-pub fn solve(input: &Input) -> Output {
+pub fn solve(input: &Input) -> Result<Output> {
     let mut surface_area = 0;
 
     // For each cube in the input, look at each cube that shares
@@ -18,7 +18,7 @@ pub fn solve(input: &Input) -> Output {
         }
     }
 
-    surface_area.into()
+    Ok(surface_area.into())
 }
 
 /// Represents an offset used to adjust a Cube location
@@ -1,11 +1,11 @@
 use super::input::Cube;
 use super::part1::Offset;
-use crate::day18::{Input, Output};
+use crate::day18::{Input, Output, Result};
 use std::collections::HashSet;
 use std::ops::RangeInclusive;
 
 /// Solve Day 18, Part 2
-pub fn solve(input: &Input) -> Output {
+pub fn solve(input: &Input) -> Result<Output> {
     // Identify the bounding box that contains all the Cubes for the lava
     // plus at least one extra in each dimension.
     let bounds = input.get_bounds();
@@ -53,7 +53,7 @@ pub fn solve(input: &Input) -> Output {
         }
     }
 
-    surface_area.into()
+    Ok(surface_area.into())
 }
 
 /// Represents the 3D range that contains all the air Cubes we want to explore,
@@ -0,0 +1,82 @@
+use crate::day19::input::{Recipe, Resource, ResourceCountArray};
+use crate::day19::{Blueprint, Input};
+use crate::random::Rng;
+
+/// Generate `size` random blueprints, numbered from 1. Costs are in the same ranges
+/// as the puzzle input: ore and clay robots cost a little ore, obsidian robots cost
+/// ore and clay, and geode robots cost ore and obsidian.
+pub fn generate(rng: &mut Rng, size: usize) -> Input {
+    (1..=size as u32)
+        .map(|id| {
+            let mut recipe = |bot, costs: [(Resource, u32, u32); 2]| {
+                let mut cost = ResourceCountArray::default();
+                for (resource, min, max) in costs {
+                    cost[resource] += rng.range(min..=max);
+                }
+                Recipe { bot, cost }
+            };
+            let recipes = [
+                recipe(
+                    Resource::Ore,
+                    [(Resource::Ore, 2, 4), (Resource::Clay, 0, 0)],
+                ),
+                recipe(
+                    Resource::Clay,
+                    [(Resource::Ore, 2, 4), (Resource::Clay, 0, 0)],
+                ),
+                recipe(
+                    Resource::Obsidian,
+                    [(Resource::Ore, 2, 4), (Resource::Clay, 5, 20)],
+                ),
+                recipe(
+                    Resource::Geode,
+                    [(Resource::Ore, 2, 4), (Resource::Obsidian, 5, 20)],
+                ),
+            ];
+            Blueprint { id, recipes }
+        })
+        .collect()
+}
+
+/// Print the blueprints the way they're written in the input
+pub fn print(input: &Input) -> String {
+    let name = |resource| match resource {
+        Resource::Ore => "ore",
+        Resource::Clay => "clay",
+        Resource::Obsidian => "obsidian",
+        Resource::Geode => "geode",
+    };
+    let resources = [
+        Resource::Ore,
+        Resource::Clay,
+        Resource::Obsidian,
+        Resource::Geode,
+    ];
+
+    let mut out = String::new();
+    for Blueprint { id, recipes } in input {
+        out += &format!("Blueprint {id}:");
+        for Recipe { bot, cost } in recipes {
+            let costs: Vec<_> = resources
+                .iter()
+                .filter(|resource| cost[**resource] > 0)
+                .map(|resource| format!("{} {}", cost[*resource], name(*resource)))
+                .collect();
+            out += &format!(" Each {} robot costs {}.", name(*bot), costs.join(" and "));
+        }
+        out.push('\n');
+    }
+    out
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::day19::input::parse;
+    use crate::random::check_round_trip;
+
+    #[test]
+    fn check_generated_input() {
+        check_round_trip(generate, print, parse);
+    }
+}
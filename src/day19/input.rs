@@ -1,4 +1,5 @@
 use crate::day19::Input;
+use crate::Result;
 use std::ops::{Add, AddAssign, Index, IndexMut, Mul, Sub, SubAssign};
 
 /// Represents one of the resource types we're dealing with today.
@@ -149,7 +150,7 @@ pub struct Blueprint {
 /// I can think of (or steal) a better idea.
 pub mod parser {
     use super::*;
-    use anyhow::{anyhow, Result};
+    use crate::error::{finish, Result};
     use nom::{
         branch::alt,
         bytes::complete::tag,
@@ -157,7 +158,7 @@ pub mod parser {
         combinator::{opt, value},
         multi::separated_list0,
         sequence::{delimited, pair, preceded, separated_pair, tuple},
-        Finish, IResult,
+        IResult,
     };
 
     /// Nom parser for "ore" -> Resource::Ore
@@ -237,16 +238,20 @@ pub mod parser {
 
     /// Entrypoint for the parsing functions
     pub fn parse(s: &str) -> Result<Vec<Blueprint>> {
-        let (_, result) = blueprints(s).finish().map_err(|e| anyhow!("{e}"))?;
-        Ok(result)
+        finish(s, blueprints(s))
     }
 }
 
-const INPUT: &str = include_str!("../../input/19/input.txt");
+pub(crate) const INPUT: &str = include_str!("../../input/19/input.txt");
+
+/// Parse the text of an input file
+pub fn parse(input: &str) -> Result<Input> {
+    parser::parse(input)
+}
 
 /// Parse that input
 pub fn read() -> Input {
-    parser::parse(INPUT).unwrap()
+    parse(INPUT).unwrap()
 }
 
 #[cfg(test)]
@@ -1,7 +1,8 @@
 use super::input::{Blueprint, Recipe, Resource, ResourceCountArray};
-use crate::day19::{Input, Output};
+use crate::day19::{Input, Output, Result};
+use crate::{search, Error};
 use rayon::prelude::*;
-use std::collections::{BinaryHeap, HashMap, HashSet};
+use std::hash::{Hash, Hasher};
 use std::iter::zip;
 use std::ops::{Index, IndexMut};
 use Resource::*;
@@ -11,20 +12,31 @@ use Resource::*;
 /// I finally found a good an legitimate use for `rayon`! I probably could have used
 /// parallel processing on Day 16, too. May try that later. Here, though, we can
 /// process each blueprint in parallel, really helping with speed.
-pub fn solve(input: &Input) -> Output {
-    input
+pub fn solve(input: &Input) -> Result<Output> {
+    Ok(input
         .par_iter()
         .map(|blueprint| Factory::new(*blueprint, 24))
         .map(|factory| factory.quality_level())
-        .sum::<u32>()
-        .into()
+        .sum::<Result<u32>>()?
+        .into())
+}
+
+impl Blueprint {
+    /// The most of a resource any one recipe needs
+    fn most_needed(&self, resource: Resource) -> u32 {
+        self.recipes
+            .iter()
+            .map(|recipe| recipe.cost[resource])
+            .max()
+            .unwrap_or_default()
+    }
 }
 
 /// It's a Factory that produces Factories! Represents each state of resource
 /// production and includes the original blueprint, the number of turns remaining,
 /// the current bot count, the current stockpile of resources, and the total number
 /// of each type of resource produced.
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy)]
 pub struct Factory {
     blueprint: Blueprint,
     remaining: u32,
@@ -33,21 +45,19 @@ pub struct Factory {
     produced: ResourceCountArray,
 }
 
-/// Sorting for Factory, so that the state closest to completion floats to
-/// the top of the Binary Heap we'll use for the graph search algorithm. "Greater"
-/// values for Factory are those where the most geodes can possibly be produced
-/// using the best-guess estimate.
-impl Ord for Factory {
-    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
-        let self_estimate = self.best_estimate(Geode);
-        let other_estimate = other.best_estimate(Geode);
-        self_estimate.cmp(&other_estimate)
+/// Two Factories with the same key are the same state as far as the search is
+/// concerned (see `Factory::key()`), so that's what makes them equal.
+impl PartialEq for Factory {
+    fn eq(&self, other: &Self) -> bool {
+        self.key() == other.key()
     }
 }
 
-impl PartialOrd for Factory {
-    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
-        Some(self.cmp(other))
+impl Eq for Factory {}
+
+impl Hash for Factory {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.key().hash(state);
     }
 }
 
@@ -77,6 +87,13 @@ impl Factory {
             return None;
         }
 
+        // There's no point having more of a bot than the most of its resource any
+        // recipe needs, since we can only spend that much of it per minute anyway.
+        // Geodes are the exception, since we don't spend those at all.
+        if bot != Geode && self.bots[bot] >= self.blueprint.most_needed(bot) {
+            return None;
+        }
+
         // If there's only one turn left, then skip it. This factory won't
         // produce anything else.
         if self.remaining == 1 {
@@ -147,63 +164,71 @@ impl Factory {
         new_resources + self.produced[resource]
     }
 
-    /// This is our unique identifier for a given Factory. It's the simplest
-    /// value that identifies a Factory uniquely _enough_ to find the right
-    /// solution.
-    fn key(&self) -> ResourceCountArray {
-        self.bots + self.produced
+    /// This is our unique identifier for a given Factory. Two Factories with the
+    /// same bots and stockpile at the same minute will produce the same geodes from
+    /// here on out. Adding the bots to the resources produced made for a smaller
+    /// key, but different Factories could share it, which cost a couple of geodes
+    /// on the example blueprints.
+    ///
+    /// Stockpiles can get big, though, and most of the difference between them
+    /// doesn't matter. Once there's enough of a resource stockpiled to pay for the
+    /// priciest recipe every minute until time runs out, having more of it won't
+    /// change a thing. So the key only counts each stockpile up to that much, which
+    /// lets a whole lot more Factories share a key.
+    fn key(&self) -> (ResourceCountArray, ResourceCountArray, u32) {
+        let mut stockpile = self.stockpile;
+        for resource in [Ore, Clay, Obsidian] {
+            let (needed, bots) = (self.blueprint.most_needed(resource), self.bots[resource]);
+            let enough = (needed * self.remaining)
+                .saturating_sub(bots * self.remaining.saturating_sub(1))
+                .max(needed);
+            stockpile[resource] = stockpile[resource].min(enough);
+        }
+        (self.bots, stockpile, self.remaining)
     }
 
     /// Performs a modified A* search through the possible Factory states,
     /// seeking a state that produces the most possible geodes.
-    pub fn geodes_produced(&self) -> u32 {
-        let mut heap = BinaryHeap::from([*self]);
-        let mut seen = HashSet::new();
-        let mut most_geodes = 0; // Used for optimization
-
-        // So long as the heap still has items on it...
-        while let Some(state) = heap.pop() {
-            // If we reached a state where time runs out, we've identified
-            // the state producing the most geodes, assuming we've implemented
-            // the ordering of Factories correctly.
-            if state.remaining == 0 {
-                return state.stockpile[Geode];
-            }
-
-            // If we've seen this state already, skip it.
-            if seen.contains(&state.key()) {
-                continue;
-            }
+    pub fn geodes_produced(&self) -> Result<u32> {
+        // If we reached a state where time runs out, we've identified the state
+        // producing the most geodes. The search shouldn't come up empty, since every
+        // Factory can always wait for time to run out, but just in case.
+        let (goal, _) = self.search().ok_or_else(|| {
+            let id = self.blueprint.id;
+            Error::no_solution(format!("Blueprint {id} never ran out of time!"))
+        })?;
+        Ok(goal.stockpile[Geode])
+    }
 
-            // Otherwise, mark it as seen. Update the most geodes produced
-            // by any state seen so far.
-            seen.insert(state.key());
+    /// The search itself. A* finds the _cheapest_ way to a goal, so think of every
+    /// geode as a negative cost. Moving from one state to the next doesn't cost
+    /// anything, and the heuristic is the negative of our best estimate, so the
+    /// state closest to completion (where the most geodes can possibly be produced)
+    /// is always checked next. Since the best estimate is an overestimate, the first
+    /// state we find where time has run out produces the most geodes. That state is
+    /// all we need, not how we got there, so that's all the search hands back.
+    fn search(&self) -> Option<(Factory, i64)> {
+        let mut most_geodes = 0; // Used for optimization
+        let successors = |state: &Factory| {
+            // Update the most geodes produced by any state seen so far
             most_geodes = most_geodes.max(state.produced[Geode]);
 
-            for next_state in state.next_states() {
-                // If we've seen this `next_state` before, skip it.
-                if seen.contains(&next_state.key()) {
-                    continue;
-                }
-
-                // If the best possible geode production for this state is still
-                // less than the most geodes we've actually seen in a state so
-                // far, skip it. The best estimate is an overestimate by design.
-                if next_state.best_estimate(Geode) < most_geodes {
-                    continue;
-                }
-
-                // Add this state to the heap to be checked.
-                heap.push(next_state);
-            }
-        }
-
-        // This should never happen
-        unreachable!()
+            // If the best possible geode production for a next state is still
+            // less than the most geodes we've actually seen in a state so far,
+            // skip it. The best estimate is an overestimate by design.
+            let most_geodes = most_geodes;
+            state
+                .next_states()
+                .filter(|next_state| next_state.best_estimate(Geode) >= most_geodes)
+                .map(|next_state| (next_state, 0))
+                .collect::<Vec<_>>()
+        };
+        let estimate = |state: &Factory| -i64::from(state.best_estimate(Geode));
+        search::astar_goal(*self, successors, estimate, |state| state.remaining == 0)
     }
 
     /// Calcualate the quality level of this Factory
-    fn quality_level(&self) -> u32 {
-        self.blueprint.id * self.geodes_produced()
+    fn quality_level(&self) -> Result<u32> {
+        Ok(self.blueprint.id * self.geodes_produced()?)
     }
 }
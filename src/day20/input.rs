@@ -1,9 +1,30 @@
 use crate::day20::Input;
+use crate::{Error, Result};
 
-const INPUT: &str = include_str!("../../input/20/input.txt");
+pub(crate) const INPUT: &str = include_str!("../../input/20/input.txt");
+
+pub fn parse(input: &str) -> Result<Input> {
+    let mut numbers = Vec::new();
+    for line in input.lines() {
+        let number = line
+            .parse::<i64>()
+            .map_err(|e| Error::parse(input, line, e))?;
+        numbers.push(number);
+    }
+
+    // The grove coordinates are counted from the zero, so there had better be one.
+    if !numbers.contains(&0) {
+        return Err(Error::parse(
+            input,
+            "",
+            "the list of numbers must contain a zero",
+        ));
+    }
+    Ok(numbers)
+}
 
 pub fn read() -> Input {
-    INPUT.lines().flat_map(|l| l.parse::<i64>()).collect()
+    parse(INPUT).unwrap()
 }
 
 #[cfg(test)]
@@ -1,15 +1,15 @@
-use crate::day20::{Input, Output};
+use crate::day20::{Input, Output, Result};
 use std::collections::HashMap;
 use std::ops::{Add, AddAssign, Index, IndexMut};
 
 /// Solve Day 20, Part 1
-pub fn solve(input: &Input) -> Output {
+pub fn solve(input: &Input) -> Result<Output> {
     // Make a decryptor!
     let mut decryptor = MixingDecryptor::from(input.clone());
     decryptor.mix();
 
     // Just like that!
-    decryptor.grove_coordinates_sum().into()
+    Ok(decryptor.grove_coordinates_sum().into())
 }
 
 /// Really just a convenient struct for bundling together the three vectors
@@ -30,10 +30,7 @@ impl From<Vec<i64>> for MixingDecryptor {
         // The forward links are the indices of the nodes that come after the
         // node at the same index as the link. So, `forward_links[node[0]]`
         // in the unmixed list would be `1`.
-        let forward_links: Vec<_> = (1..nodes.len())
-            .into_iter()
-            .chain(std::iter::once(0))
-            .collect();
+        let forward_links: Vec<_> = (1..nodes.len()).chain(std::iter::once(0)).collect();
 
         // The backward links are the indices of the nodes that come before the
         // node at the same index as the link.
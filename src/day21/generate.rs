@@ -0,0 +1,87 @@
+use crate::day21::input::{Environment, Expression, Label, Value};
+use crate::day21::Input;
+use crate::random::Rng;
+use std::collections::HashSet;
+
+/// Generate a random tree of monkeys with `size` monkeys doing math, topped off
+/// by `root` and with `humn` yelling one of the numbers at the leaves. Each monkey
+/// doing math waits on two other monkeys that nobody else is waiting on.
+pub fn generate(rng: &mut Rng, size: usize) -> Input {
+    // Come up with a unique name for every monkey
+    let mut names = HashSet::from([String::from("root"), String::from("humn")]);
+    let mut labels = Vec::new();
+    while labels.len() < size * 2 - 1 {
+        let name: String = (0..4).map(|_| rng.range(b'a'..=b'z') as char).collect();
+        if names.insert(name.clone()) {
+            labels.push(Label::try_from(name.as_str()).unwrap());
+        }
+    }
+    labels.insert(
+        rng.range(0..=labels.len()),
+        Label::try_from("humn").unwrap(),
+    );
+    let mut labels = labels.into_iter();
+
+    // Start with a monkey yelling a number for every leaf of the tree, then keep
+    // having a new monkey combine two of the remaining monkeys until only one is left.
+    let mut env = Environment(Default::default());
+    let mut waiting = Vec::new();
+    for _ in 0..=size {
+        let label = labels.next().unwrap();
+        let value = Value::Raw(rng.range(1..=20));
+        env.0.insert(label, Expression::Val(value));
+        waiting.push(label);
+    }
+    rng.shuffle(&mut waiting);
+    while waiting.len() > 1 {
+        let lhs = Value::Ref(waiting.pop().unwrap());
+        let rhs = Value::Ref(waiting.pop().unwrap());
+        let label = match waiting.is_empty() {
+            true => Label::try_from("root").unwrap(),
+            false => labels.next().unwrap(),
+        };
+        let expression = match rng.range(0..=3) {
+            0 => Expression::Add(lhs, rhs),
+            1 => Expression::Sub(lhs, rhs),
+            2 => Expression::Mul(lhs, rhs),
+            _ => Expression::Div(lhs, rhs),
+        };
+        env.0.insert(label, expression);
+        waiting.insert(rng.range(0..=waiting.len()), label);
+    }
+    env
+}
+
+/// Print the monkeys the way they're listed in the input, sorted by name so the
+/// same Environment always prints the same way.
+pub fn print(input: &Input) -> String {
+    let value = |value: &Value| match value {
+        Value::Ref(label) => label.to_string(),
+        Value::Raw(number) => number.to_string(),
+    };
+    let mut lines: Vec<_> = input
+        .0
+        .iter()
+        .map(|(label, expression)| match expression {
+            Expression::Add(lhs, rhs) => format!("{label}: {} + {}", value(lhs), value(rhs)),
+            Expression::Sub(lhs, rhs) => format!("{label}: {} - {}", value(lhs), value(rhs)),
+            Expression::Mul(lhs, rhs) => format!("{label}: {} * {}", value(lhs), value(rhs)),
+            Expression::Div(lhs, rhs) => format!("{label}: {} / {}", value(lhs), value(rhs)),
+            Expression::Val(number) => format!("{label}: {}", value(number)),
+        })
+        .collect();
+    lines.sort();
+    lines.into_iter().map(|line| line + "\n").collect()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::day21::input::parse;
+    use crate::random::check_round_trip;
+
+    #[test]
+    fn check_generated_input() {
+        check_round_trip(generate, print, parse);
+    }
+}
@@ -1,4 +1,5 @@
 use crate::day21::Input;
+use crate::Result;
 use anyhow::{bail, Error};
 use std::collections::HashMap;
 
@@ -26,6 +27,13 @@ impl TryFrom<&str> for Label {
     }
 }
 
+/// Display a Label the way it's written in the input
+impl std::fmt::Display for Label {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.0.iter().try_for_each(|ch| write!(f, "{ch}"))
+    }
+}
+
 /// Represents a Value being passed from monkey to monkey. Either a reference
 /// to another monkey, or a raw number.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -57,13 +65,13 @@ pub enum Expression {
 
 /// Represents the environment in which monkey math is being evaluated. Keeps
 /// track of each variable (monkey) and their current assigned Expression.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub struct Environment(pub HashMap<Label, Expression>);
 
 /// Wraps the parsing functions for today's input.
 pub mod parser {
     use super::*;
-    use anyhow::{anyhow, Result};
+    use crate::error::{finish, Result};
     use nom::{
         branch::alt,
         bytes::complete::tag,
@@ -71,7 +79,7 @@ pub mod parser {
         combinator::{map, map_res, verify},
         multi::separated_list0,
         sequence::separated_pair,
-        Finish, IResult,
+        IResult,
     };
 
     /// Nom parser for "humn" -> Label(['h', 'u', 'm', 'n'])
@@ -143,16 +151,21 @@ pub mod parser {
 
     /// Parses the input file into an Environment
     pub fn parse(s: &str) -> Result<Environment> {
-        let (_, result) = expressions(s).finish().map_err(|e| anyhow!("{e}"))?;
+        let result = finish(s, expressions(s))?;
         Ok(Environment(result))
     }
 }
 
-const INPUT: &str = include_str!("../../input/21/input.txt");
+pub(crate) const INPUT: &str = include_str!("../../input/21/input.txt");
+
+/// Parse the text of an input file
+pub fn parse(input: &str) -> Result<Input> {
+    parser::parse(input)
+}
 
 /// Parse that input!
 pub fn read() -> Input {
-    parser::parse(INPUT).unwrap()
+    parse(INPUT).unwrap()
 }
 
 #[cfg(test)]
@@ -1,39 +1,66 @@
+pub mod generate;
 pub mod input;
 pub mod part1;
 pub mod part2;
 
-use crate::{Output, Part};
+use crate::{Output, Part, Result, Solution, Source};
 use input::Environment;
 use std::collections::HashMap;
 
 pub type Input = Environment;
 
-pub fn run(part: Part) -> Output {
-    let input = input::read();
-    match part {
-        Part::One => part1::solve(&input),
-        Part::Two => part2::solve(&input),
+pub struct Day21;
+
+impl Solution for Day21 {
+    const DAY: u8 = 21;
+    const INPUT: &'static str = input::INPUT;
+    type Input<'a> = Input;
+
+    fn parse(text: &str) -> Result<Self::Input<'_>> {
+        input::parse(text)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Result<Output> {
+        part1::solve(input)
     }
-}
 
-pub fn run_both() -> (Output, Output) {
-    let input = input::read();
-    (part1::solve(&input), part2::solve(&input))
+    fn part2(input: &Self::Input<'_>) -> Result<Output> {
+        part2::solve(input)
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::examples;
 
     #[test]
     fn check_answer_one() {
-        let result = run(Part::One);
+        let result = Day21::run(Part::One, Source::Bundled).unwrap();
         println!("{result}");
     }
 
     #[test]
     fn check_answer_two() {
-        let result = run(Part::Two);
+        let result = Day21::run(Part::Two, Source::Bundled).unwrap();
         println!("{result}");
     }
+
+    #[test]
+    fn check_example_one() {
+        examples::check::<Day21>(Part::One);
+    }
+
+    #[test]
+    fn check_example_two() {
+        examples::check::<Day21>(Part::Two);
+    }
+
+    #[test]
+    fn check_division_by_zero() {
+        let text = include_str!("../../input/21/test.txt").replace("lfqf: 4", "lfqf: 0");
+        let input = Day21::parse(&text).unwrap();
+        assert!(Day21::part1(&input).is_err());
+        assert!(Day21::part2(&input).is_err());
+    }
 }
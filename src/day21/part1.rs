@@ -1,10 +1,13 @@
 use super::input::{Environment, Expression, Label, Value};
-use crate::day21::{Input, Output};
+use crate::day21::{Input, Output, Result};
+use crate::Error;
 
 /// Solve Day 21, Part 1
-pub fn solve(input: &Input) -> Output {
+pub fn solve(input: &Input) -> Result<Output> {
     let root = Value::try_from("root").unwrap();
-    root.eval(input).unwrap().into()
+    root.eval(input).map(Output::from).ok_or(Error::no_solution(
+        "Could not evaluate the \"root\" monkey!",
+    ))
 }
 
 /// Fetch an Expression from the Environment by Label
@@ -32,12 +35,13 @@ impl Eval for Value {
 impl Eval for Expression {
     /// Recursively evaluate the Value until a Value::Raw can be returned
     fn eval(&self, env: &Environment) -> Option<i128> {
-        // Perform operations based on the type of Expression
+        // Perform operations based on the type of Expression, giving up on
+        // anything that overflows or divides by zero
         match self {
-            Expression::Add(v1, v2) => Some(v1.eval(env)? + v2.eval(env)?),
-            Expression::Sub(v1, v2) => Some(v1.eval(env)? - v2.eval(env)?),
-            Expression::Mul(v1, v2) => Some(v1.eval(env)? * v2.eval(env)?),
-            Expression::Div(v1, v2) => Some(v1.eval(env)? / v2.eval(env)?),
+            Expression::Add(v1, v2) => v1.eval(env)?.checked_add(v2.eval(env)?),
+            Expression::Sub(v1, v2) => v1.eval(env)?.checked_sub(v2.eval(env)?),
+            Expression::Mul(v1, v2) => v1.eval(env)?.checked_mul(v2.eval(env)?),
+            Expression::Div(v1, v2) => v1.eval(env)?.checked_div(v2.eval(env)?),
             Expression::Val(value) => value.eval(env),
         }
     }
@@ -1,7 +1,8 @@
 use super::input::{Environment, Expression, Label, Value};
-use crate::day21::{Input, Output};
+use crate::day21::{Input, Output, Result};
+use crate::Error;
 
-pub fn solve(input: &Input) -> Output {
+pub fn solve(input: &Input) -> Result<Output> {
     use Expression::*;
     use Value::*;
 
@@ -15,7 +16,9 @@ pub fn solve(input: &Input) -> Output {
     // let's get the two variables that the boss monkey will be checking and
     // determine what we get if we try to evaluate them.
     let root_lbl = Label::try_from("root").unwrap();
-    let Add(left, right) = env.resolve(&root_lbl).unwrap() else { panic!("No root monkey!"); };
+    let Some(Add(left, right)) = env.resolve(&root_lbl) else {
+        return Err(Error::no_solution("No root monkey!"));
+    };
 
     // The PartialEval trait attempts to evaluate the calling expression and
     // updates each variable in the environment that can be evalutated along
@@ -31,11 +34,15 @@ pub fn solve(input: &Input) -> Output {
     // "humn". So. let's check what we got from evaluating the left and right
     // variables and see which one returned an actual value. That will be the
     // value we start adjusting moving forward. The other variable value will
-    // represent the formula we need to solve.
+    // represent the formula we need to solve. If that's not the case for some
+    // other input, we give up.
+    let unsolvable = || Error::no_solution("More than one path depends on \"humn\"!");
+    let divide_by_zero = || Error::no_solution("Can't solve for \"humn\" past a division by zero!");
+    let divide = |num: i128, by: i128| num.checked_div(by).ok_or_else(divide_by_zero);
     let (mut carry, mut next_op) = match (left_val, right_val) {
         (lhs, Val(Raw(val))) => (val, Some(lhs)),
         (Val(Raw(val)), rhs) => (val, Some(rhs)),
-        _ => unreachable!(),
+        _ => return Err(unsolvable()),
     };
 
     // Since we know that both values checked by the "root" monkey must be equal,
@@ -53,35 +60,37 @@ pub fn solve(input: &Input) -> Output {
     // find the value of "humn".
     while let Some(expr) = next_op {
         // Rearrange the expressions based on the value we're carrying forward and
-        // the type of operation being performed. There's a lot of "unreachable"
-        // points along the way that are possible because we know that every
-        // variable that doesn't depend on "humn" has already been fully evaluated.
+        // the type of operation being performed. There's a lot of "unsolvable"
+        // points along the way that we can't actually reach because we know that
+        // every variable that doesn't depend on "humn" has already been fully
+        // evaluated, at least if only one path depends on "humn".
         (carry, next_op) = match expr {
             Add(lhs, rhs) => match (lhs, rhs) {
                 (Ref(lhs), Raw(val)) => (carry - val, env.resolve(&lhs)),
                 (Raw(val), Ref(rhs)) => (carry - val, env.resolve(&rhs)),
-                (_, _) => unreachable!(),
+                (_, _) => return Err(unsolvable()),
             },
             Sub(lhs, rhs) => match (lhs, rhs) {
                 (Ref(lhs), Raw(val)) => (val + carry, env.resolve(&lhs)),
                 (Raw(val), Ref(rhs)) => (val - carry, env.resolve(&rhs)),
-                (_, _) => unreachable!(),
+                (_, _) => return Err(unsolvable()),
             },
             Mul(lhs, rhs) => match (lhs, rhs) {
-                (Ref(lhs), Raw(val)) => (carry / val, env.resolve(&lhs)),
-                (Raw(val), Ref(rhs)) => (carry / val, env.resolve(&rhs)),
-                (_, _) => unreachable!(),
+                (Ref(lhs), Raw(val)) => (divide(carry, val)?, env.resolve(&lhs)),
+                (Raw(val), Ref(rhs)) => (divide(carry, val)?, env.resolve(&rhs)),
+                (_, _) => return Err(unsolvable()),
             },
             Div(lhs, rhs) => match (lhs, rhs) {
+                (Ref(_), Raw(0)) => return Err(divide_by_zero()),
                 (Ref(lhs), Raw(val)) => (val * carry, env.resolve(&lhs)),
-                (Raw(val), Ref(rhs)) => (val / carry, env.resolve(&rhs)),
-                (_, _) => unreachable!(),
+                (Raw(val), Ref(rhs)) => (divide(val, carry)?, env.resolve(&rhs)),
+                (_, _) => return Err(unsolvable()),
             },
-            Val(_) => unreachable!(),
+            Val(_) => return Err(unsolvable()),
         };
     }
 
-    carry.into()
+    Ok(carry.into())
 }
 
 /// Remove an expression from the Environment based on Label
@@ -147,6 +156,8 @@ impl PartialEval for Expression {
                 (_, _) => *self,
             },
             Div(v1, v2) => match (v1.partial_eval(env), v2.partial_eval(env)) {
+                // Dividing by zero can't be evaluated, so it stays as it is
+                (Val(Raw(lhs)), Val(Raw(0))) => Div(Raw(lhs), Raw(0)),
                 (Val(Raw(lhs)), Val(Raw(rhs))) => Val(Raw(lhs / rhs)),
                 (_, Val(Raw(rhs))) => Div(*v1, Raw(rhs)),
                 (Val(Raw(lhs)), _) => Div(Raw(lhs), *v2),
@@ -0,0 +1,71 @@
+use crate::day22::input::{Direction, Links, MonkeyMap, Tile};
+use crate::day22::Input;
+use crate::grid::Grid;
+use crate::random::Rng;
+
+/// Generate a random map with `size` rows, each indented by a random amount and
+/// sprinkled with walls, followed by a path that alternates between moving forward
+/// and turning. The map doesn't fold up into a cube, so it's only good for part one.
+pub fn generate(rng: &mut Rng, size: usize) -> Input {
+    let path = Tile::Path(Links::default());
+    let rows: Vec<_> = (0..size)
+        .map(|_| {
+            let indent = rng.range(0..=size);
+            let width = rng.range(1..=size + 1);
+            let mut row = vec![Tile::Void; indent];
+            row.extend((0..width).map(|_| match rng.chance(0.1) {
+                true => Tile::Wall,
+                false => path,
+            }));
+            row
+        })
+        .collect();
+    let mut board = MonkeyMap(Grid::from_rows(rows, Tile::Void));
+    board.map_positions();
+
+    let mut directions = vec![Direction::Forward(rng.range(1..=50))];
+    for _ in 0..size {
+        directions.push(*rng.pick(&[Direction::TurnLeft, Direction::TurnRight]));
+        directions.push(Direction::Forward(rng.range(1..=50)));
+    }
+    (board, directions)
+}
+
+/// Print the map and the path the way they're written in the input
+pub fn print(input: &Input) -> String {
+    let (board, directions) = input;
+    let mut out = String::new();
+    let tiles = board.0.render(|tile| match tile {
+        Tile::Void => ' ',
+        Tile::Path(_) => '.',
+        Tile::Wall => '#',
+    });
+
+    // The input doesn't pad the short rows out with spaces, so neither do we
+    for row in tiles.lines() {
+        out.push_str(row.trim_end());
+        out.push('\n');
+    }
+    out.push('\n');
+    for direction in directions {
+        match direction {
+            Direction::TurnLeft => out.push('L'),
+            Direction::TurnRight => out.push('R'),
+            Direction::Forward(steps) => out += &steps.to_string(),
+        }
+    }
+    out.push('\n');
+    out
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::day22::input::parse;
+    use crate::random::check_round_trip;
+
+    #[test]
+    fn check_generated_input() {
+        check_round_trip(generate, print, parse);
+    }
+}
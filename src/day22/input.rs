@@ -1,4 +1,6 @@
 use crate::day22::Input;
+use crate::grid::{Grid, Offset, Point};
+use crate::Result;
 use itertools::Itertools;
 use std::ops::{Index, IndexMut};
 
@@ -46,7 +48,7 @@ impl Tile {
 }
 
 /// Represents a movement direction, given on the last line of the input file.
-#[derive(Debug, Copy, Clone)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
 pub enum Direction {
     TurnLeft,
     TurnRight,
@@ -54,14 +56,16 @@ pub enum Direction {
 }
 
 /// Represents the tiles on the map given to us by the monkeys, with the added
-/// metadata from us added in.
-#[derive(Debug, Clone)]
-pub struct MonkeyMap(pub Vec<Vec<Tile>>);
+/// metadata from us added in. The input doesn't have spaces at the ends of lines
+/// where the map doesn't span to the end of the line, so the short rows get padded
+/// out with Tile::Void.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MonkeyMap(pub Grid<Tile>);
 
 /// Namespacing for the parsers used in today's puzzle.
 mod parser {
     use super::*;
-    use anyhow::{anyhow, Result};
+    use crate::error::{finish, Result};
     use nom::{
         branch::alt,
         bytes::complete::{tag, take_till, take_while},
@@ -69,7 +73,7 @@ mod parser {
         combinator::{map, value},
         multi::{many0, many1, separated_list0},
         sequence::{pair, separated_pair},
-        Finish, IResult,
+        IResult,
     };
 
     /// Nom parser for " " -> Tile::Void
@@ -99,7 +103,9 @@ mod parser {
 
     /// Nom parser for all the tiles in the input
     pub fn monkey_map(s: &str) -> IResult<&str, MonkeyMap> {
-        map(separated_list0(newline, tile_line), MonkeyMap)(s)
+        map(separated_list0(newline, tile_line), |rows| {
+            MonkeyMap(Grid::from_rows(rows, Tile::Void))
+        })(s)
     }
 
     /// Nom parser for "10" -> Direction::Forward(10)
@@ -136,8 +142,7 @@ mod parser {
     /// Entrypoint for parser combinators, parses the input file into a
     /// MonkeyMap and list of Directions.
     pub fn parse(s: &str) -> Result<(MonkeyMap, Vec<Direction>)> {
-        let (_, result) = both_parts(s).finish().map_err(|e| anyhow!("{e}"))?;
-        Ok(result)
+        finish(s, both_parts(s))
     }
 }
 
@@ -148,7 +153,7 @@ impl Index<Position> for MonkeyMap {
 
     fn index(&self, index: Position) -> &Self::Output {
         let Position(row, col) = index;
-        &self.0[row][col]
+        &self.0[(row, col)]
     }
 }
 
@@ -156,7 +161,19 @@ impl Index<Position> for MonkeyMap {
 impl IndexMut<Position> for MonkeyMap {
     fn index_mut(&mut self, index: Position) -> &mut Self::Output {
         let Position(row, col) = index;
-        &mut self.0[row][col]
+        &mut self.0[(row, col)]
+    }
+}
+
+impl Heading {
+    /// The step on the grid that moves one Tile in this Heading
+    fn offset(&self) -> Offset {
+        match self {
+            Heading::Up => Offset::UP,
+            Heading::Right => Offset::RIGHT,
+            Heading::Down => Offset::DOWN,
+            Heading::Left => Offset::LEFT,
+        }
     }
 }
 
@@ -164,119 +181,52 @@ impl MonkeyMap {
     /// Build up the links to other Tiles on each Tile::Path in the MonkeyMap. This
     /// way, we can check a given Tile for the heading and position of the tile in
     /// each of the four cardinal directions.
-    fn map_positions(&mut self) {
-        let rows = self.0.len();
-
+    pub(crate) fn map_positions(&mut self) {
         // Iterating over the indices keeps borrow checker wrangling to a minimum
-        // here. We need to get the number of columns individually for each row,
-        // since each row has a variable number of columns. The input doesn't have
-        // spaces at the ends of lines where the map doesn't span to the end of the
-        // line.
-        for row in 0..rows {
-            let cols = self.0.get(row).map(|v| v.len()).unwrap_or_default();
-            for col in 0..cols {
-                if let Tile::Path(mut links) = self.0[row][col] {
-                    let position = Position(row, col);
-                    links.up = self.find_next_up(position);
-                    links.right = self.find_next_right(position);
-                    links.down = self.find_next_down(position);
-                    links.left = self.find_next_left(position);
-                    self[position] = Tile::Path(links);
-                }
+        // here.
+        let (rows, cols) = (self.0.rows(), self.0.cols());
+        for (row, col) in (0..rows).cartesian_product(0..cols) {
+            if let Tile::Path(mut links) = self.0[(row, col)] {
+                let position = Position(row, col);
+                links.up = self.find_next(position, Heading::Up);
+                links.right = self.find_next(position, Heading::Right);
+                links.down = self.find_next(position, Heading::Down);
+                links.left = self.find_next(position, Heading::Left);
+                self[position] = Tile::Path(links);
             }
         }
     }
 
     /// From a given position, identify the Heading/Position of the Tile that can
-    /// be achieved by moving up from the current position. Accounts for wrapping
-    /// around the map when moving up into an unmarked space.
-    fn find_next_up(&self, position: Position) -> Option<(Heading, Position)> {
+    /// be achieved by moving in `heading` from the current position. Accounts for
+    /// wrapping around the map when moving into an unmarked space, by stepping
+    /// (and wrapping around the edges of the grid) until we land on a Tile that
+    /// isn't a Tile::Void. There's always one of those, since we started on one.
+    fn find_next(&self, position: Position, heading: Heading) -> Option<(Heading, Position)> {
         let Position(row, col) = position;
-        let inner_iter = self.0.iter();
-
-        // This is a bit of a gnarly iterator chain, but the ultimate outcome is that
-        // it produces an iterator that starts at the current position and moves along
-        // the current column by row, in reverse, and returns the first position found
-        // that isn't a Tile::Void, which provides the wrapping functionality. This
-        // is probably the gnarliest of the four functions, since it requires iterating
-        // by column and in reverse. The rest of the `find_next_*()` functions are
-        // all variations on this one.
-        let (found_row, found_tile) = inner_iter
-            .enumerate()
-            .rev()
-            .map(|(row_idx, row)| (row_idx, row.get(col).unwrap_or(&Tile::Void)))
-            .cycle()
-            .skip(self.0.len() - row)
-            .find(|(_, tile)| !matches!(tile, Tile::Void))?;
-        if found_tile.is_path() {
-            return Some((Heading::Up, Position(found_row, col)));
-        }
-        None
-    }
-
-    /// From a given position, identify the Heading/Position of the Tile that can
-    /// be achieved by moving down from the current position. Accounts for wrapping
-    /// around the map when moving up into an unmarked space.
-    fn find_next_down(&self, position: Position) -> Option<(Heading, Position)> {
-        let Position(row, col) = position;
-        let inner_iter = self.0.iter();
-        let (found_row, found_tile) = inner_iter
-            .enumerate()
-            .map(|(row_idx, row)| (row_idx, row.get(col).unwrap_or(&Tile::Void)))
-            .cycle()
-            .skip(row + 1)
-            .find(|(_, tile)| **tile != Tile::Void)?;
-        if found_tile.is_path() {
-            return Some((Heading::Down, Position(found_row, col)));
-        }
-        None
-    }
-
-    /// From a given position, identify the Heading/Position of the Tile that can
-    /// be achieved by moving left from the current position. Accounts for wrapping
-    /// around the map when moving up into an unmarked space.
-    fn find_next_left(&self, position: Position) -> Option<(Heading, Position)> {
-        let Position(row, col) = position;
-        let row_of_tiles = self.0.get(row)?;
-        let (found_col, found_tile) = row_of_tiles
-            .iter()
-            .enumerate()
-            .rev()
-            .cycle()
-            .skip(row_of_tiles.len() - col)
-            .find(|(_, tile)| **tile != Tile::Void)?;
-        if found_tile.is_path() {
-            return Some((Heading::Left, Position(row, found_col)));
+        let mut point = Point(col as isize, row as isize);
+        loop {
+            point = (point + heading.offset()).wrap(self.0.rows(), self.0.cols());
+            let Point(col, row) = point;
+            match self.0[point] {
+                Tile::Void => continue,
+                Tile::Path(_) => return Some((heading, Position(row as usize, col as usize))),
+                Tile::Wall => return None,
+            }
         }
-        None
     }
+}
 
-    fn find_next_right(&self, position: Position) -> Option<(Heading, Position)> {
-        let Position(row, col) = position;
-        let row_of_tiles = self.0.get(row)?;
+pub(crate) const INPUT: &str = include_str!("../../input/22/input.txt");
 
-        // And this one is probably the least gnarly. Which obviously means it was
-        // the one I wrote last. I really should have written these in reverse,
-        // because writing the "up" iterator chain as a PITA and I could have
-        // used this one as a template. Ah, well.
-        let (found_col, found_tile) = row_of_tiles
-            .iter()
-            .enumerate()
-            .cycle()
-            .skip(col + 1)
-            .find(|(_, tile)| **tile != Tile::Void)?;
-        if found_tile.is_path() {
-            return Some((Heading::Right, Position(row, found_col)));
-        }
-        None
-    }
+/// Parse the text of an input file
+pub fn parse(input: &str) -> Result<Input> {
+    let (mut board, directions) = parser::parse(input)?;
+    board.map_positions();
+    Ok((board, directions))
 }
 
-const INPUT: &str = include_str!("../../input/22/input.txt");
-
 /// Parse that input!
 pub fn read() -> Input {
-    let (mut board, directions) = parser::parse(INPUT).unwrap();
-    board.map_positions();
-    (board, directions)
+    parse(INPUT).unwrap()
 }
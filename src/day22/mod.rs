@@ -1,38 +1,67 @@
+pub mod generate;
 pub mod input;
 pub mod part1;
 pub mod part2;
+pub mod visualize;
 
-use crate::{Output, Part};
+use crate::{Output, Part, Result, Solution, Source};
 use input::{Direction, MonkeyMap};
 
 pub type Input = (MonkeyMap, Vec<Direction>);
 
-pub fn run(part: Part) -> Output {
-    let input = input::read();
-    match part {
-        Part::One => part1::solve(&input),
-        Part::Two => part2::solve(&input),
+pub struct Day22;
+
+impl Solution for Day22 {
+    const DAY: u8 = 22;
+    const INPUT: &'static str = input::INPUT;
+    type Input<'a> = Input;
+
+    fn parse(text: &str) -> Result<Self::Input<'_>> {
+        input::parse(text)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Result<Output> {
+        part1::solve(input)
     }
-}
 
-pub fn run_both() -> (Output, Output) {
-    let input = input::read();
-    (part1::solve(&input), part2::solve(&input))
+    fn part2(input: &Self::Input<'_>) -> Result<Output> {
+        part2::solve(input)
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::examples;
 
     #[test]
     fn check_answer_one() {
-        let result = run(Part::One);
+        let result = Day22::run(Part::One, Source::Bundled).unwrap();
         assert_eq!(result, 13566);
     }
 
     #[test]
     fn check_answer_two() {
-        let result = run(Part::Two);
+        let result = Day22::run(Part::Two, Source::Bundled).unwrap();
         assert_eq!(result, 11451);
     }
+
+    #[test]
+    fn check_example_one() {
+        examples::check::<Day22>(Part::One);
+    }
+
+    #[test]
+    fn check_example_two() {
+        examples::check::<Day22>(Part::Two);
+    }
+
+    #[test]
+    fn check_unfoldable_map() {
+        // Six faces in a row roll up into a tube, not a cube
+        let input = Day22::parse("......\n\n1").unwrap();
+        assert!(Day22::part2(&input).is_err());
+        let input = Day22::parse(".....\n\n1").unwrap();
+        assert!(Day22::part2(&input).is_err());
+    }
 }
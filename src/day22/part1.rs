@@ -1,8 +1,9 @@
 use super::input::{Direction, Heading, Links, MonkeyMap, Position, Tile};
-use crate::day22::{Input, Output};
+use crate::day22::{Input, Output, Result};
+use crate::Error;
 
 /// Solve Day 22, Part 1
-pub fn solve(input: &Input) -> Output {
+pub fn solve(input: &Input) -> Result<Output> {
     // This bit is just so I can convert the reference to the input into a
     // mutable map. I pass in input as a reference because it keeps me from
     // accidentally mutating it in Part 1 and spending hours wondering why
@@ -11,7 +12,9 @@ pub fn solve(input: &Input) -> Output {
     let mut board = monkey_map.clone();
 
     // Start at the first path Tile on the first row, facing right
-    let Some(start_pos) = board.first_path_position() else { panic!("Cannot find start position!"); };
+    let Some(start_pos) = board.first_path_position() else {
+        return Err(Error::no_solution("Cannot find start position!"));
+    };
     let mut walker = Walker::new(start_pos);
 
     // Follow each direction
@@ -20,7 +23,7 @@ pub fn solve(input: &Input) -> Output {
         .for_each(|direction| walker.follow(&board, *direction));
 
     // Let the walker calculate its own score and return it
-    walker.score().into()
+    Ok(walker.score().into())
 }
 
 impl MonkeyMap {
@@ -28,8 +31,12 @@ impl MonkeyMap {
     /// in reading order (left to right, top to bottom), if there is
     /// one.
     pub fn first_path_position(&self) -> Option<Position> {
-        let first_row = self.0.first()?;
-        let first_col = first_row
+        if self.0.rows() == 0 {
+            return None;
+        }
+        let first_col = self
+            .0
+            .row(0)
             .iter()
             .position(|tile| matches!(tile, Tile::Path(_)))?;
         Some(Position(0, first_col))
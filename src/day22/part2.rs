@@ -1,233 +1,247 @@
-use super::input::{Direction, Heading, MonkeyMap, Position, Tile};
+use super::input::{Direction, Heading, Links, MonkeyMap, Position, Tile};
 use super::part1::Walker;
-use crate::day22::{Input, Output};
+use crate::day22::{Input, Output, Result};
+use crate::Error;
+use itertools::Itertools;
 
 /// Solve Day 22, Part 2
-pub fn solve(input: &Input) -> Output {
+pub fn solve(input: &Input) -> Result<Output> {
     let (board, directions) = input;
     let mut board = board.clone();
-    board.wrap(); // This is the difference!
-    let Some(start_pos) = board.first_path_position() else { panic!("Cannot find start position!"); };
+    board.wrap()?; // This is the difference!
+    let Some(start_pos) = board.first_path_position() else {
+        return Err(Error::no_solution("Cannot find start position!"));
+    };
     let mut walker = Walker::new(start_pos);
     directions
         .iter()
         .for_each(|direction| walker.follow(&board, *direction));
-    walker.score().into()
+    Ok(walker.score().into())
 }
 
-impl MonkeyMap {
-    /// My map looks like this, where the pairs of letters indicate pairs of edges
-    /// that line up to form the cube if this shape were folded.
-    ///
-    ///            _______ _______
-    ///           |   A   |   B   |
-    ///           |C      |      D|
-    ///           |_______|___F___|
-    ///           |       |
-    ///           |E     F|
-    ///    _______|_______|
-    ///   |   E   |       |
-    ///   |C      |      D|
-    ///   |_______|___G___|
-    ///   |       |
-    ///   |A     G|
-    ///   |___B___|
-    ///
-    /// Yours probably doesn't. This function re-maps the connections on the linkss
-    /// on the outside edges to the correct matching edge. The hard part is making
-    /// sure you match up the right edges in the right order. I recommend cutting
-    /// out the shape of your map on a sheet of paper, folding it into a cube, and
-    /// using that cube to determine how to match up the sides. This was an incredibly
-    /// tedious bit of code to write because the various joins between the open face
-    /// pairs all had their own bits of uniqueness that prevented me from doing this
-    /// in a loop. So, each pair of faces is being joined manually.
-    fn wrap(&mut self) {
-        let inner = &self.0;
-
-        // Match up the (A) sides. All the faces are joined this way, where I
-        // iterate along the positions from the matching faces in the order that
-        // they match up and modify their links as needed to have each edge direct
-        // the Walker to the corresponding Tile on the other face. This was made
-        // even more tedious by the fact that this approach is completely different
-        // that the one I'd need to take to do this for the example input, since
-        // the example map is shaped differently from my input.
-        let side1 = (50..100).map(|col| Position(0, col));
-        let side2 = (150..200).map(|row| Position(row, 0));
-        for (p1, p2) in std::iter::zip(side1, side2) {
-            if self[p1].is_path() {
-                let mut links = self[p1].links();
-                links.up = if self[p2].is_path() {
-                    Some((Heading::Right, p2))
-                } else {
-                    None
-                };
-                self[p1] = Tile::Path(links);
-            }
+/// A direction in 3D space, for when the map gets folded up into a cube. Every
+/// direction we need points straight along one of the axes.
+type Vector = [isize; 3];
 
-            if self[p2].is_path() {
-                let mut links = self[p2].links();
-                links.left = if self[p1].is_path() {
-                    Some((Heading::Down, p1))
-                } else {
-                    None
-                };
-                self[p2] = Tile::Path(links);
-            }
-        }
+fn neg(v: Vector) -> Vector {
+    v.map(|x| -x)
+}
 
-        // Match up the (B) sides.
-        let side1 = (100..150).map(|col| Position(0, col));
-        let side2 = (0..50).map(|col| Position(199, col));
-        for (p1, p2) in std::iter::zip(side1, side2) {
-            if self[p1].is_path() {
-                let mut links = self[p1].links();
-                links.up = if self[p2].is_path() {
-                    Some((Heading::Up, p2))
-                } else {
-                    None
-                };
-                self[p1] = Tile::Path(links);
-            }
+fn dot(a: Vector, b: Vector) -> isize {
+    a.iter().zip(b).map(|(a, b)| a * b).sum()
+}
 
-            if self[p2].is_path() {
-                let mut links = self[p2].links();
-                links.down = if self[p1].is_path() {
-                    Some((Heading::Down, p1))
-                } else {
-                    None
-                };
-                self[p2] = Tile::Path(links);
-            }
-        }
+/// Add up some multiples of some vectors
+fn combine(terms: &[(Vector, isize)]) -> Vector {
+    let mut total = [0; 3];
+    for (v, n) in terms {
+        (0..3).for_each(|axis| total[axis] += v[axis] * n);
+    }
+    total
+}
 
-        // Match up the (C) sides.
-        let side1 = (0..50).map(|row| Position(row, 50));
-        let side2 = (100..150).map(|row| Position(row, 0)).rev();
-        for (p1, p2) in std::iter::zip(side1, side2) {
-            if self[p1].is_path() {
-                let mut links = self[p1].links();
-                links.left = if self[p2].is_path() {
-                    Some((Heading::Right, p2))
-                } else {
-                    None
-                };
-                self[p1] = Tile::Path(links);
-            }
+/// One face of the cube. It knows where it is on the map, counting in faces
+/// instead of tiles, and which way it ends up facing once the map is folded: the
+/// `normal` points out of the cube, `right` is the way the columns count up, and
+/// `down` is the way the rows count up.
+#[derive(Debug, Clone, Copy)]
+struct Face {
+    row: usize,
+    col: usize,
+    normal: Vector,
+    right: Vector,
+    down: Vector,
+}
 
-            if self[p2].is_path() {
-                let mut links = self[p2].links();
-                links.left = if self[p1].is_path() {
-                    Some((Heading::Right, p1))
-                } else {
-                    None
-                };
-                self[p2] = Tile::Path(links);
-            }
+impl Face {
+    /// The way you're going in 3D when you're on this face, headed `heading`
+    fn direction(&self, heading: Heading) -> Vector {
+        match heading {
+            Heading::Up => neg(self.down),
+            Heading::Right => self.right,
+            Heading::Down => self.down,
+            Heading::Left => neg(self.right),
         }
+    }
 
-        // Match up the (D) sides.
-        let side1 = (0..50).map(|row| Position(row, 149));
-        let side2 = (100..150).map(|row| Position(row, 99)).rev();
-        for (p1, p2) in std::iter::zip(side1, side2) {
-            if self[p1].is_path() {
-                let mut links = self[p1].links();
-                links.right = if self[p2].is_path() {
-                    Some((Heading::Left, p2))
-                } else {
-                    None
-                };
-                self[p1] = Tile::Path(links);
-            }
+    /// The heading on this face that goes in the 3D `direction`, if it's one
+    /// that runs along the face
+    fn heading(&self, direction: Vector) -> Option<Heading> {
+        [Heading::Up, Heading::Right, Heading::Down, Heading::Left]
+            .into_iter()
+            .find(|heading| self.direction(*heading) == direction)
+    }
 
-            if self[p2].is_path() {
-                let mut links = self[p2].links();
-                links.right = if self[p1].is_path() {
-                    Some((Heading::Left, p1))
-                } else {
-                    None
-                };
-                self[p2] = Tile::Path(links);
-            }
+    /// The face you get to by walking off this one, headed `heading`, when it's
+    /// the next face over on the map, too. Folding the map along the edge you
+    /// walked over tips the new face over by a quarter turn, so it ends up facing
+    /// the way you were headed.
+    fn neighbor(&self, heading: Heading) -> Face {
+        let (row, col) = (self.row, self.col);
+        let (normal, right, down) = (self.normal, self.right, self.down);
+        match heading {
+            Heading::Up => Face {
+                row: row - 1,
+                normal: neg(down),
+                down: normal,
+                ..*self
+            },
+            Heading::Right => Face {
+                col: col + 1,
+                normal: right,
+                right: neg(normal),
+                ..*self
+            },
+            Heading::Down => Face {
+                row: row + 1,
+                normal: down,
+                down: neg(normal),
+                ..*self
+            },
+            Heading::Left => Face {
+                col: col - 1,
+                normal: neg(right),
+                right: normal,
+                ..*self
+            },
         }
+    }
+}
 
-        // Match up the (E) sides.
-        let side1 = (50..100).map(|row| Position(row, 50));
-        let side2 = (0..50).map(|col| Position(100, col));
-        for (p1, p2) in std::iter::zip(side1, side2) {
-            if self[p1].is_path() {
-                let mut links = self[p1].links();
-                links.left = if self[p2].is_path() {
-                    Some((Heading::Down, p2))
-                } else {
-                    None
-                };
-                self[p1] = Tile::Path(links);
-            }
+impl Links {
+    /// Change the link in one direction
+    fn set(&mut self, heading: Heading, link: Option<(Heading, Position)>) {
+        match heading {
+            Heading::Up => self.up = link,
+            Heading::Right => self.right = link,
+            Heading::Down => self.down = link,
+            Heading::Left => self.left = link,
+        }
+    }
+}
 
-            if self[p2].is_path() {
-                let mut links = self[p2].links();
-                links.up = if self[p1].is_path() {
-                    Some((Heading::Right, p1))
-                } else {
-                    None
-                };
-                self[p2] = Tile::Path(links);
-            }
+impl MonkeyMap {
+    /// The first version of this joined up the edges of the cube by hand for the
+    /// layout of my map, seven pairs of edges and all, which was tedious to write
+    /// and didn't work for anybody else's map (or the example). Now, we fold the
+    /// map up into a cube for real and let the cube tell us which edges meet.
+    ///
+    /// The faces are all the same size, so with six of them, the size of a face is
+    /// the square root of a sixth of the tiles. Starting from the first face, we
+    /// fold each face next to one we've already placed over its shared edge, which
+    /// tells us which way every face points once they're all folded up. Then each
+    /// tile along an edge of a face gets linked to the tile on the face its edge
+    /// folds onto. Maps that don't fold up into a cube get an error instead.
+    fn wrap(&mut self) -> Result<()> {
+        let unfoldable = || Error::no_solution("The map doesn't fold up into a cube!");
+        let tiles = self
+            .0
+            .iter()
+            .filter(|(_, tile)| **tile != Tile::Void)
+            .count();
+        let size = (1..=tiles)
+            .find(|size| 6 * size * size >= tiles)
+            .filter(|size| 6 * size * size == tiles)
+            .ok_or_else(unfoldable)?;
+        if self.0.rows() % size != 0 || self.0.cols() % size != 0 {
+            return Err(unfoldable());
         }
 
-        // Match up the (F) sides.
-        let side1 = (100..150).map(|col| Position(49, col));
-        let side2 = (50..100).map(|row| Position(row, 99));
-        for (p1, p2) in std::iter::zip(side1, side2) {
-            if self[p1].is_path() {
-                let mut links = self[p1].links();
-                links.down = if self[p2].is_path() {
-                    Some((Heading::Left, p2))
-                } else {
-                    None
-                };
-                self[p1] = Tile::Path(links);
+        // Find the faces on the map. A face has to be all tiles, no void.
+        let (face_rows, face_cols) = (self.0.rows() / size, self.0.cols() / size);
+        let mut on_map = Vec::new();
+        for (row, col) in (0..face_rows).cartesian_product(0..face_cols) {
+            let covered = (0..size)
+                .cartesian_product(0..size)
+                .filter(|(r, c)| self.0[(row * size + r, col * size + c)] != Tile::Void)
+                .count();
+            match covered {
+                0 => continue,
+                _ if covered == size * size => on_map.push((row, col)),
+                _ => return Err(unfoldable()),
             }
+        }
 
-            if self[p2].is_path() {
-                let mut links = self[p2].links();
-                links.right = if self[p1].is_path() {
-                    Some((Heading::Up, p1))
-                } else {
-                    None
+        // Fold them up, one face at a time. Which way the first face points doesn't
+        // matter, the rest all follow from it.
+        let (row, col) = on_map.first().copied().ok_or_else(unfoldable)?;
+        let first = Face {
+            row,
+            col,
+            normal: [0, 0, -1],
+            right: [1, 0, 0],
+            down: [0, 1, 0],
+        };
+        let mut faces = vec![first];
+        let mut idx = 0;
+        while let Some(face) = faces.get(idx).copied() {
+            idx += 1;
+            for heading in [Heading::Up, Heading::Right, Heading::Down, Heading::Left] {
+                let next = match heading {
+                    Heading::Up if face.row == 0 => continue,
+                    Heading::Left if face.col == 0 => continue,
+                    _ => face.neighbor(heading),
                 };
-                self[p2] = Tile::Path(links);
+                let placed = faces.iter().any(|f| (f.row, f.col) == (next.row, next.col));
+                if !placed && on_map.contains(&(next.row, next.col)) {
+                    faces.push(next);
+                }
             }
         }
 
-        // Match up the (G) sides.
-        let side1 = (50..100).map(|col| Position(149, col));
-        let side2 = (150..200).map(|row| Position(row, 49));
-        for (p1, p2) in std::iter::zip(side1, side2) {
-            if self[p1].is_path() {
-                let mut links = self[p1].links();
-                links.down = if self[p2].is_path() {
-                    Some((Heading::Left, p2))
-                } else {
-                    None
-                };
-                self[p1] = Tile::Path(links);
-            }
+        // Every face needs to end up on a different side of the cube
+        let sides: Vec<_> = faces.iter().map(|face| face.normal).unique().collect();
+        if faces.len() != 6 || sides.len() != 6 {
+            return Err(unfoldable());
+        }
 
-            if self[p2].is_path() {
-                let mut links = self[p2].links();
-                links.right = if self[p1].is_path() {
-                    Some((Heading::Up, p1))
-                } else {
-                    None
+        // Link up each tile along each edge of each face. Working in 3D, with the
+        // cube centered on the origin and each tile two units across, the tile in
+        // `row`, `col` of a face sits at
+        //
+        //   normal * size + right * (2 * col + 1 - size) + down * (2 * row + 1 - size)
+        //
+        // Walking off the edge goes one unit in the direction you're headed to get
+        // to the edge, then one unit into the cube to get to the middle of the tile
+        // on the other side. You're headed into the cube now, too.
+        let offset = |idx: usize| 2 * idx as isize + 1 - size as isize;
+        let index = |offset: isize| ((offset + size as isize - 1) / 2) as usize;
+        for face in faces.iter() {
+            for heading in [Heading::Up, Heading::Right, Heading::Down, Heading::Left] {
+                let direction = face.direction(heading);
+                let onto = faces.iter().find(|f| f.normal == direction);
+                let Some((onto, new_heading)) =
+                    onto.and_then(|onto| Some((onto, onto.heading(neg(face.normal))?)))
+                else {
+                    return Err(unfoldable());
                 };
-                self[p2] = Tile::Path(links);
+
+                for along in 0..size {
+                    let (row, col) = match heading {
+                        Heading::Up => (0, along),
+                        Heading::Right => (along, size - 1),
+                        Heading::Down => (size - 1, along),
+                        Heading::Left => (along, 0),
+                    };
+                    let from = Position(face.row * size + row, face.col * size + col);
+                    let Tile::Path(mut links) = self[from] else { continue; };
+
+                    let point = combine(&[
+                        (face.normal, size as isize - 1),
+                        (face.right, offset(col)),
+                        (face.down, offset(row)),
+                        (direction, 1),
+                    ]);
+                    let to = Position(
+                        onto.row * size + index(dot(point, onto.down)),
+                        onto.col * size + index(dot(point, onto.right)),
+                    );
+                    let link = self[to].is_path().then_some((new_heading, to));
+                    links.set(heading, link);
+                    self[from] = Tile::Path(links);
+                }
             }
         }
-
-        // And that's that! Seven slightly different edge-to-edge joins! I can't
-        // tell you how many small "typo" bugs I made in these seven blocks. The
-        // good news is that once the edges are forwarding correctly, walking the
-        // "cube" is exactly the same as walking the map in part one.
+        Ok(())
     }
 }
@@ -0,0 +1,112 @@
+use crate::day22::input::{Direction, Heading, MonkeyMap, Tile};
+use crate::day22::part1::Walker;
+use crate::day22::{Input, Result};
+use crate::grid::Grid;
+use crate::visualize::{palette, Frame, Rgb, Sink};
+use crate::Error;
+
+/// Follow the directions around the flat map from part one, drawing the map after
+/// each direction with the trail the walker has left behind
+pub fn visualize(input: &Input, sink: &mut dyn Sink) -> Result<()> {
+    let (monkey_map, directions) = input;
+    let Some(start_pos) = monkey_map.first_path_position() else {
+        return Err(Error::no_solution("Cannot find start position!"));
+    };
+    let mut board = Board {
+        monkey_map,
+        trail: Grid::new(monkey_map.0.rows(), monkey_map.0.cols(), None),
+        walker: Walker::new(start_pos),
+    };
+    board.mark();
+    sink.frame(&board)?;
+
+    // Take one step at a time, so the trail doesn't skip any tiles
+    for direction in directions {
+        match *direction {
+            Direction::Forward(steps) => {
+                for _ in 0..steps {
+                    board.walker.follow(monkey_map, Direction::Forward(1));
+                    board.mark();
+                }
+            }
+            turn => {
+                board.walker.follow(monkey_map, turn);
+                board.mark();
+            }
+        }
+        sink.frame(&board)?;
+    }
+    Ok(())
+}
+
+/// The map, drawn like the puzzle description draws it, with the last heading
+/// the walker had on every tile it's been to
+struct Board<'a> {
+    monkey_map: &'a MonkeyMap,
+    trail: Grid<Option<Heading>>,
+    walker: Walker,
+}
+
+impl Board<'_> {
+    /// Leave a mark on the walker's tile, pointing the way it's facing
+    fn mark(&mut self) {
+        let Walker(heading, position) = self.walker;
+        self.trail[(position.0, position.1)] = Some(heading);
+    }
+}
+
+impl Frame for Board<'_> {
+    fn size(&self) -> (usize, usize) {
+        (self.monkey_map.0.rows(), self.monkey_map.0.cols())
+    }
+
+    fn glyph(&self, row: usize, col: usize) -> char {
+        match (self.monkey_map.0[(row, col)], self.trail[(row, col)]) {
+            (_, Some(Heading::Up)) => '^',
+            (_, Some(Heading::Right)) => '>',
+            (_, Some(Heading::Down)) => 'v',
+            (_, Some(Heading::Left)) => '<',
+            (Tile::Path(_), None) => '.',
+            (Tile::Wall, None) => '#',
+            (Tile::Void, None) => ' ',
+        }
+    }
+
+    /// The walker stands out from its trail
+    fn color(&self, row: usize, col: usize) -> Rgb {
+        let Walker(_, position) = self.walker;
+        match (row, col) == (position.0, position.1) {
+            true => palette('@'),
+            false => palette(self.glyph(row, col)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::day22::input::parse;
+    use crate::visualize::Frames;
+
+    #[test]
+    fn check_example() {
+        let input = parse(include_str!("../../input/22/test.txt")).unwrap();
+        let mut frames = Frames::default();
+        visualize(&input, &mut frames).unwrap();
+        assert_eq!(frames.0.len(), input.1.len() + 1);
+
+        // The trail from the puzzle description
+        let last: Vec<_> = frames
+            .0
+            .last()
+            .unwrap()
+            .lines()
+            .map(str::trim_end)
+            .collect();
+        assert_eq!(last[0], "        >>v#");
+        assert_eq!(last[4], "...#...v..v#");
+        assert_eq!(last[5], ">>>v...>#.>>");
+        assert_eq!(last[7], "...>>>>v..#.");
+        assert_eq!(last[11], "        ......#.");
+    }
+}
@@ -1,4 +1,5 @@
-use anyhow::{bail, Error};
+use crate::grid::Point;
+use crate::Error;
 use itertools::Itertools;
 use std::fmt::{Display, Formatter, Result as FmtResult};
 use std::ops::{BitAnd, BitAndAssign, BitOr, BitOrAssign, BitXor, BitXorAssign, Not, Shl};
@@ -132,10 +133,20 @@ impl<T: Chunk, const CHUNKS: usize> GridRow<T, CHUNKS> {
     /// left to right. These indices can be used to iterate through the
     /// bits in this GridRow.
     fn indices(&self) -> impl Iterator<Item = GridRowIdx<T>> {
-        (0..CHUNKS)
-            .cartesian_product(0..(<u64>::BITS as usize))
-            .map(|(c, b)| c + b)
-            .map(GridRowIdx::from)
+        (0..(CHUNKS * T::width())).map(GridRowIdx::from)
+    }
+
+    /// Check whether any bit is set in the leftmost or rightmost column of the row
+    fn touches_edge(&self) -> bool {
+        let left = self
+            .0
+            .first()
+            .is_some_and(|c| *c & T::low_bit() != T::zero());
+        let right = self
+            .0
+            .last()
+            .is_some_and(|c| *c & T::high_bit() != T::zero());
+        left || right
     }
 
     /// Shift the bits in this row one space to the right. Accounts for the
@@ -308,6 +319,11 @@ impl<T: Chunk> From<(usize, usize)> for GridIdx<T> {
 }
 
 impl<T: Chunk, const CHUNKS: usize, const ROWS: usize> Grid<T, CHUNKS, ROWS> {
+    /// The number of columns in the Grid, which is every bit in every chunk of a row
+    fn cols() -> usize {
+        CHUNKS * T::width()
+    }
+
     /// Set a bit in the Grid
     fn set(&mut self, GridIdx { row, bit }: GridIdx<T>) {
         self.rows[row].set(bit);
@@ -318,14 +334,38 @@ impl<T: Chunk, const CHUNKS: usize, const ROWS: usize> Grid<T, CHUNKS, ROWS> {
         self.rows[row].is_set(bit)
     }
 
+    /// Check to see if there's an elf at a (<row>, <col>) in the Grid
+    pub fn has_elf(&self, row: usize, col: usize) -> bool {
+        self.is_set(GridIdx::from((row, col)))
+    }
+
     /// Iterate through the indices in this Grid. Allows for iterating over
     /// the spaces in the Grid indirectly.
     fn indices(&self) -> impl Iterator<Item = GridIdx<T>> {
         (0..ROWS)
-            .cartesian_product(0..(CHUNKS * 64))
+            .cartesian_product(0..Self::cols())
             .map(GridIdx::from)
     }
 
+    /// Make sure every elf has room to move in any direction. The offsets drop any
+    /// bits shifted past the edge of the Grid, so an elf on the edge could step off
+    /// it and quietly disappear, which would give the wrong answer. Better to fail.
+    pub fn check_room(&self) -> Result<(), Error> {
+        let edge_rows = [self.rows.first(), self.rows.last()];
+        let crowded = edge_rows
+            .into_iter()
+            .flatten()
+            .any(|row| *row != GridRow::default())
+            || self.rows.iter().any(GridRow::touches_edge);
+        match crowded {
+            true => Err(Error::no_solution(format!(
+                "The elves spread past the edge of the {ROWS}x{} grid!",
+                Self::cols()
+            ))),
+            false => Ok(()),
+        }
+    }
+
     /// Shift all the bits in the Grid one space to the right.
     fn offset_right(&mut self) -> Self {
         self.rows.iter_mut().for_each(|r| r.offset_right());
@@ -360,13 +400,13 @@ impl<T: Chunk, const CHUNKS: usize, const ROWS: usize> Grid<T, CHUNKS, ROWS> {
 
     /// Identify the bounds of the populated rectangle in the Grid, returning
     /// ((<min_row>, <min_col>), (<max_row>, <max_col>))
-    fn bounds(&self) -> ((usize, usize), (usize, usize)) {
+    pub fn bounds(&self) -> ((usize, usize), (usize, usize)) {
         let mut min_row = usize::MAX;
         let mut min_col = usize::MAX;
         let mut max_row = usize::MIN;
         let mut max_col = usize::MIN;
 
-        for (row, col) in (0..ROWS).cartesian_product(0..(CHUNKS * 64)) {
+        for (row, col) in (0..ROWS).cartesian_product(0..Self::cols()) {
             let idx = GridIdx::from((row, col));
             if self.is_set(idx) {
                 min_row = min_row.min(row);
@@ -385,7 +425,7 @@ impl<T: Chunk, const CHUNKS: usize, const ROWS: usize> Grid<T, CHUNKS, ROWS> {
     pub fn count_empty_spaces(&self) -> u32 {
         let mut empty_spaces = 0;
         let ((min_row, min_col), (max_row, max_col)) = self.bounds();
-        for (row, col) in (0..ROWS).cartesian_product(0..(CHUNKS * 64)) {
+        for (row, col) in (0..ROWS).cartesian_product(0..Self::cols()) {
             if row < min_row || row > max_row || col < min_col || col > max_col {
                 continue;
             }
@@ -398,28 +438,31 @@ impl<T: Chunk, const CHUNKS: usize, const ROWS: usize> Grid<T, CHUNKS, ROWS> {
     }
 }
 
-/// This is the input parsing for today's puzzle. Iterates over the lines and
-/// characters of the input, setting bits in an empty Grid. Shifts the bits
-/// such that the set bits are centered around the center of the Grid.
+/// This is the input parsing for today's puzzle. Reads the input as a map of
+/// elves with the shared grid parser, then sets the bits for the elves in an empty
+/// Grid. Shifts the bits such that the set bits are centered around the center of
+/// the Grid.
 impl<T: Chunk, const CHUNKS: usize, const ROWS: usize> FromStr for Grid<T, CHUNKS, ROWS> {
     type Err = Error;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let rows = s.lines().count();
-        let cols = s.lines().next().map(|l| l.len()).unwrap_or_default();
+        let elves = crate::grid::Grid::parse(s, |ch| match ch {
+            '#' => Ok(true),
+            '.' => Ok(false),
+            _ => Err("Input string should only contain '#', '.', or newline!"),
+        })?;
+        let (rows, cols) = (elves.rows(), elves.cols());
+        if rows > ROWS || cols > Self::cols() {
+            let reason = format!("Input is too big, can be at most {ROWS}x{}!", Self::cols());
+            return Err(Error::parse(s, s, reason));
+        }
         let mut grid = Grid::default();
         let row_offset = (ROWS / 2) - (rows / 2);
-        let col_offset = ((CHUNKS * 64) / 2) - (cols / 2);
-
-        for (row, line) in s.lines().enumerate() {
-            for (col, ch) in line.chars().enumerate() {
-                let idx = GridIdx::from((row + row_offset, col + col_offset));
-                match ch {
-                    '#' => grid.set(idx),
-                    '.' => continue,
-                    _ => bail!("Input string should only contain '#', '.', or newline!"),
-                }
-            }
+        let col_offset = (Self::cols() / 2) - (cols / 2);
+
+        for (Point(col, row), _) in elves.iter().filter(|(_, elf)| **elf) {
+            let (row, col) = (row as usize, col as usize);
+            grid.set(GridIdx::from((row + row_offset, col + col_offset)));
         }
 
         Ok(grid)
@@ -886,4 +929,29 @@ mod test {
         println!("Rounds Taken: {}", rounds);
         println!("Calculated in: {:?}", start.elapsed());
     }
+
+    #[test]
+    fn check_grid_size() {
+        // Every column of every chunk is usable
+        let wide = format!("{}\n", "#.".repeat(100));
+        let grid: Grid<u128, 2, 192> = Grid::from_str(&wide).unwrap();
+        assert_eq!(grid.bounds(), ((96, 28), (96, 226)));
+        assert_eq!(grid.count_empty_spaces(), 99);
+        let Err(Error::Parse { reason, .. }) = Grid::<u128, 2, 192>::from_str(&"#".repeat(257))
+        else {
+            panic!("Should be too big!");
+        };
+        assert!(reason.contains("192x256"));
+
+        // The top elves step north into the first row and the bottom elves step
+        // south into the last row, so there's no room for another round
+        let mut state: Grid<u64, 1, 4> = Grid::from_str("##\n##").unwrap();
+        assert!(state.check_room().is_ok());
+        state = GridBuilder::init(state, Rules::default())
+            .identify_movers()
+            .make_proposals()
+            .resolve_conflicts()
+            .finalize();
+        assert!(state.check_room().is_err());
+    }
 }
@@ -1,10 +1,16 @@
 use super::grid::Grid;
 use crate::day23::Input;
+use crate::Result;
 use std::str::FromStr;
 
-const INPUT: &str = include_str!("../../input/23/input.txt");
+pub(crate) const INPUT: &str = include_str!("../../input/23/input.txt");
+
+/// Parse the text of an input file
+pub fn parse(input: &str) -> Result<Input> {
+    Grid::from_str(input)
+}
 
 /// Parse the input!
 pub fn read() -> Input {
-    Grid::from_str(INPUT).unwrap()
+    parse(INPUT).unwrap()
 }
@@ -2,40 +2,60 @@ pub mod grid;
 pub mod input;
 pub mod part1;
 pub mod part2;
+pub mod visualize;
 
-use crate::{Output, Part};
+use crate::{Output, Part, Result, Solution, Source};
 use grid::Grid;
 
-// These values are tuned to produce the right answer in the least
-// time. It's a bit of a hack, to be honest, but it works!
-pub type Input = Grid<u128, 2, 176>;
+// These values are tuned to leave the elves enough room to spread out in the
+// least time. The elves in my input drift a long way south, so it takes 192 rows.
+// If they ever run out of room, solving fails instead of giving a wrong answer.
+pub type Input = Grid<u128, 2, 192>;
 
-pub fn run(part: Part) -> Output {
-    let input = input::read();
-    match part {
-        Part::One => part1::solve(&input),
-        Part::Two => part2::solve(&input),
+pub struct Day23;
+
+impl Solution for Day23 {
+    const DAY: u8 = 23;
+    const INPUT: &'static str = input::INPUT;
+    type Input<'a> = Input;
+
+    fn parse(text: &str) -> Result<Self::Input<'_>> {
+        input::parse(text)
     }
-}
 
-pub fn run_both() -> (Output, Output) {
-    let input = input::read();
-    (part1::solve(&input), part2::solve(&input))
+    fn part1(input: &Self::Input<'_>) -> Result<Output> {
+        part1::solve(input)
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Result<Output> {
+        part2::solve(input)
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::examples;
 
     #[test]
     fn check_answer_one() {
-        let result = run(Part::One);
+        let result = Day23::run(Part::One, Source::Bundled).unwrap();
         assert_eq!(result, 4052);
     }
 
     #[test]
     fn check_answer_two() {
-        let result = run(Part::Two);
+        let result = Day23::run(Part::Two, Source::Bundled).unwrap();
         assert_eq!(result, 978);
     }
+
+    #[test]
+    fn check_example_one() {
+        examples::check::<Day23>(Part::One);
+    }
+
+    #[test]
+    fn check_example_two() {
+        examples::check::<Day23>(Part::Two);
+    }
 }
@@ -1,9 +1,9 @@
 use super::grid::{Grid, GridBuilder, Rules};
-use crate::day23::{Input, Output};
+use crate::day23::{Input, Output, Result};
 use std::collections::HashMap;
 
 /// Solve Day 23, Part 1
-pub fn solve(input: &Input) -> Output {
+pub fn solve(input: &Input) -> Result<Output> {
     // Start with a copy of the Grid and the default order of proposal directions
     let mut state = *input;
     let mut propose_order = Rules::default();
@@ -12,6 +12,7 @@ pub fn solve(input: &Input) -> Output {
     // pattern here, but it does make for a nice syntax for producing new Grid
     // states. It's nice to have listed each part of the process like this.
     for _ in 0..10 {
+        state.check_room()?;
         state = GridBuilder::init(state, propose_order)
             .identify_movers()
             .make_proposals()
@@ -21,5 +22,5 @@ pub fn solve(input: &Input) -> Output {
     }
 
     // Count and return the number of empty spaces
-    state.count_empty_spaces().into()
+    Ok(state.count_empty_spaces().into())
 }
@@ -1,8 +1,8 @@
 use super::grid::{Grid, GridBuilder, Rules};
-use crate::day23::{Input, Output};
+use crate::day23::{Input, Output, Result};
 
 /// Solve Day 23, Part 2
-pub fn solve(input: &Input) -> Output {
+pub fn solve(input: &Input) -> Result<Output> {
     // Start with a copy of the Grid, the initial proposal order, and a blank Grid
     let mut state = *input;
     let mut last_state = Grid::default();
@@ -14,6 +14,7 @@ pub fn solve(input: &Input) -> Output {
     // we can stop.
     while state != last_state {
         last_state = state;
+        state.check_room()?;
         state = GridBuilder::init(state, propose_order)
             .identify_movers()
             .make_proposals()
@@ -25,5 +26,5 @@ pub fn solve(input: &Input) -> Output {
 
     // Return the number of rounds it took to find a round where no changes
     // occurred.
-    rounds.into()
+    Ok(rounds.into())
 }
@@ -0,0 +1,86 @@
+use crate::day23::grid::{Chunk, Grid, GridBuilder, Rules};
+use crate::day23::{Input, Result};
+use crate::visualize::{Crop, Frame, Sink};
+
+/// Let the elves spread out until they stop moving, like in part two, drawing the
+/// Grid after each round. The Grid is a lot bigger than the space the elves use, so
+/// the first time through is just to find out how much of it needs to be drawn.
+pub fn visualize(input: &Input, sink: &mut dyn Sink) -> Result<()> {
+    let ((mut top, mut left), (mut bottom, mut right)) = input.bounds();
+    for state in rounds(input) {
+        let ((min_row, min_col), (max_row, max_col)) = state?.bounds();
+        (top, left) = (top.min(min_row), left.min(min_col));
+        (bottom, right) = (bottom.max(max_row), right.max(max_col));
+    }
+
+    let (rows, cols) = (top..(bottom + 1), left..(right + 1));
+    for state in std::iter::once(Ok(*input)).chain(rounds(input)) {
+        let (rows, cols) = (rows.clone(), cols.clone());
+        let frame = &state?;
+        sink.frame(&Crop { frame, rows, cols })?;
+    }
+    Ok(())
+}
+
+/// Every state of the Grid after the first, up to the first round where no elves
+/// move (which looks just like the round before it), stopping after the first error
+fn rounds(input: &Input) -> impl Iterator<Item = Result<Input>> {
+    let mut state = *input;
+    let mut propose_order = Rules::default();
+    let mut done = false;
+    std::iter::from_fn(move || {
+        if done {
+            return None;
+        }
+        if let Err(error) = state.check_room() {
+            done = true;
+            return Some(Err(error));
+        }
+        let last_state = state;
+        state = GridBuilder::init(state, propose_order)
+            .identify_movers()
+            .make_proposals()
+            .resolve_conflicts()
+            .finalize();
+        propose_order.rotate();
+        done = state == last_state;
+        Some(Ok(state))
+    })
+}
+
+/// Each elf is a `#`, like in the puzzle description
+impl<T: Chunk, const CHUNKS: usize, const ROWS: usize> Frame for Grid<T, CHUNKS, ROWS> {
+    fn size(&self) -> (usize, usize) {
+        (ROWS, CHUNKS * T::width())
+    }
+
+    fn glyph(&self, row: usize, col: usize) -> char {
+        match self.has_elf(row, col) {
+            true => '#',
+            false => '.',
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::day23::input::parse;
+    use crate::visualize::Frames;
+
+    #[test]
+    fn check_example() {
+        let input = parse(include_str!("../../input/23/test.txt")).unwrap();
+        let mut frames = Frames::default();
+        visualize(&input, &mut frames).unwrap();
+
+        // The elves stop moving in round 20, which looks just like round 19, and
+        // every frame has all 22 elves in it
+        assert_eq!(frames.0.len(), 21);
+        assert_eq!(frames.0[19], frames.0[20]);
+        assert!(frames
+            .0
+            .iter()
+            .all(|frame| frame.matches('#').count() == 22));
+    }
+}
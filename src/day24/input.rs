@@ -1,5 +1,7 @@
 use crate::day24::Input;
-use anyhow::{bail, Error, Result};
+use crate::grid::{Grid, Offset, Point};
+use crate::{Error, Result};
+use anyhow::bail;
 use itertools::Itertools;
 use std::collections::{HashMap, HashSet};
 
@@ -15,7 +17,7 @@ pub enum Direction {
 }
 
 impl TryFrom<u8> for Direction {
-    type Error = Error;
+    type Error = anyhow::Error;
 
     fn try_from(value: u8) -> Result<Self, Self::Error> {
         match value {
@@ -40,7 +42,7 @@ impl Direction {
     }
 
     /// Get an array of all four Directions
-    const fn all() -> [Direction; 4] {
+    pub const fn all() -> [Direction; 4] {
         [
             Direction::Down,
             Direction::Left,
@@ -63,10 +65,15 @@ impl Blizzard {
         Blizzard(direction.value())
     }
 
-    fn direction(&self) -> Result<Direction> {
+    fn direction(&self) -> anyhow::Result<Direction> {
         Direction::try_from(self.0)
     }
 
+    /// Count the Blizzards blowing through this space
+    pub fn count(&self) -> u32 {
+        self.0.count_ones()
+    }
+
     /// Add a direction to the current Blizzard
     fn add(&mut self, direction: Direction) {
         self.0 |= direction.value();
@@ -74,7 +81,7 @@ impl Blizzard {
 
     /// Check if the Blizzard space has a Blizzard blowing in a
     /// particular direction.
-    fn has(&self, direction: Direction) -> bool {
+    pub fn has(&self, direction: Direction) -> bool {
         self.0 & direction.value() > 0
     }
 }
@@ -95,38 +102,27 @@ pub enum Space {
 pub struct Valley {
     pub rows: usize,
     pub cols: usize,
-    pub spaces: Vec<Vec<Space>>,
+    pub spaces: Grid<Space>,
 }
 
-/// Produce a new Valley from a 2D grid of Spaces.
-impl From<Vec<Vec<Space>>> for Valley {
-    fn from(spaces: Vec<Vec<Space>>) -> Self {
-        let rows = spaces.len();
-        let cols = spaces.first().map(|line| line.len()).unwrap_or_default();
+/// Produce a new Valley from a grid of Spaces.
+impl From<Grid<Space>> for Valley {
+    fn from(spaces: Grid<Space>) -> Self {
+        let (rows, cols) = (spaces.rows(), spaces.cols());
         Valley { rows, cols, spaces }
     }
 }
 
-impl From<&str> for Valley {
-    fn from(input: &str) -> Self {
-        let mut spaces = Vec::new();
-        for line in input.lines() {
-            let row = line
-                .chars()
-                .map(|glyph| match glyph {
-                    '>' => Space::Blizzard(Blizzard::from(Direction::Right)),
-                    '<' => Space::Blizzard(Blizzard::from(Direction::Left)),
-                    '^' => Space::Blizzard(Blizzard::from(Direction::Up)),
-                    'v' => Space::Blizzard(Blizzard::from(Direction::Down)),
-                    '.' => Space::Empty,
-                    '#' => Space::Wall,
-                    _ => unreachable!(),
-                })
-                .collect::<Vec<_>>();
-            spaces.push(row);
+impl Direction {
+    /// The step on the grid a Blizzard takes each minute when it's blowing
+    /// in this Direction
+    fn offset(&self) -> Offset {
+        match self {
+            Direction::Down => Offset::DOWN,
+            Direction::Left => Offset::LEFT,
+            Direction::Up => Offset::UP,
+            Direction::Right => Offset::RIGHT,
         }
-
-        Valley::from(spaces)
     }
 }
 
@@ -136,25 +132,23 @@ impl Valley {
     /// wrapping around the Valley when they encounter a Wall.
     fn advance(&self) -> Self {
         // Creates a new mutable clone of this Valley with only Empty Spaces
-        let Valley { rows, cols, spaces } = self;
-        let mut new_spaces = vec![vec![Space::Empty; *cols]; *rows];
-        let mut new_state = Valley::from(new_spaces);
+        let mut new_state = Valley::from(Grid::new(self.rows, self.cols, Space::Empty));
 
         // For each Space in the current Valley, update the appropriate space in the
         // new state. Move Blizzards and set Walls. Skip the Empties, since all the
         // Spaces in the new state are already Empty.
-        for (row, col) in (0..*rows).cartesian_product(0..*cols) {
-            match spaces[row][col] {
+        for (point, space) in self.spaces.iter() {
+            match space {
                 Space::Blizzard(blizzard) => {
                     for direction in Direction::all() {
                         if !blizzard.has(direction) {
                             continue;
                         }
-                        let (new_row, new_col) = self.next_position(row, col, direction);
-                        new_state.add_blizzard(new_row, new_col, direction);
+                        let new_point = self.next_position(point, direction);
+                        new_state.add_blizzard(new_point, direction);
                     }
                 }
-                Space::Wall => new_state.spaces[row][col] = Space::Wall,
+                Space::Wall => new_state.spaces[point] = Space::Wall,
                 Space::Empty => continue,
             }
         }
@@ -163,41 +157,31 @@ impl Valley {
         new_state
     }
 
-    /// Given a starting row and column and a Direction to move, return the row
-    /// and column where you'd end up if you moved in that Direction.
-    fn next_position(&self, row: usize, col: usize, direction: Direction) -> (usize, usize) {
-        match direction {
-            // Wrapping for increasing rows/columns
-            Direction::Down => ((row % (self.rows - 2)) + 1, col),
-            Direction::Right => (row, (col % (self.cols - 2)) + 1),
-
-            // Wrapping for decreasing rows/columns
-            Direction::Left => match col - 1 {
-                0 => (row, self.cols - 2),
-                _ => (row, col - 1),
-            },
-            Direction::Up => match row - 1 {
-                0 => (self.rows - 2, col),
-                _ => (row - 1, col),
-            },
-        }
+    /// Given a starting point and a Direction to move, return the point where
+    /// you'd end up if you moved in that Direction. The Blizzards wrap around the
+    /// inside of the Valley (everything but the Walls), so shift the point to make
+    /// the inside start at (0, 0), wrap it, and shift it back.
+    fn next_position(&self, point: Point, direction: Direction) -> Point {
+        let wall = Offset(1, 1);
+        let next = point - wall + direction.offset();
+        next.wrap(self.rows - 2, self.cols - 2) + wall
     }
 
-    /// Given a row and column and the Direction a blizzard is blowing, add that
+    /// Given a point and the Direction a blizzard is blowing, add that
     /// blizzard Direction to that Space. If the Space is empty, convert it to a
     /// Blizzard. If there's already a Blizzard there, just add the new Direction
     /// to the existing Blizzard. Attempts to add a Blizzard to a Wall will
     /// definitely fail.
-    fn add_blizzard(&mut self, row: usize, col: usize, direction: Direction) {
-        match &mut self.spaces[row][col] {
+    fn add_blizzard(&mut self, point: Point, direction: Direction) {
+        match &mut self.spaces[point] {
             Space::Blizzard(v) => v.add(direction),
             Space::Wall => panic!("Tried to add a blizzard to a wall!"),
-            Space::Empty => self.spaces[row][col] = Space::Blizzard(Blizzard::from(direction)),
+            Space::Empty => self.spaces[point] = Space::Blizzard(Blizzard::from(direction)),
         }
     }
 }
 
-const INPUT: &str = include_str!("../../input/24/input.txt");
+pub(crate) const INPUT: &str = include_str!("../../input/24/input.txt");
 
 /// Parse the initial Valley state from the input, then advance the state
 /// minute-by-minute until we reach a state we've seen before. That's right,
@@ -207,8 +191,33 @@ const INPUT: &str = include_str!("../../input/24/input.txt");
 /// potentially re-creating the same state multiple times. Store the Valley
 /// states in a Vector where the index indicates the minute at which that
 /// state is valid.
-pub fn read() -> Input {
-    let mut valley = Valley::from(INPUT);
+pub fn parse(input: &str) -> Result<Input> {
+    // `Valley::from` and the Blizzards blowing around in it expect a rectangle of
+    // Spaces with Walls all the way around, so make sure that's what we've got.
+    let spaces = Grid::parse(input, |glyph| match glyph {
+        '>' => Ok(Space::Blizzard(Blizzard::from(Direction::Right))),
+        '<' => Ok(Space::Blizzard(Blizzard::from(Direction::Left))),
+        '^' => Ok(Space::Blizzard(Blizzard::from(Direction::Up))),
+        'v' => Ok(Space::Blizzard(Blizzard::from(Direction::Down))),
+        '.' => Ok(Space::Empty),
+        '#' => Ok(Space::Wall),
+        _ => Err("expected one of '>', '<', '^', 'v', '.', or '#'"),
+    })?;
+    let (rows, cols) = (spaces.rows(), spaces.cols());
+    if rows < 3 || cols < 3 {
+        return Err(Error::parse(input, input, "the valley is too small"));
+    }
+    for (row, line) in input.lines().enumerate() {
+        for (col, glyph) in line.char_indices() {
+            let on_edge = row == 0 || row == rows - 1 || col == 0 || col == cols - 1;
+            if on_edge && matches!(spaces[(row, col)], Space::Blizzard(_)) {
+                let reason = "blizzards can't blow along the walls";
+                return Err(Error::parse(input, &line[col..], reason));
+            }
+        }
+    }
+
+    let mut valley = Valley::from(spaces);
     let mut valley_states = Vec::new();
     let mut seen_states = HashSet::new();
     while !seen_states.contains(&valley) {
@@ -216,7 +225,12 @@ pub fn read() -> Input {
         valley_states.push(valley.clone());
         valley = valley.advance()
     }
-    valley_states
+    Ok(valley_states)
+}
+
+/// Parse that input!
+pub fn read() -> Input {
+    parse(INPUT).unwrap()
 }
 
 #[cfg(test)]
@@ -227,39 +241,24 @@ mod test {
 
     impl Display for Valley {
         fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
-            for row in &self.spaces {
-                for space in row.iter() {
-                    match space {
-                        Space::Blizzard(blizzard) => {
-                            if blizzard.0.count_ones() > 1 {
-                                write!(f, "X")?;
-                            } else {
-                                let glyph = match blizzard.direction().unwrap() {
-                                    Direction::Down => 'v',
-                                    Direction::Left => '<',
-                                    Direction::Up => '^',
-                                    Direction::Right => '>',
-                                };
-                                write!(f, "{glyph}")?;
-                            }
-                        }
-                        Space::Wall => {
-                            write!(f, "#")?;
-                        }
-                        Space::Empty => {
-                            write!(f, ".")?;
-                        }
-                    }
-                }
-                writeln!(f)?;
-            }
-            write!(f, "")
+            let text = self.spaces.render(|space| match space {
+                Space::Blizzard(blizzard) if blizzard.0.count_ones() > 1 => 'X',
+                Space::Blizzard(blizzard) => match blizzard.direction().unwrap() {
+                    Direction::Down => 'v',
+                    Direction::Left => '<',
+                    Direction::Up => '^',
+                    Direction::Right => '>',
+                },
+                Space::Wall => '#',
+                Space::Empty => '.',
+            });
+            write!(f, "{text}")
         }
     }
 
     #[test]
     fn check_input() {
-        let mut valley = Valley::from(INPUT);
+        let mut valley = read()[0].clone();
         let mut valley_states = HashMap::new();
         let mut seen_states = HashSet::new();
         while !seen_states.contains(&valley) {
@@ -1,11 +1,13 @@
 use super::input::{Space, Valley};
-use crate::day24::{Input, Output};
-use core::cmp::Reverse;
-use std::collections::{BinaryHeap, HashMap};
+use crate::day24::{Input, Output, Result};
+use crate::search::{self, Found};
+use crate::Error;
 
 /// Solve Day 24, Part 1
-pub fn solve(input: &Input) -> Output {
-    let first_state = input.get(0).expect("Valley should have an initial state!");
+pub fn solve(input: &Input) -> Result<Output> {
+    let Some(first_state) = input.get(0) else {
+        return Err(Error::no_solution("Valley should have an initial state!"));
+    };
 
     // All examples and input have the start Space at index (0, 1) and the end
     // Space on the bottom row, next to the last column.
@@ -14,12 +16,14 @@ pub fn solve(input: &Input) -> Output {
 
     // Calculate the length of the shortest path through the Valley.
     if let Some(minutes) = start_at.shortest_path(end_at, 0, input) {
-        return (minutes as u32).into();
+        return Ok((minutes as u32).into());
     }
 
     // Unless we can't find a path. Then, freak out! This doesn't happen,
     // though. Not anymore...
-    panic!("Could not find a way through the valley. Died of frostbite!");
+    Err(Error::no_solution(
+        "Could not find a way through the valley. Died of frostbite!",
+    ))
 }
 
 /// Represents the location of the elven Expedition through the Valley.
@@ -31,17 +35,17 @@ impl Expedition {
     /// determine which next steps are possible, in order to avoid landing
     /// on a Space with an active Blizzard. There are a maximum of five
     /// possible moves, including waiting.
-    fn possible_next_steps(&self, valley: &Valley) -> [Option<Expedition>; 5] {
+    pub fn possible_next_steps(&self, valley: &Valley) -> [Option<Expedition>; 5] {
         let Expedition(row, col) = *self;
         let mut possible_steps = [None; 5];
 
         // Attempt to move to the left
-        if let Space::Empty = valley.spaces[row][col - 1] {
+        if let Space::Empty = valley.spaces[(row, col - 1)] {
             possible_steps[0] = Some(Expedition(row, col - 1));
         }
 
         // Attempt to move to the right
-        if let Space::Empty = valley.spaces[row][col + 1] {
+        if let Space::Empty = valley.spaces[(row, col + 1)] {
             possible_steps[1] = Some(Expedition(row, col + 1));
         }
 
@@ -49,7 +53,7 @@ impl Expedition {
         // final space is in the top row, which means that moving into
         // the top row is possible.
         if row > 0 {
-            if let Space::Empty = valley.spaces[row - 1][col] {
+            if let Space::Empty = valley.spaces[(row - 1, col)] {
                 possible_steps[2] = Some(Expedition(row - 1, col));
             }
         }
@@ -57,14 +61,14 @@ impl Expedition {
         // The downard move needs to account for the beginning space
         // being on the last row, which means that moving into the last row
         // is possible.
-        if row < (valley.spaces.len() - 1) {
-            if let Space::Empty = valley.spaces[row + 1][col] {
+        if row < (valley.rows - 1) {
+            if let Space::Empty = valley.spaces[(row + 1, col)] {
                 possible_steps[3] = Some(Expedition(row + 1, col));
             }
         }
 
         // Waiting is a necessary option if there's nothing in our current space
-        if let Space::Empty = valley.spaces[row][col] {
+        if let Space::Empty = valley.spaces[(row, col)] {
             possible_steps[4] = Some(Expedition(row, col));
         }
 
@@ -74,46 +78,77 @@ impl Expedition {
     /// Find the shortest path from this Expedition's location to the `target`,
     /// assuming we start the journey at minute `start_time`. Pass in a reference
     /// to the time states of the Valley so we can know which Spaces are Empty
-    /// for each minute. It's a Dijkstra's implementation.
+    /// for each minute. Returns the minute we arrive.
     pub fn shortest_path(
         &self,
         target: Expedition,
         start_time: usize,
         valley_states: &[Valley],
     ) -> Option<usize> {
-        // Sort locations in the Heap by the least number of minutes spent. Uniquely
-        // identify states along the path by the location of the Expedition for a
-        // given state of the Valley.
-        let mut open = BinaryHeap::from([(Reverse(start_time), *self)]);
-        let mut minutes = HashMap::from([((*self, start_time % valley_states.len()), start_time)]);
-
-        // So long as there are states to explore, take the one with the fewest
-        // number of minutes passed.
-        while let Some((Reverse(minutes_passed), expedition)) = open.pop() {
-            // If we've found the end, return the number of minutes passed
-            if expedition == target {
-                return Some(minutes_passed);
-            }
+        let found = self.search(target, start_time, valley_states)?;
+        Some(start_time + found.cost)
+    }
 
-            // Get the state of the Valley in the next minute to identify
-            // which Spaces are available to be moved to.
-            let state_idx = (minutes_passed + 1) % valley_states.len();
-            let state = &valley_states[state_idx];
-
-            // Check each next step to see if this is the fastest we've gotten
-            // to that state. If so, keep it and add it to the heap. Otherwise,
-            // keep moving on.
-            for step in expedition.possible_next_steps(state).into_iter().flatten() {
-                let next_minutes = minutes_passed + 1;
-                let curr_minutes = *minutes.get(&(step, state_idx)).unwrap_or(&usize::MAX);
-                if next_minutes >= curr_minutes {
-                    continue;
-                }
-                open.push((Reverse(next_minutes), step));
-                minutes.insert((step, state_idx), next_minutes);
-            }
-        }
+    /// Find the fastest route from this Expedition's location to the `target`, the
+    /// same way as `shortest_path()`. Returns where the Expedition is at each minute
+    /// along the way, starting with where it is now.
+    pub fn route(
+        &self,
+        target: Expedition,
+        start_time: usize,
+        valley_states: &[Valley],
+    ) -> Option<Vec<Expedition>> {
+        let found = self.search(target, start_time, valley_states)?;
+        Some(found.path().into_iter().map(|(step, _)| step).collect())
+    }
 
-        None // Something has gone terribly wrong...
+    /// It's an A* search. Uniquely identify states along the path by the location of
+    /// the Expedition for a given state of the Valley. Every minute costs the same,
+    /// and the Expedition can't possibly get to the `target` any faster than walking
+    /// straight there, so the distance to the `target` makes a good heuristic.
+    fn search(
+        &self,
+        target: Expedition,
+        start_time: usize,
+        valley_states: &[Valley],
+    ) -> Option<Found<(Expedition, usize), usize>> {
+        // Get the state of the Valley in the next minute to identify which Spaces
+        // are available to be moved to.
+        let successors = |(expedition, state_idx): &(Expedition, usize)| {
+            let next_idx = (state_idx + 1) % valley_states.len();
+            let steps = expedition.possible_next_steps(&valley_states[next_idx]);
+            steps
+                .into_iter()
+                .flatten()
+                .map(move |step| ((step, next_idx), 1))
+        };
+        let Expedition(target_row, target_col) = target;
+        let distance = |(Expedition(row, col), _): &(Expedition, usize)| {
+            row.abs_diff(target_row) + col.abs_diff(target_col)
+        };
+        let start = (*self, start_time % valley_states.len());
+        search::astar(start, successors, distance, |(step, _)| *step == target)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::day24::input::parse;
+
+    #[test]
+    fn check_route() {
+        let input = parse(include_str!("../../input/24/test.txt")).unwrap();
+        let (start_at, end_at) = (Expedition(0, 1), Expedition(5, 6));
+        let route = start_at.route(end_at, 0, &input).unwrap();
+
+        // 18 minutes, one move (or wait) per minute, and never in a Blizzard
+        assert_eq!(route.len(), 19);
+        assert_eq!((route[0], route[18]), (start_at, end_at));
+        for (minute, pair) in route.windows(2).enumerate() {
+            let valley = &input[(minute + 1) % input.len()];
+            let steps = pair[0].possible_next_steps(valley);
+            assert!(steps.contains(&Some(pair[1])), "minute {minute}");
+        }
     }
 }
@@ -1,10 +1,13 @@
 use super::input::Space;
 use super::part1::Expedition;
-use crate::day24::{Input, Output};
+use crate::day24::{Input, Output, Result};
+use crate::Error;
 
 /// Solve Day 24, Part 2
-pub fn solve(input: &Input) -> Output {
-    let first_state = input.get(0).expect("Valley should have an initial state!");
+pub fn solve(input: &Input) -> Result<Output> {
+    let Some(first_state) = input.get(0) else {
+        return Err(Error::no_solution("Valley should have an initial state!"));
+    };
 
     // All examples and input have the start Space at index (0, 1) and the end
     // Space on the bottom row, next to the last column.
@@ -13,19 +16,22 @@ pub fn solve(input: &Input) -> Output {
 
     // Start at zero minutes and move from the start to the end.
     let Some(minutes) = start_at.shortest_path(end_at, 0, input) else {
-        panic!("Could not find a way through the valley. Died of frostbite!"); 
+        let reason = "Could not find a way through the valley. Died of frostbite!";
+        return Err(Error::no_solution(reason));
     };
 
     // Turn around and head back to the start.
     let Some(minutes) = end_at.shortest_path(start_at, minutes, input) else {
-        panic!("Could not find a way back! Died in a blizzard!"); 
+        let reason = "Could not find a way back! Died in a blizzard!";
+        return Err(Error::no_solution(reason));
     };
 
     // Then turn around and head back to the end again.
     let Some(minutes) = start_at.shortest_path(end_at, minutes, input) else {
-        panic!("Could not find a way back! Died of embarassment!"); 
+        let reason = "Could not find a way back! Died of embarassment!";
+        return Err(Error::no_solution(reason));
     };
 
     // Return the total number of minutes it took to travel all that way.
-    (minutes as u32).into()
+    Ok((minutes as u32).into())
 }
@@ -0,0 +1,107 @@
+use crate::day24::input::{Direction, Space, Valley};
+use crate::day24::part1::Expedition;
+use crate::day24::{Input, Result};
+use crate::visualize::{Frame, Sink};
+use crate::Error;
+use std::collections::HashSet;
+
+/// Watch the blizzards blow through the Valley on the way across in part one,
+/// drawing every space the expedition could have reached by each minute. The first
+/// minute one of those spaces is the way out, we're done.
+pub fn visualize(input: &Input, sink: &mut dyn Sink) -> Result<()> {
+    let Some(first_state) = input.get(0) else {
+        return Err(Error::no_solution("Valley should have an initial state!"));
+    };
+    let start_at = Expedition(0, 1);
+    let end_at = Expedition(first_state.rows - 1, first_state.cols - 2);
+
+    let mut reachable = HashSet::from([start_at]);
+    for minute in 0.. {
+        let valley = &input[minute % input.len()];
+        sink.frame(&Trip {
+            valley,
+            reachable: &reachable,
+        })?;
+        if reachable.contains(&end_at) {
+            return Ok(());
+        }
+
+        // Every space that can be stepped to (or waited in) from a reachable space,
+        // once the blizzards have moved on
+        let next_valley = &input[(minute + 1) % input.len()];
+        reachable = reachable
+            .iter()
+            .flat_map(|expedition| expedition.possible_next_steps(next_valley))
+            .flatten()
+            .collect();
+        if reachable.is_empty() {
+            break;
+        }
+    }
+    Err(Error::no_solution(
+        "Could not find a way through the valley. Died of frostbite!",
+    ))
+}
+
+/// The Valley, drawn like the puzzle description draws it, with an `E` on every
+/// space the expedition could be in
+struct Trip<'a> {
+    valley: &'a Valley,
+    reachable: &'a HashSet<Expedition>,
+}
+
+impl Frame for Trip<'_> {
+    fn size(&self) -> (usize, usize) {
+        (self.valley.rows, self.valley.cols)
+    }
+
+    fn glyph(&self, row: usize, col: usize) -> char {
+        if self.reachable.contains(&Expedition(row, col)) {
+            return 'E';
+        }
+        match &self.valley.spaces[(row, col)] {
+            Space::Wall => '#',
+            Space::Empty => '.',
+            Space::Blizzard(blizzard) if blizzard.count() > 1 => {
+                char::from_digit(blizzard.count(), 10).unwrap_or('?')
+            }
+            Space::Blizzard(blizzard) => {
+                let direction = Direction::all().into_iter().find(|d| blizzard.has(*d));
+                match direction {
+                    Some(Direction::Down) => 'v',
+                    Some(Direction::Left) => '<',
+                    Some(Direction::Up) => '^',
+                    Some(Direction::Right) => '>',
+                    None => '.',
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::day24::input::parse;
+    use crate::visualize::Frames;
+
+    #[test]
+    fn check_example() {
+        let input = parse(include_str!("../../input/24/test.txt")).unwrap();
+        let mut frames = Frames::default();
+        visualize(&input, &mut frames).unwrap();
+
+        // It takes 18 minutes to get across, and the first frame is the Valley from
+        // the puzzle description, with the expedition at the start
+        assert_eq!(frames.0.len(), 19);
+        let expected = "\
+            #E######\n\
+            #>>.<^<#\n\
+            #.<..<<#\n\
+            #>v.><>#\n\
+            #<^v^^>#\n\
+            ######.#\n";
+        assert_eq!(frames.0[0], expected);
+        assert!(frames.0[18].ends_with("######E#\n"));
+    }
+}
@@ -1,4 +1,5 @@
 use crate::day25::Input;
+use crate::{Error, Result};
 
 /// This represents one of our SNAFU numbers, which is just a String
 /// in a Wrapper so we can have custom `From` implementations.
@@ -12,9 +13,19 @@ impl From<&str> for Snafu {
     }
 }
 
-const INPUT: &str = include_str!("../../input/25/input.txt");
+pub(crate) const INPUT: &str = include_str!("../../input/25/input.txt");
+
+/// Parse the text of an input file
+pub fn parse(input: &str) -> Result<Input> {
+    for line in input.lines() {
+        if let Some(idx) = line.find(|c| !"=-012".contains(c)) {
+            return Err(Error::parse(input, &line[idx..], "not a SNAFU digit"));
+        }
+    }
+    Ok(input.lines().map(Snafu::from).collect::<Vec<_>>())
+}
 
 /// Parse that input!
 pub fn read() -> Input {
-    INPUT.lines().map(Snafu::from).collect::<Vec<_>>()
+    parse(INPUT).unwrap()
 }
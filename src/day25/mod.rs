@@ -1,34 +1,44 @@
 pub mod input;
 pub mod part1;
 
-use crate::{Output, Part};
+use crate::{Output, Part, Result, Solution, Source};
 use input::Snafu;
 
 pub type Input = Vec<Snafu>;
 
-pub fn run(part: Part) -> Output {
-    let input = input::read();
-    match part {
-        Part::One => part1::solve(&input),
-        Part::Two => String::from("No part 2 for Day 25!").into(),
+pub struct Day25;
+
+impl Solution for Day25 {
+    const DAY: u8 = 25;
+    const INPUT: &'static str = input::INPUT;
+    type Input<'a> = Input;
+
+    fn parse(text: &str) -> Result<Self::Input<'_>> {
+        input::parse(text)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Result<Output> {
+        part1::solve(input)
     }
-}
 
-pub fn run_both() -> (Output, Output) {
-    let input = input::read();
-    (
-        part1::solve(&input),
-        Output::String(String::from("Nothing to see here..")),
-    )
+    fn part2(input: &Self::Input<'_>) -> Result<Output> {
+        Ok(String::from("No part 2 for Day 25!").into())
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::examples;
 
     #[test]
     fn check_answer_one() {
-        let result = run(Part::One);
+        let result = Day25::run(Part::One, Source::Bundled).unwrap();
         assert_eq!(result, "2-=102--02--=1-12=22");
     }
+
+    #[test]
+    fn check_example_one() {
+        examples::check::<Day25>(Part::One);
+    }
 }
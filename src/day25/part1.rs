@@ -1,11 +1,11 @@
 use super::input::Snafu;
-use crate::day25::{Input, Output};
+use crate::day25::{Input, Output, Result};
 
 /// Solve Day 25, Part 1
-pub fn solve(input: &Input) -> Output {
+pub fn solve(input: &Input) -> Result<Output> {
     let fuel_cost: i128 = input.iter().cloned().map(i128::from).sum();
     let snafu_cost = Snafu::from(fuel_cost);
-    snafu_cost.0.into()
+    Ok(snafu_cost.0.into())
 }
 
 /// Unit conversion from a SNAFU number to a base-10 integer. This is a pretty
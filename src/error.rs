@@ -0,0 +1,177 @@
+//! Errors that can occur while solving a day's puzzle. Most of the days used to just
+//! `.unwrap()` their way through parsing and `panic!` when they couldn't find an answer,
+//! which is fine for my input but not so great for anyone else's.
+use std::fmt::{Display, Formatter, Result as FmtResult};
+use std::path::PathBuf;
+
+/// Shorthand for results that fail with our `Error`
+pub type Result<T, E = Error> = std::result::Result<T, E>;
+
+/// All the ways that solving a day's puzzle can fail
+#[derive(Debug)]
+pub enum Error {
+    /// The input file couldn't be read
+    Io {
+        path: PathBuf,
+        error: std::io::Error,
+    },
+
+    /// The input couldn't be read from a stream, for the days that can read their
+    /// input a line at a time instead of all at once
+    Read(std::io::Error),
+
+    /// The input text couldn't be parsed. Lines and columns start at one, and
+    /// the column is counted in characters from the start of the line.
+    Parse {
+        line: usize,
+        column: usize,
+        reason: String,
+    },
+
+    /// The input parsed just fine, but the solver couldn't find an answer for it
+    NoSolution(String),
+
+    /// A file (like an image of a visualization) couldn't be written
+    Write {
+        path: PathBuf,
+        error: std::io::Error,
+    },
+
+    /// Output couldn't be written somewhere that isn't a file, like the terminal
+    Output(std::io::Error),
+}
+
+impl Error {
+    /// Build a parse error for a problem found at the start of `at`, which must be
+    /// a slice of `input`. The position is found by comparing the two slices, which
+    /// means parsers don't need to keep track of lines and columns themselves. If
+    /// `at` isn't part of `input`, the error is reported at the end of the input.
+    pub fn parse(input: &str, at: &str, reason: impl Display) -> Self {
+        let start = input.as_ptr() as usize;
+        let end = start + input.len();
+        let at_ptr = at.as_ptr() as usize;
+        let offset = if (start..=end).contains(&at_ptr) {
+            at_ptr - start
+        } else {
+            input.len()
+        };
+
+        let before = &input[..offset];
+        let line_start = before.rfind('\n').map(|idx| idx + 1).unwrap_or_default();
+        let line = before.matches('\n').count() + 1;
+        let column = before[line_start..].chars().count() + 1;
+        let reason = reason.to_string();
+        Error::Parse {
+            line,
+            column,
+            reason,
+        }
+    }
+
+    /// Build a parse error from the error returned by a nom parser that was run
+    /// over `input` (or some slice of it).
+    pub fn nom(input: &str, error: nom::error::Error<&str>) -> Self {
+        let found = error.input.lines().next().unwrap_or_default();
+        let found: String = found.chars().take(20).collect();
+        let reason = match found.is_empty() {
+            true => format!("unexpected end of line ({:?})", error.code),
+            false => format!("unexpected {found:?} ({:?})", error.code),
+        };
+        Error::parse(input, error.input, reason)
+    }
+
+    /// Build an error for an input that doesn't have a solution
+    pub fn no_solution(reason: impl Display) -> Self {
+        Error::NoSolution(reason.to_string())
+    }
+}
+
+/// Finish running a nom parser over the whole of `input`, making sure it consumed
+/// everything but trailing whitespace. Parsers built on `separated_list1` stop quietly
+/// at the first line they can't parse, so without this check a typo halfway through
+/// the input would just drop the rest of it.
+pub fn finish<'a, T>(input: &'a str, result: nom::IResult<&'a str, T>) -> Result<T> {
+    use nom::Finish;
+
+    let (rest, value) = result.finish().map_err(|e| Error::nom(input, e))?;
+    let rest = rest.trim_start();
+    if !rest.is_empty() {
+        let found: String = rest
+            .lines()
+            .next()
+            .unwrap_or_default()
+            .chars()
+            .take(20)
+            .collect();
+        return Err(Error::parse(input, rest, format!("unexpected {found:?}")));
+    }
+    Ok(value)
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match self {
+            Error::Io { path, error } => {
+                write!(f, "Could not read input from {}: {error}", path.display())
+            }
+            Error::Read(error) => write!(f, "Could not read input: {error}"),
+            Error::Parse {
+                line,
+                column,
+                reason,
+            } => write!(
+                f,
+                "Could not parse input at line {line}, column {column}: {reason}"
+            ),
+            Error::NoSolution(reason) => write!(f, "No solution found: {reason}"),
+            Error::Write { path, error } => {
+                write!(f, "Could not write to {}: {error}", path.display())
+            }
+            Error::Output(error) => write!(f, "Could not write output: {error}"),
+        }
+    }
+}
+
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Error::Io { error, .. }
+            | Error::Read(error)
+            | Error::Write { error, .. }
+            | Error::Output(error) => Some(error),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn check_parse_position() {
+        let input = "first line\nsecond line\nthird";
+        let at = &input[18..];
+        let Error::Parse { line, column, .. } = Error::parse(input, at, "bad") else {
+            panic!("Should be a parse error!");
+        };
+        assert_eq!((line, column), (2, 8));
+
+        let Error::Parse { line, column, .. } = Error::parse(input, "elsewhere", "bad") else {
+            panic!("Should be a parse error!");
+        };
+        assert_eq!((line, column), (3, 6));
+    }
+
+    #[test]
+    fn check_nom_error() {
+        use nom::{bytes::complete::tag, Finish, IResult};
+
+        let input = "move 1\nmove x";
+        let line = input.lines().nth(1).unwrap();
+        let result: IResult<&str, &str> = tag("move 1")(line);
+        let error = Error::nom(input, result.finish().unwrap_err());
+        let message = error.to_string();
+        assert!(message.starts_with("Could not parse input at line 2, column 1:"));
+    }
+}
@@ -0,0 +1,52 @@
+//! Test harness for the example inputs from each day's puzzle description. The examples
+//! live next to my inputs in `input/XX/test.txt`, and their published answers are in
+//! `input/examples.toml`. My inputs all share quirks that the examples don't, so solving
+//! the examples is a good sign that a solution works for anyone's input.
+use crate::*;
+use std::path::{Path, PathBuf};
+
+/// The path to the example input for a day
+pub fn path(day: u8) -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).join(format!("input/{day:02}/test.txt"))
+}
+
+/// The text of the example input for a day, for tests that need to call into a day
+/// directly instead of going through `Solution::run`.
+pub fn text(day: u8) -> String {
+    let path = path(day);
+    match std::fs::read_to_string(&path) {
+        Ok(text) => text,
+        Err(error) => panic!("Couldn't read {}: {error}", path.display()),
+    }
+}
+
+/// The published answers for all the example inputs
+pub fn answers() -> Answers {
+    let path = Path::new(env!("CARGO_MANIFEST_DIR")).join("input/examples.toml");
+    Answers::read(&path).unwrap()
+}
+
+/// The published answer for one part of a day's example input
+pub fn expected(day: u8, part: Part) -> String {
+    match answers().expected(day, part) {
+        Some(expected) => expected,
+        None => panic!("No published answer for the Day {day} example, {part:?}"),
+    }
+}
+
+/// Solve one part of a day's example input and check that the answer matches the
+/// published answer from the puzzle description.
+pub fn check<S: Solution>(part: Part) {
+    let path = path(S::DAY);
+    let answer = match S::run(part, Source::Path(&path)) {
+        Ok(answer) => answer,
+        Err(error) => panic!("Day {} example, {part:?}: {error}", S::DAY),
+    };
+    let check = answers().check(S::DAY, part, &answer);
+    assert_eq!(
+        check,
+        Check::Pass,
+        "Day {} example, {part:?}: got {answer}, {check}",
+        S::DAY
+    );
+}
@@ -0,0 +1,463 @@
+//! Downloading puzzle inputs from the Advent of Code site and keeping them around, so
+//! the binary can solve anyone's inputs without copying files into `input/` by hand.
+//! Every input is different, so downloads need the `session` cookie from a logged-in
+//! browser. Inputs are cached per user under a data directory, laid out the same way
+//! as `input/` (`<data dir>/<user>/XX/input.txt`), so each one only gets downloaded
+//! once and can be read back with `--input-dir` like any other inputs.
+//!
+//! There's no HTTP client in the dependencies, and I'd rather not add one for a few
+//! requests. Plain `http://` URLs (like a mock server in the tests) are handled
+//! with a bare-bones HTTP/1.0 request over a `TcpStream`, and `https://` URLs are
+//! handed off to `curl`. That means talking to the real site needs `curl` installed
+//! and on the `PATH`, and it's an error that says so if it isn't.
+use crate::Result;
+use std::fmt::{Display, Formatter, Result as FmtResult};
+use std::io::{Read, Write};
+use std::net::TcpStream;
+use std::path::{Path, PathBuf};
+use std::process::{Command, Stdio};
+use std::time::Duration;
+
+/// Where the inputs come from, unless told otherwise
+pub const BASE_URL: &str = "https://adventofcode.com";
+
+/// Let the folks running the site know who's asking, like they request
+const USER_AGENT: &str = "aoc2022bin (Eric's Advent of Code 2022 solutions)";
+
+/// Give up on a server that stops talking to us for this long
+const TIMEOUT: Duration = Duration::from_secs(30);
+
+/// All the ways that downloading inputs and submitting answers can fail. None of
+/// this has anything to do with solving the puzzles, so it gets its own error
+/// instead of crowding `crate::Error`.
+#[derive(Debug)]
+pub enum FetchError {
+    /// A user name that can't be used as the name of a directory
+    InvalidUser(String),
+
+    /// A session token that can't go in a cookie as it is. The token itself is
+    /// left out, since it's a secret.
+    InvalidSession,
+
+    /// A downloaded input couldn't be written to the cache
+    Write {
+        path: PathBuf,
+        error: std::io::Error,
+    },
+
+    /// The puzzle server doesn't have anything at `url`, usually because that day
+    /// hasn't been unlocked yet
+    NotFound { url: String },
+
+    /// The puzzle server didn't accept the session token for `url`
+    Unauthorized { url: String },
+
+    /// Couldn't talk to the puzzle server, or it said something we didn't expect
+    Http { url: String, reason: String },
+}
+
+impl Display for FetchError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match self {
+            FetchError::InvalidUser(user) => write!(f, "{user:?} can't be used as a user name"),
+            FetchError::InvalidSession => write!(
+                f,
+                "The session token should be the value of the `session` cookie, with no \
+                 spaces, quotes, commas, semicolons, backslashes, or control characters"
+            ),
+            FetchError::Write { path, error } => {
+                write!(f, "Could not write to {}: {error}", path.display())
+            }
+            FetchError::NotFound { url } => write!(
+                f,
+                "Nothing to download at {url} (is that day unlocked yet?)"
+            ),
+            FetchError::Unauthorized { url } => write!(
+                f,
+                "Not allowed to download {url}, so the session token is probably missing or expired"
+            ),
+            FetchError::Http { url, reason } => write!(f, "Could not download {url}: {reason}"),
+        }
+    }
+}
+
+impl std::error::Error for FetchError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            FetchError::Write { error, .. } => Some(error),
+            _ => None,
+        }
+    }
+}
+
+/// Downloads inputs for one user from the puzzle server at `base_url`
+#[derive(Debug, Clone)]
+pub struct Client {
+    base_url: String,
+    session: String,
+}
+
+impl Client {
+    /// Create a client for the server at `base_url` (like `BASE_URL`), logged in with
+    /// the value of a `session` cookie. The token goes straight into a `Cookie`
+    /// header, so anything that isn't allowed in a cookie value (like a line break
+    /// that would start a header of its own) is an error.
+    pub fn new(base_url: &str, session: &str) -> Result<Self, FetchError> {
+        let base_url = base_url.trim_end_matches('/').to_string();
+        let session = session.trim().to_string();
+        if session.is_empty() || !session.bytes().all(is_cookie_octet) {
+            return Err(FetchError::InvalidSession);
+        }
+        Ok(Client { base_url, session })
+    }
+
+    /// Download the text of a day's input
+    pub fn input(&self, day: u8) -> Result<String, FetchError> {
+        self.send(&self.day_url(day, "input"), None)
+    }
+
+    /// Send a request for `url` and return the body, if the server said OK. Requests
+    /// with a `form` are POSTed, and the rest are plain GETs.
+    pub(crate) fn send(&self, url: &str, form: Option<&str>) -> Result<String, FetchError> {
+        let http_error = |reason: String| FetchError::Http {
+            url: url.to_string(),
+            reason,
+        };
+        let (status, body) = match url.split_once("://") {
+            Some(("http", rest)) => http_send(rest, &self.session, form),
+            Some(("https", _)) => curl_send(url, &self.session, form),
+            _ => Err(String::from("only http:// and https:// URLs are supported")),
+        }
+        .map_err(http_error)?;
+
+        match status {
+            200..=299 => Ok(body),
+            404 => Err(FetchError::NotFound {
+                url: url.to_string(),
+            }),
+            400 | 401 | 403 => Err(FetchError::Unauthorized {
+                url: url.to_string(),
+            }),
+            _ => Err(http_error(format!("the server responded with {status}"))),
+        }
+    }
+
+    /// The URL for a page about a day's puzzle, like `input` or `answer`
+    pub(crate) fn day_url(&self, day: u8, page: &str) -> String {
+        format!("{}/2022/day/{day}/{page}", self.base_url)
+    }
+}
+
+/// Check whether a byte can be part of a cookie value, which is any visible ASCII
+/// character but `"`, `,`, `;`, and `\` (RFC 6265)
+fn is_cookie_octet(byte: u8) -> bool {
+    byte.is_ascii_graphic() && !matches!(byte, b'"' | b',' | b';' | b'\\')
+}
+
+/// Send a request to a URL (without the `http://`) the hard way. Asking for HTTP/1.0
+/// means the server just sends the body and hangs up, instead of chunking it.
+fn http_send(
+    url: &str,
+    session: &str,
+    form: Option<&str>,
+) -> std::result::Result<(u16, String), String> {
+    let (authority, path) = match url.find('/') {
+        Some(idx) => url.split_at(idx),
+        None => (url, "/"),
+    };
+    let address = match authority.contains(':') {
+        true => authority.to_string(),
+        false => format!("{authority}:80"),
+    };
+
+    let mut stream = TcpStream::connect(address).map_err(|e| e.to_string())?;
+    stream.set_read_timeout(Some(TIMEOUT)).ok();
+    stream.set_write_timeout(Some(TIMEOUT)).ok();
+    let headers =
+        format!("Host: {authority}\r\nUser-Agent: {USER_AGENT}\r\nCookie: session={session}\r\n");
+    let request = match form {
+        Some(form) => format!(
+            "POST {path} HTTP/1.0\r\n{headers}\
+             Content-Type: application/x-www-form-urlencoded\r\n\
+             Content-Length: {}\r\n\r\n{form}",
+            form.len()
+        ),
+        None => format!("GET {path} HTTP/1.0\r\n{headers}\r\n"),
+    };
+    stream
+        .write_all(request.as_bytes())
+        .map_err(|e| e.to_string())?;
+    let mut response = Vec::new();
+    stream
+        .read_to_end(&mut response)
+        .map_err(|e| e.to_string())?;
+    let response = String::from_utf8(response).map_err(|e| e.to_string())?;
+
+    // The status line is something like `HTTP/1.1 200 OK`, and the body starts
+    // after the first blank line.
+    let bad_response = || format!("couldn't make sense of the response {response:?}");
+    let (head, body) = response.split_once("\r\n\r\n").ok_or_else(bad_response)?;
+    let status = head
+        .split_whitespace()
+        .nth(1)
+        .and_then(|code| code.parse().ok())
+        .ok_or_else(bad_response)?;
+    Ok((status, body.to_string()))
+}
+
+/// Send a request with `curl`, which prints the status code after the body. The
+/// cookie goes in on stdin so the session token doesn't show up in the process list.
+fn curl_send(
+    url: &str,
+    session: &str,
+    form: Option<&str>,
+) -> std::result::Result<(u16, String), String> {
+    let mut command = Command::new("curl");
+    command
+        .args(["--silent", "--show-error", "--location"])
+        .args(["--max-time", &TIMEOUT.as_secs().to_string()])
+        .args(["--user-agent", USER_AGENT])
+        .args(["--header", "@-"])
+        .args(["--write-out", "\n%{http_code}", url]);
+    if let Some(form) = form {
+        command.args(["--data-raw", form]);
+    }
+    let mut child = command
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .map_err(|e| format!("https:// URLs need `curl` installed and on the PATH ({e})"))?;
+    if let Some(mut stdin) = child.stdin.take() {
+        writeln!(stdin, "Cookie: session={session}").map_err(|e| e.to_string())?;
+    }
+    let output = child.wait_with_output().map_err(|e| e.to_string())?;
+    if !output.status.success() {
+        return Err(String::from_utf8_lossy(&output.stderr).trim().to_string());
+    }
+
+    let stdout = String::from_utf8(output.stdout).map_err(|e| e.to_string())?;
+    let (body, status) = stdout.rsplit_once('\n').unwrap_or_default();
+    let status = status
+        .parse()
+        .map_err(|_| format!("curl didn't report a status code ({status:?})"))?;
+    Ok((status, body.to_string()))
+}
+
+/// The downloaded inputs for one user, kept in `<data dir>/<user>/XX/input.txt`
+#[derive(Debug, Clone)]
+pub struct Cache {
+    dir: PathBuf,
+}
+
+impl Cache {
+    /// The cache for `user` under `data_dir`. Users are just names for directories,
+    /// so they can't have any path separators in them.
+    pub fn new(data_dir: &Path, user: &str) -> Result<Self, FetchError> {
+        let valid = !matches!(user, "" | "." | "..") && !user.contains(['/', '\\']);
+        if !valid {
+            return Err(FetchError::InvalidUser(user.to_string()));
+        }
+        let dir = data_dir.join(user);
+        Ok(Cache { dir })
+    }
+
+    /// The directory holding this user's inputs, which works with `--input-dir`
+    pub fn dir(&self) -> &Path {
+        &self.dir
+    }
+
+    /// Where this user's answer history is kept (see `submit.rs`)
+    pub fn history_path(&self) -> PathBuf {
+        self.dir.join("history.toml")
+    }
+
+    /// Where a day's input lives in the cache, whether or not it's there yet
+    pub fn path(&self, day: u8) -> PathBuf {
+        self.dir.join(format!("{day:02}")).join("input.txt")
+    }
+
+    /// The path to a day's input, if it's been downloaded
+    pub fn get(&self, day: u8) -> Option<PathBuf> {
+        Some(self.path(day)).filter(|path| path.is_file())
+    }
+
+    /// Download a day's input into the cache, unless it's already there (or
+    /// `refresh` says to download it again), and return where it is. The input is
+    /// written to a temporary file first, so an interrupted download never leaves
+    /// half an input lying around.
+    pub fn fetch(&self, client: &Client, day: u8, refresh: bool) -> Result<PathBuf, FetchError> {
+        if let Some(path) = self.get(day).filter(|_| !refresh) {
+            return Ok(path);
+        }
+        let text = client.input(day)?;
+
+        let path = self.path(day);
+        let partial = path.with_extension("txt.partial");
+        let write_error = |error| FetchError::Write {
+            path: path.clone(),
+            error,
+        };
+        if let Some(dir) = path.parent() {
+            std::fs::create_dir_all(dir).map_err(write_error)?;
+        }
+        std::fs::write(&partial, text).map_err(write_error)?;
+        std::fs::rename(&partial, &path).map_err(write_error)?;
+        Ok(path)
+    }
+}
+
+/// A name for whoever owns a session token, for when no one says who they are. It's a
+/// hash of the token (FNV-1a), so the token itself never ends up in a path.
+pub fn user_name(session: &str) -> String {
+    let hash = session
+        .trim()
+        .bytes()
+        .fold(0xcbf2_9ce4_8422_2325_u64, |hash, byte| {
+            (hash ^ byte as u64).wrapping_mul(0x0000_0100_0000_01b3)
+        });
+    format!("{hash:016x}")
+}
+
+/// Where to keep downloaded inputs: `$AOC_DATA_DIR` if it's set, or an `aoc2022`
+/// directory in the usual spot for user data (`$XDG_DATA_HOME`, then
+/// `~/.local/share`).
+pub fn default_data_dir() -> Option<PathBuf> {
+    let var = |name| std::env::var_os(name).filter(|value| !value.is_empty());
+    if let Some(dir) = var("AOC_DATA_DIR") {
+        return Some(PathBuf::from(dir));
+    }
+    let data_home = var("XDG_DATA_HOME")
+        .map(PathBuf::from)
+        .or_else(|| var("HOME").map(|home| Path::new(&home).join(".local").join("share")))?;
+    Some(data_home.join("aoc2022"))
+}
+
+/// Serve one canned response (a status and a body) for each request that comes in on
+/// a local port, then hand back every request that came in. Hands back the base URL
+/// for the server, so the tests don't need the real site.
+#[cfg(test)]
+pub(crate) fn mock_server(
+    responses: Vec<(u16, &'static str)>,
+) -> (String, std::thread::JoinHandle<Vec<String>>) {
+    let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+    let base_url = format!("http://{}", listener.local_addr().unwrap());
+    let handle = std::thread::spawn(move || {
+        let mut requests = Vec::new();
+        for (status, body) in responses {
+            let (mut stream, _) = listener.accept().unwrap();
+            let mut request = String::new();
+            let mut buffer = [0; 1024];
+            while !is_complete(&request) {
+                let read = stream.read(&mut buffer).unwrap();
+                request.push_str(std::str::from_utf8(&buffer[..read]).unwrap());
+            }
+            requests.push(request);
+            let response = format!(
+                "HTTP/1.1 {status} Whatever\r\nContent-Length: {}\r\n\r\n{body}",
+                body.len()
+            );
+            stream.write_all(response.as_bytes()).unwrap();
+        }
+        requests
+    });
+    (base_url, handle)
+}
+
+/// Check whether the mock server has read all of a request, including the body
+#[cfg(test)]
+fn is_complete(request: &str) -> bool {
+    let Some((head, body)) = request.split_once("\r\n\r\n") else {
+        return false;
+    };
+    let length = head
+        .lines()
+        .find_map(|line| line.strip_prefix("Content-Length: "))
+        .and_then(|length| length.parse().ok())
+        .unwrap_or(0);
+    body.len() >= length
+}
+
+/// A fresh, empty data directory for one test
+#[cfg(test)]
+pub(crate) fn test_data_dir(name: &str) -> PathBuf {
+    let name = format!("aoc2022-{name}-{}", std::process::id());
+    let dir = std::env::temp_dir().join(name);
+    let _ = std::fs::remove_dir_all(&dir);
+    dir
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn check_fetch() {
+        let (base_url, server) = mock_server(vec![(200, "1000\n2000\n")]);
+        let client = Client::new(&base_url, "abc123").unwrap();
+        let dir = test_data_dir("fetch");
+        let cache = Cache::new(&dir, &user_name("abc123")).unwrap();
+
+        let path = cache.fetch(&client, 1, false).unwrap();
+        assert_eq!(std::fs::read_to_string(&path).unwrap(), "1000\n2000\n");
+        assert_eq!(cache.get(1), Some(path.clone()));
+        assert!(path.ends_with("01/input.txt"));
+
+        // The second fetch comes from the cache, or the server would have panicked
+        let again = cache.fetch(&client, 1, false).unwrap();
+        assert_eq!(again, path);
+
+        let requests = server.join().unwrap();
+        assert_eq!(requests.len(), 1);
+        assert!(requests[0].starts_with("GET /2022/day/1/input HTTP/1.0\r\n"));
+        assert!(requests[0].contains("\r\nCookie: session=abc123\r\n"));
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn check_fetch_errors() {
+        let responses = vec![(404, "Not Found"), (400, "Please log in"), (500, "Oops")];
+        let (base_url, server) = mock_server(responses);
+        let client = Client::new(&base_url, "expired").unwrap();
+        let dir = test_data_dir("fetch-errors");
+        let cache = Cache::new(&dir, "someone").unwrap();
+
+        let result = cache.fetch(&client, 25, false);
+        assert!(matches!(result, Err(FetchError::NotFound { .. })));
+        let result = cache.fetch(&client, 1, false);
+        assert!(matches!(result, Err(FetchError::Unauthorized { .. })));
+        let result = cache.fetch(&client, 1, false);
+        assert!(matches!(result, Err(FetchError::Http { .. })));
+
+        // Nothing gets cached when the download fails
+        server.join().unwrap();
+        assert_eq!(cache.get(1), None);
+        assert!(!dir.exists());
+    }
+
+    #[test]
+    fn check_user_names() {
+        assert_eq!(user_name("abc123"), user_name(" abc123\n"));
+        assert_ne!(user_name("abc123"), user_name("abc124"));
+        assert!(!user_name("abc123").contains("abc123"));
+
+        let dir = test_data_dir("users");
+        assert!(matches!(
+            Cache::new(&dir, ".."),
+            Err(FetchError::InvalidUser(_))
+        ));
+        assert!(Cache::new(&dir, "a/b").is_err());
+        assert!(Cache::new(&dir, "").is_err());
+    }
+
+    #[test]
+    fn check_session_tokens() {
+        assert!(Client::new(BASE_URL, " 53616c7465645f5f\n").is_ok());
+        for session in ["", "abc\r\nX-Injected: 1", "abc 123", "abc;123", "abc\"123"] {
+            assert!(matches!(
+                Client::new(BASE_URL, session),
+                Err(FetchError::InvalidSession)
+            ));
+        }
+    }
+}
@@ -0,0 +1,404 @@
+//! Grids, and the points and offsets for getting around in them. A lot of the days
+//! hand us a map drawn with characters, and they each used to come up with their own
+//! two-dimensional vector and coordinate type to hold it. This is the one they share.
+//!
+//! Points are (x, y), where x counts columns to the right and y counts rows down from
+//! the top, the way the puzzle maps are drawn. Points can be negative (or off the
+//! edge of the grid), so the days that wander around an infinite plane can use them
+//! too. Indexing a `Grid` with a `(row, col)` tuple works as well, for the days that
+//! just loop over rows and columns.
+use crate::{Error, Result};
+use std::ops::{Add, AddAssign, Index, IndexMut, Mul, Neg, Sub};
+
+/// A point on the plane, as (x, y), with y increasing downwards
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Point(pub isize, pub isize);
+
+/// The difference between two points, as (x, y)
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Offset(pub isize, pub isize);
+
+impl Offset {
+    pub const UP: Offset = Offset(0, -1);
+    pub const RIGHT: Offset = Offset(1, 0);
+    pub const DOWN: Offset = Offset(0, 1);
+    pub const LEFT: Offset = Offset(-1, 0);
+
+    /// The four neighbors that share a side, clockwise from up
+    pub const ORTHOGONAL: [Offset; 4] = [Offset::UP, Offset::RIGHT, Offset::DOWN, Offset::LEFT];
+
+    /// All eight neighbors, diagonals included, clockwise from up
+    pub const ALL: [Offset; 8] = [
+        Offset::UP,
+        Offset(1, -1),
+        Offset::RIGHT,
+        Offset(1, 1),
+        Offset::DOWN,
+        Offset(-1, 1),
+        Offset::LEFT,
+        Offset(-1, -1),
+    ];
+
+    /// Shrink this offset to (at most) one step in each direction, pointing the
+    /// same way. Taking that step over and over will eventually cover the offset,
+    /// if it's horizontal, vertical, or diagonal.
+    pub fn signum(self) -> Offset {
+        let Offset(x, y) = self;
+        Offset(x.signum(), y.signum())
+    }
+}
+
+impl Point {
+    /// The Manhattan distance to another point, as in, how many steps it takes to
+    /// get there without cutting any corners
+    pub fn manhattan(&self, other: &Point) -> usize {
+        let (Point(x1, y1), Point(x2, y2)) = (self, other);
+        x1.abs_diff(*x2) + y1.abs_diff(*y2)
+    }
+
+    /// The Chebyshev distance to another point, as in, how many steps it takes to
+    /// get there if diagonal steps are allowed
+    pub fn chebyshev(&self, other: &Point) -> usize {
+        let (Point(x1, y1), Point(x2, y2)) = (self, other);
+        x1.abs_diff(*x2).max(y1.abs_diff(*y2))
+    }
+
+    /// Wrap this point around the edges of a `rows` by `cols` rectangle with its
+    /// top left corner at (0, 0), like walking off one side and coming back on the
+    /// other
+    pub fn wrap(self, rows: usize, cols: usize) -> Point {
+        let Point(x, y) = self;
+        Point(x.rem_euclid(cols as isize), y.rem_euclid(rows as isize))
+    }
+}
+
+impl Add<Offset> for Point {
+    type Output = Point;
+
+    fn add(self, rhs: Offset) -> Self::Output {
+        let (Point(x, y), Offset(dx, dy)) = (self, rhs);
+        Point(x + dx, y + dy)
+    }
+}
+
+impl AddAssign<Offset> for Point {
+    fn add_assign(&mut self, rhs: Offset) {
+        *self = *self + rhs;
+    }
+}
+
+impl Sub<Offset> for Point {
+    type Output = Point;
+
+    fn sub(self, rhs: Offset) -> Self::Output {
+        self + -rhs
+    }
+}
+
+/// The offset it takes to get from `rhs` to `self`
+impl Sub<Point> for Point {
+    type Output = Offset;
+
+    fn sub(self, rhs: Point) -> Self::Output {
+        let (Point(x1, y1), Point(x2, y2)) = (self, rhs);
+        Offset(x1 - x2, y1 - y2)
+    }
+}
+
+impl Add<Offset> for Offset {
+    type Output = Offset;
+
+    fn add(self, rhs: Offset) -> Self::Output {
+        let (Offset(x1, y1), Offset(x2, y2)) = (self, rhs);
+        Offset(x1 + x2, y1 + y2)
+    }
+}
+
+impl Mul<isize> for Offset {
+    type Output = Offset;
+
+    fn mul(self, rhs: isize) -> Self::Output {
+        let Offset(x, y) = self;
+        Offset(x * rhs, y * rhs)
+    }
+}
+
+impl Neg for Offset {
+    type Output = Offset;
+
+    fn neg(self) -> Self::Output {
+        let Offset(x, y) = self;
+        Offset(-x, -y)
+    }
+}
+
+/// A rectangle of things, stored row by row in one big vector
+#[derive(Debug, Default, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    rows: usize,
+    cols: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    /// A `rows` by `cols` grid with `fill` in every cell
+    pub fn new(rows: usize, cols: usize, fill: T) -> Self
+    where
+        T: Clone,
+    {
+        let cells = vec![fill; rows * cols];
+        Grid { rows, cols, cells }
+    }
+
+    /// Build a grid out of rows that might not all be the same length, padding the
+    /// short ones out with `fill`
+    pub fn from_rows(rows: impl IntoIterator<Item = Vec<T>>, fill: T) -> Self
+    where
+        T: Clone,
+    {
+        let rows: Vec<_> = rows.into_iter().collect();
+        let cols = rows.iter().map(|row| row.len()).max().unwrap_or_default();
+        let mut cells = Vec::with_capacity(rows.len() * cols);
+        for mut row in rows.iter().cloned() {
+            row.resize(cols, fill.clone());
+            cells.extend(row);
+        }
+        let rows = rows.len();
+        Grid { rows, cols, cells }
+    }
+
+    /// Parse a map drawn with characters, one row per line, using `cell` to turn
+    /// each character into a cell. If `cell` doesn't like a character, it gives
+    /// back the reason why, and that becomes the parse error.
+    pub fn parse(
+        text: &str,
+        mut cell: impl FnMut(char) -> std::result::Result<T, &'static str>,
+    ) -> Result<Self> {
+        let Some(first) = text.lines().next() else {
+            return Err(Error::parse(text, text, "the map is empty"));
+        };
+        let cols = first.chars().count();
+        let mut cells = Vec::with_capacity(text.len());
+        let mut rows = 0;
+        for line in text.lines() {
+            if line.chars().count() != cols {
+                let reason = "rows of the map must all be the same length";
+                return Err(Error::parse(text, line, reason));
+            }
+            for (idx, glyph) in line.char_indices() {
+                let value = cell(glyph).map_err(|e| Error::parse(text, &line[idx..], e))?;
+                cells.push(value);
+            }
+            rows += 1;
+        }
+        Ok(Grid { rows, cols, cells })
+    }
+
+    /// Draw the grid back out as text, one line per row, using `glyph` to pick
+    /// the character for each cell
+    pub fn render(&self, glyph: impl Fn(&T) -> char) -> String {
+        let mut text = String::with_capacity(self.rows * (self.cols + 1));
+        for row in self.cells.chunks(self.cols.max(1)).take(self.rows) {
+            text.extend(row.iter().map(&glyph));
+            text.push('\n');
+        }
+        text
+    }
+
+    /// The number of rows in the grid
+    pub fn rows(&self) -> usize {
+        self.rows
+    }
+
+    /// The number of columns in the grid
+    pub fn cols(&self) -> usize {
+        self.cols
+    }
+
+    /// One row of the grid, from left to right
+    pub fn row(&self, row: usize) -> &[T] {
+        &self.cells[row * self.cols..(row + 1) * self.cols]
+    }
+
+    /// Check whether a point is inside the grid
+    pub fn contains(&self, point: Point) -> bool {
+        let Point(x, y) = point;
+        (0..self.cols as isize).contains(&x) && (0..self.rows as isize).contains(&y)
+    }
+
+    /// Where a point's cell lives in the big vector, if the point is in the grid
+    fn idx(&self, point: Point) -> Option<usize> {
+        let Point(x, y) = point;
+        match self.contains(point) {
+            true => Some(y as usize * self.cols + x as usize),
+            false => None,
+        }
+    }
+
+    /// The cell at a point, if the point is in the grid
+    pub fn get(&self, point: Point) -> Option<&T> {
+        self.idx(point).map(|idx| &self.cells[idx])
+    }
+
+    /// The cell at a point, if the point is in the grid, for changing
+    pub fn get_mut(&mut self, point: Point) -> Option<&mut T> {
+        self.idx(point).map(|idx| &mut self.cells[idx])
+    }
+
+    /// Every point in the grid, row by row
+    pub fn points(&self) -> impl Iterator<Item = Point> {
+        let cols = self.cols as isize;
+        (0..self.rows as isize).flat_map(move |y| (0..cols).map(move |x| Point(x, y)))
+    }
+
+    /// Every point in the grid along with its cell, row by row
+    pub fn iter(&self) -> impl Iterator<Item = (Point, &T)> {
+        self.points().zip(self.cells.iter())
+    }
+
+    /// The first point (row by row) whose cell matches `predicate`
+    pub fn position(&self, predicate: impl Fn(&T) -> bool) -> Option<Point> {
+        self.iter()
+            .find(|(_, cell)| predicate(cell))
+            .map(|(point, _)| point)
+    }
+
+    /// The neighbors of a point that are still inside the grid, one for each of
+    /// the `offsets` (usually `Offset::ORTHOGONAL` or `Offset::ALL`)
+    pub fn neighbors<'a>(
+        &'a self,
+        point: Point,
+        offsets: &'a [Offset],
+    ) -> impl Iterator<Item = Point> + 'a {
+        offsets
+            .iter()
+            .map(move |offset| point + *offset)
+            .filter(|neighbor| self.contains(*neighbor))
+    }
+
+    /// The neighbors of a point, one for each of the `offsets`, where stepping off
+    /// one edge of the grid comes back around on the other
+    pub fn wrapping_neighbors<'a>(
+        &'a self,
+        point: Point,
+        offsets: &'a [Offset],
+    ) -> impl Iterator<Item = Point> + 'a {
+        offsets
+            .iter()
+            .map(move |offset| (point + *offset).wrap(self.rows, self.cols))
+    }
+
+    /// A grid the same shape as this one, with `f` applied to every cell
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        let cells = self.cells.iter().map(f).collect();
+        let (rows, cols) = (self.rows, self.cols);
+        Grid { rows, cols, cells }
+    }
+}
+
+/// Index by point. Panics if the point is outside the grid.
+impl<T> Index<Point> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, point: Point) -> &Self::Output {
+        match self.get(point) {
+            Some(cell) => cell,
+            None => panic!("{point:?} is outside the grid!"),
+        }
+    }
+}
+
+impl<T> IndexMut<Point> for Grid<T> {
+    fn index_mut(&mut self, point: Point) -> &mut Self::Output {
+        match self.get_mut(point) {
+            Some(cell) => cell,
+            None => panic!("{point:?} is outside the grid!"),
+        }
+    }
+}
+
+/// Index by (row, col), for looping over rows and columns
+impl<T> Index<(usize, usize)> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, (row, col): (usize, usize)) -> &Self::Output {
+        assert!(col < self.cols, "column {col} is outside the grid!");
+        &self.cells[row * self.cols + col]
+    }
+}
+
+impl<T> IndexMut<(usize, usize)> for Grid<T> {
+    fn index_mut(&mut self, (row, col): (usize, usize)) -> &mut Self::Output {
+        assert!(col < self.cols, "column {col} is outside the grid!");
+        &mut self.cells[row * self.cols + col]
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    /// Parse a map of rock ('#') and open ground ('.')
+    fn rocks(text: &str) -> Result<Grid<bool>> {
+        Grid::parse(text, |glyph| match glyph {
+            '#' => Ok(true),
+            '.' => Ok(false),
+            _ => Err("expected '#' or '.'"),
+        })
+    }
+
+    #[test]
+    fn check_parse_and_render() {
+        let text = "#..\n.#.\n..#\n#.#\n";
+        let grid = rocks(text).unwrap();
+        assert_eq!((grid.rows(), grid.cols()), (4, 3));
+        assert!(grid[Point(2, 3)]);
+        assert!(!grid[(3, 1)]);
+        assert_eq!(grid.row(1), [false, true, false]);
+        assert_eq!(grid.get(Point(3, 0)), None);
+        assert_eq!(grid.position(|rock| *rock), Some(Point(0, 0)));
+        assert_eq!(grid.render(|rock| if *rock { '#' } else { '.' }), text);
+
+        let error = rocks("#..\n.x.\n").unwrap_err().to_string();
+        assert!(error.contains("line 2, column 2"), "{error}");
+        assert!(error.contains("expected '#' or '.'"), "{error}");
+        assert!(rocks("#..\n.#\n").is_err());
+        assert!(rocks("").is_err());
+    }
+
+    #[test]
+    fn check_neighbors() {
+        let grid = Grid::new(3, 4, 0u8);
+        let corner: Vec<_> = grid.neighbors(Point(0, 0), &Offset::ORTHOGONAL).collect();
+        assert_eq!(corner, [Point(1, 0), Point(0, 1)]);
+        let middle = grid.neighbors(Point(1, 1), &Offset::ALL).count();
+        assert_eq!(middle, 8);
+
+        let wrapped: Vec<_> = grid
+            .wrapping_neighbors(Point(0, 0), &Offset::ORTHOGONAL)
+            .collect();
+        assert_eq!(
+            wrapped,
+            [Point(0, 2), Point(1, 0), Point(0, 1), Point(3, 0)]
+        );
+    }
+
+    #[test]
+    fn check_points() {
+        let (a, b) = (Point(1, -2), Point(-3, 4));
+        assert_eq!(a.manhattan(&b), 10);
+        assert_eq!(a.chebyshev(&b), 6);
+        assert_eq!(b - a, Offset(-4, 6));
+        assert_eq!((b - a).signum(), Offset(-1, 1));
+        assert_eq!(a + Offset::DOWN * 2, Point(1, 0));
+        assert_eq!(a - Offset::LEFT, Point(2, -2));
+        assert_eq!(Point(-1, 7).wrap(3, 4), Point(3, 1));
+
+        let grid = Grid::from_rows([vec![1, 2, 3], vec![4]], 0);
+        assert_eq!(
+            grid.render(|n| char::from_digit(*n, 10).unwrap()),
+            "123\n400\n"
+        );
+        assert_eq!(grid.map(|n| n * 2)[Point(0, 1)], 8);
+    }
+}
@@ -0,0 +1,348 @@
+//! Intervals of integers, and sets of them. Day 4 compares the sections two Elves
+//! were assigned and Day 15 works out how much of a row the sensors can see, and
+//! they each used to have their own little range type to do it. This is the one
+//! they share.
+//!
+//! Intervals include both ends, the way both puzzles describe them, so `2-4` is the
+//! sections 2, 3, and 4. An interval whose start is past its end is empty.
+use std::fmt::Debug;
+
+/// The integer types an `Interval` can be made of
+pub trait Endpoint: Copy + Ord + Debug {
+    /// The next value up, if there is one
+    fn successor(self) -> Option<Self>;
+
+    /// The next value down, if there is one
+    fn predecessor(self) -> Option<Self>;
+
+    /// The number of values from `self` up to `other`, both included, or zero if
+    /// `other` is less than `self`. Saturates at `u64::MAX`, which only matters for
+    /// an interval covering every single 64-bit integer.
+    fn count_to(self, other: Self) -> u64;
+}
+
+macro_rules! impl_endpoint {
+    ( $( $t:ty ),* ) => {
+        $(
+            impl Endpoint for $t {
+                fn successor(self) -> Option<Self> {
+                    self.checked_add(1)
+                }
+
+                fn predecessor(self) -> Option<Self> {
+                    self.checked_sub(1)
+                }
+
+                fn count_to(self, other: Self) -> u64 {
+                    if other < self {
+                        return 0;
+                    }
+                    u64::try_from(self.abs_diff(other))
+                        .unwrap_or(u64::MAX)
+                        .saturating_add(1)
+                }
+            }
+        )*
+    };
+}
+
+impl_endpoint!(u8, u16, u32, u64, usize, i8, i16, i32, i64, isize);
+
+/// The integers from `start` to `end`, both included
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Interval<T> {
+    pub start: T,
+    pub end: T,
+}
+
+/// Convert a pair of integers to an `Interval`
+impl<T> From<(T, T)> for Interval<T> {
+    fn from((start, end): (T, T)) -> Self {
+        Interval { start, end }
+    }
+}
+
+impl<T: Endpoint> Interval<T> {
+    pub fn new(start: T, end: T) -> Self {
+        Interval { start, end }
+    }
+
+    /// Check whether there's nothing in the interval
+    pub fn is_empty(&self) -> bool {
+        self.start > self.end
+    }
+
+    /// The number of integers in the interval
+    pub fn len(&self) -> u64 {
+        self.start.count_to(self.end)
+    }
+
+    /// Check whether `value` is in the interval
+    pub fn contains_value(&self, value: T) -> bool {
+        self.start <= value && value <= self.end
+    }
+
+    /// Check whether this interval completely contains `other`. Everything contains
+    /// an empty interval.
+    pub fn contains(&self, other: &Self) -> bool {
+        other.is_empty() || (self.start <= other.start && self.end >= other.end)
+    }
+
+    /// Check whether this interval and `other` have anything in common
+    pub fn overlaps(&self, other: &Self) -> bool {
+        self.intersection(other).is_some()
+    }
+
+    /// Check whether this interval and `other` overlap or sit right next to each
+    /// other, so that together they make one interval with no gap in it
+    pub fn touches(&self, other: &Self) -> bool {
+        !self.is_empty()
+            && !other.is_empty()
+            && reaches(self.end, other.start)
+            && reaches(other.end, self.start)
+    }
+
+    /// The part of this interval that's also in `other`, if there is any
+    pub fn intersection(&self, other: &Self) -> Option<Self> {
+        let overlap = Interval::new(self.start.max(other.start), self.end.min(other.end));
+        (!overlap.is_empty()).then_some(overlap)
+    }
+
+    /// The smallest interval that covers both this interval and `other`
+    pub fn hull(&self, other: &Self) -> Self {
+        match (self.is_empty(), other.is_empty()) {
+            (true, _) => *other,
+            (_, true) => *self,
+            _ => Interval::new(self.start.min(other.start), self.end.max(other.end)),
+        }
+    }
+}
+
+/// Check whether an interval that ends at `end` and one that starts at `start`
+/// leave no gap between them
+fn reaches<T: Endpoint>(end: T, start: T) -> bool {
+    match end.successor() {
+        Some(next) => start <= next,
+        None => true,
+    }
+}
+
+/// A set of integers, kept as a list of intervals sorted by where they start. Any
+/// intervals that overlap or touch get merged as they're added, so no two intervals
+/// in the list ever overlap or touch, and there are no empty ones.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct IntervalSet<T> {
+    intervals: Vec<Interval<T>>,
+}
+
+impl<T> Default for IntervalSet<T> {
+    fn default() -> Self {
+        IntervalSet {
+            intervals: Vec::new(),
+        }
+    }
+}
+
+impl<T: Endpoint> IntervalSet<T> {
+    /// An empty set
+    pub fn new() -> Self {
+        IntervalSet::default()
+    }
+
+    /// Add every integer in `interval` to the set, merging it with any intervals it
+    /// overlaps or touches
+    pub fn insert(&mut self, interval: Interval<T>) {
+        if interval.is_empty() {
+            return;
+        }
+
+        // The intervals it touches all sit together in the list, starting with the
+        // first one that doesn't end too far to the left of it and ending before
+        // the first one that starts too far to the right of it
+        let first = self
+            .intervals
+            .partition_point(|other| !reaches(other.end, interval.start));
+        let last = self
+            .intervals
+            .partition_point(|other| reaches(interval.end, other.start));
+        let merged = self.intervals[first..last]
+            .iter()
+            .fold(interval, |merged, other| merged.hull(other));
+        self.intervals.splice(first..last, [merged]);
+    }
+
+    /// The intervals in the set, from left to right
+    pub fn intervals(&self) -> &[Interval<T>] {
+        &self.intervals
+    }
+
+    /// The intervals in the set, from left to right
+    pub fn iter(&self) -> impl Iterator<Item = &Interval<T>> {
+        self.intervals.iter()
+    }
+
+    /// Check whether there's nothing in the set
+    pub fn is_empty(&self) -> bool {
+        self.intervals.is_empty()
+    }
+
+    /// The number of integers in the set. Saturates at `u64::MAX`, like `Interval::len`.
+    pub fn len(&self) -> u64 {
+        self.intervals
+            .iter()
+            .fold(0u64, |total, interval| total.saturating_add(interval.len()))
+    }
+
+    /// Check whether `value` is in the set
+    pub fn contains_value(&self, value: T) -> bool {
+        let idx = self
+            .intervals
+            .partition_point(|interval| interval.end < value);
+        self.intervals
+            .get(idx)
+            .is_some_and(|interval| interval.contains_value(value))
+    }
+
+    /// Everything in either this set or `other`
+    pub fn union(&self, other: &Self) -> Self {
+        let mut union = self.clone();
+        other.iter().for_each(|interval| union.insert(*interval));
+        union
+    }
+
+    /// Everything in both this set and `other`. Walks along both lists at once,
+    /// moving past whichever interval ends first.
+    pub fn intersection(&self, other: &Self) -> Self {
+        let mut intervals = Vec::new();
+        let (mut left, mut right) = (
+            self.intervals.iter().peekable(),
+            other.intervals.iter().peekable(),
+        );
+        while let (Some(a), Some(b)) = (left.peek(), right.peek()) {
+            intervals.extend(a.intersection(b));
+            match a.end < b.end {
+                true => left.next(),
+                false => right.next(),
+            };
+        }
+
+        // Intersecting intervals that don't touch can't make intervals that touch,
+        // so these are already sorted and merged
+        IntervalSet { intervals }
+    }
+
+    /// The runs of integers in `within` that aren't in the set, from left to right
+    pub fn gaps(&self, within: Interval<T>) -> Self {
+        let mut intervals = Vec::new();
+        if within.is_empty() {
+            return IntervalSet { intervals };
+        }
+
+        // Everything left of `next` has been covered or counted as a gap, and there's
+        // nothing left to check once `next` runs off the end of the integer type
+        let mut next = Some(within.start);
+        for interval in self
+            .intervals
+            .iter()
+            .filter_map(|i| i.intersection(&within))
+        {
+            let Some(from) = next else { break };
+            if let Some(before) = interval
+                .start
+                .predecessor()
+                .filter(|_| from < interval.start)
+            {
+                intervals.push(Interval::new(from, before));
+            }
+            next = interval.end.successor();
+        }
+        if let Some(from) = next.filter(|from| *from <= within.end) {
+            intervals.push(Interval::new(from, within.end));
+        }
+        IntervalSet { intervals }
+    }
+}
+
+impl<T: Endpoint> FromIterator<Interval<T>> for IntervalSet<T> {
+    fn from_iter<I: IntoIterator<Item = Interval<T>>>(iter: I) -> Self {
+        let mut set = IntervalSet::new();
+        set.extend(iter);
+        set
+    }
+}
+
+impl<T: Endpoint> Extend<Interval<T>> for IntervalSet<T> {
+    /// Sort everything first, so most new intervals either merge with the last one
+    /// or go on the end
+    fn extend<I: IntoIterator<Item = Interval<T>>>(&mut self, iter: I) {
+        let mut added: Vec<_> = iter.into_iter().collect();
+        added.sort_unstable();
+        for interval in added {
+            match self.intervals.last() {
+                Some(last) if reaches(last.end, interval.start) => self.insert(interval),
+                _ if interval.is_empty() => continue,
+                _ => self.intervals.push(interval),
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn set(intervals: &[(i32, i32)]) -> IntervalSet<i32> {
+        intervals.iter().copied().map(Interval::from).collect()
+    }
+
+    fn pairs(set: &IntervalSet<i32>) -> Vec<(i32, i32)> {
+        set.iter().map(|i| (i.start, i.end)).collect()
+    }
+
+    #[test]
+    fn check_intervals() {
+        let a = Interval::new(2u8, 6);
+        let b = Interval::new(4u8, 8);
+        assert_eq!(a.len(), 5);
+        assert!(a.overlaps(&b) && !a.contains(&b));
+        assert!(Interval::new(2u8, 8).contains(&a));
+        assert_eq!(a.intersection(&b), Some(Interval::new(4, 6)));
+        assert_eq!(a.intersection(&Interval::new(7, 9)), None);
+        assert!(a.touches(&Interval::new(7, 9)) && !a.overlaps(&Interval::new(7, 9)));
+        assert!(Interval::new(5u8, 4).is_empty());
+        assert_eq!(Interval::new(u8::MIN, u8::MAX).len(), 256);
+        assert_eq!(Interval::new(i64::MIN, i64::MAX).len(), u64::MAX);
+    }
+
+    #[test]
+    fn check_interval_sets() {
+        let mut covered = set(&[(10, 12), (1, 3), (5, 5), (4, 4), (20, 30)]);
+        assert_eq!(pairs(&covered), [(1, 5), (10, 12), (20, 30)]);
+        assert_eq!(covered.len(), 19);
+
+        covered.insert(Interval::new(11, 21));
+        assert_eq!(pairs(&covered), [(1, 5), (10, 30)]);
+        covered.insert(Interval::new(7, 7));
+        assert_eq!(pairs(&covered), [(1, 5), (7, 7), (10, 30)]);
+        assert!(covered.contains_value(7) && !covered.contains_value(8));
+
+        let other = set(&[(0, 1), (5, 11), (29, 40)]);
+        assert_eq!(pairs(&covered.union(&other)), [(0, 40)]);
+        assert_eq!(
+            pairs(&covered.intersection(&other)),
+            [(1, 1), (5, 5), (7, 7), (10, 11), (29, 30)]
+        );
+        assert_eq!(
+            pairs(&covered.gaps(Interval::new(0, 35))),
+            [(0, 0), (6, 6), (8, 9), (31, 35)]
+        );
+        assert_eq!(pairs(&covered.gaps(Interval::new(12, 20))), []);
+
+        // Gaps all the way to the edges of the integer type
+        let edges: IntervalSet<u8> = [Interval::new(0, 9), Interval::new(250, 255)]
+            .into_iter()
+            .collect();
+        let gaps: Vec<_> = edges.gaps(Interval::new(0, 255)).iter().copied().collect();
+        assert_eq!(gaps, [Interval::new(10, 249)]);
+    }
+}
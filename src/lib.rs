@@ -1,8 +1,9 @@
 #![feature(iter_intersperse)]
 #![allow(unused)]
-use std::cmp::PartialEq;
-use std::fmt::{Display, Formatter, Result as DisplayResult};
+use std::borrow::Cow;
+use std::path::Path;
 
+pub mod answers;
 pub mod day01;
 pub mod day02;
 pub mod day03;
@@ -28,66 +29,68 @@ pub mod day22;
 pub mod day23;
 pub mod day24;
 pub mod day25;
+pub mod error;
+#[cfg(test)]
+mod examples;
+pub mod fetch;
+pub mod grid;
+pub mod interval;
+pub mod output;
+pub mod random;
+pub mod search;
+pub mod solution;
+pub mod submit;
+pub mod visualize;
+
+pub use answers::{Answers, Check};
+pub use error::{Error, Result};
+pub use output::Output;
+pub use solution::{puzzle, puzzles, Answered, Puzzle, Solution, Timings};
 
 // Used as a flag to indicate which part of a day to run.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum Part {
     One,
     Two,
 }
 
-macro_rules! impl_output_from {
-    ( $( ($e:tt, $t:ty) ),* ) => {
-        #[derive(Debug, Eq)]
-        pub enum Output {
-            $( $e($t), )*
-        }
-
-        $(
-            impl From<$t> for Output {
-                fn from(value: $t) -> Self {
-                    Output::$e(value)
-                }
-            }
-        )*
-    };
-}
-
-impl_output_from! {
-    (U8,     u8),
-    (U16,    u16),
-    (U32,    u32),
-    (U64,    u64),
-    (U128,   u128),
-    (I8,     i8),
-    (I16,    i16),
-    (I32,    i32),
-    (I64,    i64),
-    (I128,   i128),
-    (String, String)
+/// Indicates where a day should get its puzzle input from. Each day still bundles
+/// my input file at compile time, but any other input can be passed in as text or
+/// read from a file at runtime, so the library can solve anyone's puzzle input.
+#[derive(Debug, Default, Clone, Copy)]
+pub enum Source<'a> {
+    #[default]
+    Bundled,
+    Text(&'a str),
+    Path(&'a Path),
 }
 
-impl Display for Output {
-    fn fmt(&self, f: &mut Formatter<'_>) -> DisplayResult {
+impl<'a> Source<'a> {
+    /// Produce the text of the puzzle input. The `bundled` text is provided by each
+    /// day and is only used for `Source::Bundled`.
+    pub fn text(&self, bundled: &'static str) -> Result<Cow<'a, str>> {
         match self {
-            Output::U8(v) => write!(f, "{v}"),
-            Output::U16(v) => write!(f, "{v}"),
-            Output::U32(v) => write!(f, "{v}"),
-            Output::U64(v) => write!(f, "{v}"),
-            Output::U128(v) => write!(f, "{v}"),
-            Output::I8(v) => write!(f, "{v}"),
-            Output::I16(v) => write!(f, "{v}"),
-            Output::I32(v) => write!(f, "{v}"),
-            Output::I64(v) => write!(f, "{v}"),
-            Output::I128(v) => write!(f, "{v}"),
-            Output::String(v) => write!(f, "{v}"),
+            Source::Bundled => Ok(Cow::Borrowed(bundled)),
+            Source::Text(text) => Ok(Cow::Borrowed(text)),
+            Source::Path(path) => match std::fs::read_to_string(path) {
+                Ok(text) => Ok(Cow::Owned(text)),
+                Err(error) => Err(Error::Io {
+                    path: path.to_path_buf(),
+                    error,
+                }),
+            },
         }
     }
 }
 
-/// Consider an output equal to any value where they can both be
-/// coerced to the same string
-impl<T: Display> PartialEq<T> for Output {
-    fn eq(&self, other: &T) -> bool {
-        *self.to_string() == other.to_string()
+impl<'a> From<&'a str> for Source<'a> {
+    fn from(text: &'a str) -> Self {
+        Source::Text(text)
+    }
+}
+
+impl<'a> From<&'a Path> for Source<'a> {
+    fn from(path: &'a Path) -> Self {
+        Source::Path(path)
     }
 }
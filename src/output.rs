@@ -0,0 +1,194 @@
+//! `Output` is what every day's solver returns, so that the binary can expect the same
+//! (printable) type from each of them. Most answers are a single number or a string,
+//! but there are variants for grids, lists, and tuples too, so that structured answers
+//! don't need to be flattened into text before they're returned.
+use std::cmp::PartialEq;
+use std::fmt::{Display, Formatter, Result as DisplayResult};
+
+/// Generates the `Output` enum with a variant for each type listed, along with a `From`
+/// implementation for each of those types. Variants listed after the `;` don't get a
+/// `From` implementation, usually because their type is already taken.
+macro_rules! impl_output_from {
+    ( $( ($e:tt, $t:ty) ),* ; $( ($e2:tt, $t2:ty) ),* ) => {
+        #[derive(Debug, Eq)]
+        pub enum Output {
+            $( $e($t), )*
+            $( $e2($t2), )*
+        }
+
+        $(
+            impl From<$t> for Output {
+                fn from(value: $t) -> Self {
+                    Output::$e(value)
+                }
+            }
+        )*
+
+        impl Output {
+            /// The name of the variant holding the answer, like "U32" or "String"
+            pub fn kind(&self) -> &'static str {
+                match self {
+                    $( Output::$e(_) => stringify!($e), )*
+                    $( Output::$e2(_) => stringify!($e2), )*
+                }
+            }
+        }
+    };
+}
+
+impl_output_from! {
+    (U8,       u8),
+    (U16,      u16),
+    (U32,      u32),
+    (U64,      u64),
+    (U128,     u128),
+    (I8,       i8),
+    (I16,      i16),
+    (I32,      i32),
+    (I64,      i64),
+    (I128,     i128),
+    (String,   String),
+    (BoolGrid, Vec<Vec<bool>>),
+    (CharGrid, Vec<Vec<char>>),
+    (List,     Vec<Output>);
+    (Tuple,    Vec<Output>)
+}
+
+impl<A: Into<Output>, B: Into<Output>> From<(A, B)> for Output {
+    fn from((a, b): (A, B)) -> Self {
+        Output::Tuple(vec![a.into(), b.into()])
+    }
+}
+
+impl<A: Into<Output>, B: Into<Output>, C: Into<Output>> From<(A, B, C)> for Output {
+    fn from((a, b, c): (A, B, C)) -> Self {
+        Output::Tuple(vec![a.into(), b.into(), c.into()])
+    }
+}
+
+impl Output {
+    /// Produce an `Output::List` from anything that can be turned into outputs
+    pub fn list<T: Into<Output>>(items: impl IntoIterator<Item = T>) -> Self {
+        Output::List(items.into_iter().map(Into::into).collect())
+    }
+
+    /// Render the answer as JSON. Numbers become JSON numbers and strings become
+    /// JSON strings. Grids, lists, and tuples keep their structure as (nested)
+    /// arrays, with each cell of a `CharGrid` as a one-character string.
+    pub fn to_json(&self) -> String {
+        let array = |items: Vec<String>| format!("[{}]", items.join(", "));
+        match self {
+            Output::String(text) => json_string(text),
+            Output::BoolGrid(rows) => array(
+                rows.iter()
+                    .map(|row| array(row.iter().map(bool::to_string).collect()))
+                    .collect(),
+            ),
+            Output::CharGrid(rows) => array(
+                rows.iter()
+                    .map(|row| array(row.iter().map(|c| json_string(&c.to_string())).collect()))
+                    .collect(),
+            ),
+            Output::List(items) | Output::Tuple(items) => {
+                array(items.iter().map(Output::to_json).collect())
+            }
+            number => number.to_string(),
+        }
+    }
+}
+
+/// Quote and escape text for JSON, so that multi-line answers survive the trip
+fn json_string(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len() + 2);
+    escaped.push('"');
+    for ch in text.chars() {
+        match ch {
+            '"' => escaped.push_str("\\\""),
+            '\\' => escaped.push_str("\\\\"),
+            '\n' => escaped.push_str("\\n"),
+            '\r' => escaped.push_str("\\r"),
+            '\t' => escaped.push_str("\\t"),
+            c if c.is_control() => escaped.push_str(&format!("\\u{:04x}", c as u32)),
+            c => escaped.push(c),
+        }
+    }
+    escaped.push('"');
+    escaped
+}
+
+/// Grids are shown one row per line. Lit cells in a `BoolGrid` are shown as '#' and
+/// unlit cells as ' ', which is how the Day 10 CRT draws letters. Lists are shown
+/// like `[1, 2, 3]` and tuples like `(1, 2)`.
+impl Display for Output {
+    fn fmt(&self, f: &mut Formatter<'_>) -> DisplayResult {
+        match self {
+            Output::U8(v) => write!(f, "{v}"),
+            Output::U16(v) => write!(f, "{v}"),
+            Output::U32(v) => write!(f, "{v}"),
+            Output::U64(v) => write!(f, "{v}"),
+            Output::U128(v) => write!(f, "{v}"),
+            Output::I8(v) => write!(f, "{v}"),
+            Output::I16(v) => write!(f, "{v}"),
+            Output::I32(v) => write!(f, "{v}"),
+            Output::I64(v) => write!(f, "{v}"),
+            Output::I128(v) => write!(f, "{v}"),
+            Output::String(v) => write!(f, "{v}"),
+            Output::BoolGrid(rows) => {
+                let glyph = |lit: &bool| if *lit { '#' } else { ' ' };
+                let rows: Vec<String> =
+                    rows.iter().map(|r| r.iter().map(glyph).collect()).collect();
+                write!(f, "{}", rows.join("\n"))
+            }
+            Output::CharGrid(rows) => {
+                let rows: Vec<String> = rows.iter().map(|r| r.iter().collect()).collect();
+                write!(f, "{}", rows.join("\n"))
+            }
+            Output::List(items) => {
+                let items: Vec<_> = items.iter().map(Output::to_string).collect();
+                write!(f, "[{}]", items.join(", "))
+            }
+            Output::Tuple(items) => {
+                let items: Vec<_> = items.iter().map(Output::to_string).collect();
+                write!(f, "({})", items.join(", "))
+            }
+        }
+    }
+}
+
+/// Consider an output equal to any value where they can both be
+/// coerced to the same string
+impl<T: Display> PartialEq<T> for Output {
+    fn eq(&self, other: &T) -> bool {
+        *self.to_string() == other.to_string()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn check_structured_outputs() {
+        let grid = Output::from(vec![vec![true, false], vec![false, true]]);
+        assert_eq!(grid, "# \n #");
+        assert_eq!(grid.to_json(), "[[true, false], [false, true]]");
+        assert_eq!(grid.kind(), "BoolGrid");
+
+        let grid = Output::from(vec![vec!['a', '"'], vec!['.', '#']]);
+        assert_eq!(grid, "a\"\n.#");
+        assert_eq!(grid.to_json(), r##"[["a", "\""], [".", "#"]]"##);
+
+        let list = Output::list([3u32, 1, 2]);
+        assert_eq!(list, "[3, 1, 2]");
+        assert_eq!(list.to_json(), "[3, 1, 2]");
+
+        let tuple = Output::from((12i64, String::from("twelve")));
+        assert_eq!(tuple, "(12, twelve)");
+        assert_eq!(tuple.to_json(), r#"[12, "twelve"]"#);
+        assert_eq!(tuple.kind(), "Tuple");
+
+        let nested = Output::list([Output::from((1u8, 2u8)), Output::from((3u8, 4u8))]);
+        assert_eq!(nested, "[(1, 2), (3, 4)]");
+        assert_eq!(nested.to_json(), "[[1, 2], [3, 4]]");
+    }
+}
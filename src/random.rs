@@ -0,0 +1,151 @@
+//! A small, seedable random number generator for making up puzzle inputs. Each day
+//! with a `generate.rs` uses this to build random (but valid) inputs of whatever size
+//! we ask for, and the same seed always builds the same input. It's SplitMix64 under
+//! the hood, which is nowhere near good enough for cryptography but plenty good for
+//! coming up with monkeys and valves.
+use std::fmt::Debug;
+use std::ops::RangeInclusive;
+
+/// The random number generator. Cheap to make, so make a new one for each input.
+#[derive(Debug, Clone)]
+pub struct Rng(u64);
+
+impl Rng {
+    /// Create a new generator from a seed
+    pub fn new(seed: u64) -> Self {
+        Rng(seed)
+    }
+
+    /// Produce the next random u64
+    pub fn next_u64(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^ (z >> 31)
+    }
+
+    /// Produce a random number in the (inclusive) range, for any of the integer types
+    pub fn range<T>(&mut self, range: RangeInclusive<T>) -> T
+    where
+        T: Copy + TryInto<i128> + TryFrom<i128>,
+    {
+        let start = (*range.start()).try_into().ok().expect("range is too wide");
+        let end = (*range.end()).try_into().ok().expect("range is too wide");
+        assert!(start <= end, "can't pick a number from an empty range");
+        let span = (end - start) as u128 + 1;
+        let bits = (self.next_u64() as u128) << 64 | self.next_u64() as u128;
+        let value = start + (bits % span) as i128;
+        T::try_from(value).ok().unwrap()
+    }
+
+    /// Returns `true` with the given probability
+    pub fn chance(&mut self, probability: f64) -> bool {
+        let sample = (self.next_u64() >> 11) as f64 / (1u64 << 53) as f64;
+        sample < probability
+    }
+
+    /// Pick one of the items at random
+    pub fn pick<'a, T>(&mut self, items: &'a [T]) -> &'a T {
+        &items[self.range(0..=items.len() - 1)]
+    }
+
+    /// Shuffle the items in place
+    pub fn shuffle<T>(&mut self, items: &mut [T]) {
+        for idx in (1..items.len()).rev() {
+            items.swap(idx, self.range(0..=idx));
+        }
+    }
+}
+
+/// Generate inputs of a few different sizes from a few different seeds, print each one
+/// out, and check that parsing the text gives back exactly what was generated. Each
+/// input is parsed both with and without the trailing newline that puzzle inputs have.
+#[cfg(test)]
+pub(crate) fn check_round_trip<T, G, P, R>(generate: G, print: P, parse: R)
+where
+    T: PartialEq + Debug,
+    G: Fn(&mut Rng, usize) -> T,
+    P: Fn(&T) -> String,
+    R: Fn(&str) -> crate::Result<T>,
+{
+    for seed in 0..25 {
+        for size in [1, 2, 10, 100] {
+            let generated = generate(&mut Rng::new(seed), size);
+            let text = print(&generated);
+            for text in [text.as_str(), text.trim_end()] {
+                match parse(text) {
+                    Ok(parsed) => assert_eq!(parsed, generated, "seed {seed}, size {size}"),
+                    Err(e) => panic!("seed {seed}, size {size}: {e}\n{text}"),
+                }
+            }
+        }
+    }
+}
+
+/// Solve inputs of a few different sizes from a few different seeds with both an
+/// optimized solver and a slow reference solver that's obviously correct, and report
+/// every input they disagree on. A solver returning an error counts as disagreeing.
+#[cfg(test)]
+pub(crate) fn check_against_reference<T, A, G, S, R>(
+    generate: G,
+    sizes: &[usize],
+    solve: S,
+    reference: R,
+) where
+    A: PartialEq + Debug,
+    G: Fn(&mut Rng, usize) -> T,
+    S: Fn(&T) -> crate::Result<A>,
+    R: Fn(&T) -> crate::Result<A>,
+{
+    let mut disagreements = Vec::new();
+    for seed in 0..25 {
+        for size in sizes {
+            let input = generate(&mut Rng::new(seed), *size);
+            match (solve(&input), reference(&input)) {
+                (Ok(answer), Ok(expected)) if answer == expected => continue,
+                (answer, expected) => disagreements.push(format!(
+                    "seed {seed}, size {size}: solver gave {answer:?}, reference gave {expected:?}"
+                )),
+            }
+        }
+    }
+    assert!(
+        disagreements.is_empty(),
+        "{} of {} inputs disagreed:\n{}",
+        disagreements.len(),
+        25 * sizes.len(),
+        disagreements.join("\n")
+    );
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn check_rng() {
+        // The same seed makes the same numbers
+        let mut rng1 = Rng::new(2022);
+        let mut rng2 = Rng::new(2022);
+        for _ in 0..100 {
+            assert_eq!(rng1.next_u64(), rng2.next_u64());
+        }
+
+        // Ranges are inclusive, and every value in the range turns up
+        let mut seen = [false; 7];
+        for _ in 0..1000 {
+            let value = rng1.range(-3..=3i32);
+            seen[(value + 3) as usize] = true;
+        }
+        assert!(seen.iter().all(|x| *x));
+        assert_eq!(rng1.range(u64::MAX..=u64::MAX), u64::MAX);
+
+        // Shuffling keeps all the items
+        let mut items: Vec<_> = (0..50).collect();
+        rng1.shuffle(&mut items);
+        assert_ne!(items, (0..50).collect::<Vec<_>>());
+        items.sort();
+        assert_eq!(items, (0..50).collect::<Vec<_>>());
+    }
+}
@@ -0,0 +1,324 @@
+//! Graph searches for the pathfinding days. Each of them used to hand-roll its own
+//! Dijkstra's with a `BinaryHeap<(Reverse(_), _)>` and a `HashMap` of the best costs
+//! found so far, which is the same thirty lines every time. Here they are once.
+//!
+//! A search starts from a state and asks a `successors` function where it can go
+//! from each state it reaches (and what each step costs, for the weighted searches).
+//! States can be anything that can go in a `HashMap`: a point on a map, a point and
+//! a time, a whole factory full of robots, etc. Every search keeps track of how it got
+//! to each state, so the route to any state it reached can be pieced back together.
+use std::cmp::Reverse;
+use std::collections::hash_map::Entry;
+use std::collections::{BinaryHeap, HashMap, VecDeque};
+use std::hash::Hash;
+use std::ops::Add;
+
+/// Some numbers about how much work a search did, for comparing approaches
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct Stats {
+    /// How many states had their successors checked
+    pub expanded: usize,
+
+    /// How many times a new (or cheaper) way to a state turned up
+    pub discovered: usize,
+
+    /// The most states that were ever waiting to be expanded at once
+    pub max_frontier: usize,
+}
+
+/// Everything a search reached, with the cheapest cost it found to each state and
+/// the state it came from to get there. Every way to a state the search finds goes
+/// in `found`, pointing back at the way it found to the state before it, and `best`
+/// says which of those is the cheapest way to each state.
+#[derive(Debug, Clone)]
+pub struct Visited<S, C> {
+    found: Vec<(S, C, Option<usize>)>,
+    best: HashMap<S, usize>,
+    pub stats: Stats,
+}
+
+impl<S: Clone + Eq + Hash, C: Copy> Visited<S, C> {
+    fn new(start: S, cost: C) -> Self {
+        Visited {
+            found: vec![(start.clone(), cost, None)],
+            best: HashMap::from([(start, 0)]),
+            stats: Stats::default(),
+        }
+    }
+
+    /// Record a way to get to `state` from the way we found with index `parent`,
+    /// if it's the first one or it's cheaper than the last one. Returns the index
+    /// of the new way, if it was.
+    fn improve(&mut self, state: S, parent: usize, cost: C) -> Option<usize>
+    where
+        C: Ord,
+    {
+        let idx = self.found.len();
+        match self.best.entry(state.clone()) {
+            Entry::Occupied(entry) if self.found[*entry.get()].1 <= cost => return None,
+            Entry::Occupied(mut entry) => entry.insert(idx),
+            Entry::Vacant(entry) => *entry.insert(idx),
+        };
+        self.found.push((state, cost, Some(parent)));
+        self.stats.discovered += 1;
+        Some(idx)
+    }
+
+    /// Check whether the way we found with index `idx` is (still) the cheapest
+    fn is_best(&self, idx: usize) -> bool {
+        self.best.get(&self.found[idx].0) == Some(&idx)
+    }
+
+    /// The cheapest cost found to a state, if the search reached it
+    pub fn cost(&self, state: &S) -> Option<C> {
+        self.best.get(state).map(|idx| self.found[*idx].1)
+    }
+
+    /// Every state the search reached, with the cheapest cost it found to each
+    pub fn costs(&self) -> impl Iterator<Item = (&S, &C)> {
+        self.best
+            .iter()
+            .map(|(state, idx)| (state, &self.found[*idx].1))
+    }
+
+    /// The cheapest route the search found to a state, from the start to the state
+    /// (both included), if the search reached it
+    pub fn path_to(&self, state: &S) -> Option<Vec<S>> {
+        let mut path = Vec::new();
+        let mut next = self.best.get(state).copied();
+        while let Some(idx) = next {
+            let (state, _, parent) = &self.found[idx];
+            path.push(state.clone());
+            next = *parent;
+        }
+        path.reverse();
+        (!path.is_empty()).then_some(path)
+    }
+}
+
+/// A goal a search found, and how much it cost to get there
+#[derive(Debug, Clone)]
+pub struct Found<S, C> {
+    pub goal: S,
+    pub cost: C,
+    pub visited: Visited<S, C>,
+}
+
+impl<S: Clone + Eq + Hash, C: Copy> Found<S, C> {
+    /// The route from the start to the goal, both included
+    pub fn path(&self) -> Vec<S> {
+        self.visited.path_to(&self.goal).unwrap_or_default()
+    }
+}
+
+/// Breadth-first search from `start` to the first state where `is_goal` is true,
+/// for when every step costs the same. The cost is the number of steps.
+pub fn bfs<S, I>(
+    start: S,
+    mut successors: impl FnMut(&S) -> I,
+    mut is_goal: impl FnMut(&S) -> bool,
+) -> Option<Found<S, usize>>
+where
+    S: Clone + Eq + Hash,
+    I: IntoIterator<Item = S>,
+{
+    let mut visited = Visited::new(start.clone(), 0);
+    let mut queue = VecDeque::from([(start, 0, 0)]);
+    while let Some((state, steps, idx)) = queue.pop_front() {
+        if is_goal(&state) {
+            let (goal, cost) = (state, steps);
+            return Some(Found {
+                goal,
+                cost,
+                visited,
+            });
+        }
+        visited.stats.expanded += 1;
+        for next in successors(&state) {
+            // The first way to a state is always the shortest one, so any other
+            // way isn't an improvement
+            if let Some(next_idx) = visited.improve(next.clone(), idx, steps + 1) {
+                queue.push_back((next, steps + 1, next_idx));
+            }
+        }
+        visited.stats.max_frontier = visited.stats.max_frontier.max(queue.len());
+    }
+    None
+}
+
+/// Dijkstra's from `start` to the cheapest state where `is_goal` is true. The
+/// `successors` give each state you can get to along with what it costs to step
+/// there, which can't be negative.
+pub fn dijkstra<S, C, I>(
+    start: S,
+    successors: impl FnMut(&S) -> I,
+    is_goal: impl FnMut(&S) -> bool,
+) -> Option<Found<S, C>>
+where
+    S: Clone + Eq + Hash,
+    C: Copy + Ord + Default + Add<Output = C>,
+    I: IntoIterator<Item = (S, C)>,
+{
+    astar(start, successors, |_| C::default(), is_goal)
+}
+
+/// Dijkstra's from `start` to every state that can be reached from it
+pub fn reachable<S, C, I>(start: S, successors: impl FnMut(&S) -> I) -> Visited<S, C>
+where
+    S: Clone + Eq + Hash,
+    C: Copy + Ord + Default + Add<Output = C>,
+    I: IntoIterator<Item = (S, C)>,
+{
+    let mut visited = Visited::new(start.clone(), C::default());
+    run(start, successors, |_| C::default(), |_| false, &mut visited);
+    visited
+}
+
+/// A* from `start` to the cheapest state where `is_goal` is true. Like Dijkstra's,
+/// but the `heuristic` guesses what it'll cost to get from a state to a goal, so
+/// the search can check the most promising states first. As long as the guess is
+/// never more than the real cost, the first goal found is still the cheapest one.
+pub fn astar<S, C, I>(
+    start: S,
+    successors: impl FnMut(&S) -> I,
+    heuristic: impl FnMut(&S) -> C,
+    is_goal: impl FnMut(&S) -> bool,
+) -> Option<Found<S, C>>
+where
+    S: Clone + Eq + Hash,
+    C: Copy + Ord + Default + Add<Output = C>,
+    I: IntoIterator<Item = (S, C)>,
+{
+    let mut visited = Visited::new(start.clone(), C::default());
+    let (goal, cost) = run(start, successors, heuristic, is_goal, &mut visited)?;
+    Some(Found {
+        goal,
+        cost,
+        visited,
+    })
+}
+
+/// A* like `astar`, for when all you want is the goal and what it cost, not the
+/// route there or anything else the search found along the way
+pub fn astar_goal<S, C, I>(
+    start: S,
+    successors: impl FnMut(&S) -> I,
+    heuristic: impl FnMut(&S) -> C,
+    is_goal: impl FnMut(&S) -> bool,
+) -> Option<(S, C)>
+where
+    S: Clone + Eq + Hash,
+    C: Copy + Ord + Default + Add<Output = C>,
+    I: IntoIterator<Item = (S, C)>,
+{
+    let mut visited = Visited::new(start.clone(), C::default());
+    run(start, successors, heuristic, is_goal, &mut visited)
+}
+
+/// The search behind Dijkstra's and A*. The heap only holds the guessed total
+/// cost and the index of a way to a state in `visited`, so the states themselves
+/// don't need to be sortable. Ties go to whichever state was found last, which
+/// tends to be the one furthest along, so the search dives for a goal instead of
+/// spreading out.
+fn run<S, C, I>(
+    start: S,
+    mut successors: impl FnMut(&S) -> I,
+    mut heuristic: impl FnMut(&S) -> C,
+    mut is_goal: impl FnMut(&S) -> bool,
+    visited: &mut Visited<S, C>,
+) -> Option<(S, C)>
+where
+    S: Clone + Eq + Hash,
+    C: Copy + Ord + Default + Add<Output = C>,
+    I: IntoIterator<Item = (S, C)>,
+{
+    let mut open = BinaryHeap::from([(Reverse(heuristic(&start)), 0)]);
+    while let Some((_, idx)) = open.pop() {
+        // Skip the states we've found a cheaper way to since they were pushed
+        if !visited.is_best(idx) {
+            continue;
+        }
+        let (state, cost, _) = visited.found[idx].clone();
+        if is_goal(&state) {
+            return Some((state, cost));
+        }
+        visited.stats.expanded += 1;
+        for (next, step) in successors(&state) {
+            let next_cost = cost + step;
+            let guess = next_cost + heuristic(&next);
+            if let Some(next_idx) = visited.improve(next, idx, next_cost) {
+                open.push((Reverse(guess), next_idx));
+            }
+        }
+        visited.stats.max_frontier = visited.stats.max_frontier.max(open.len());
+    }
+    None
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::grid::{Grid, Offset, Point};
+
+    /// A little maze. Walls are '#', and the other digits are what it costs to
+    /// step onto that space.
+    const MAZE: &str = "\
+        1111#1\n\
+        1#9#11\n\
+        1#1111\n\
+        111#91\n";
+
+    fn maze() -> Grid<Option<u32>> {
+        Grid::parse(MAZE, |glyph| match glyph {
+            '#' => Ok(None),
+            _ => glyph.to_digit(10).map(Some).ok_or("not a digit"),
+        })
+        .unwrap()
+    }
+
+    /// Every open space next to `point`, with the cost to step there
+    fn steps(maze: &Grid<Option<u32>>, point: Point) -> Vec<(Point, u32)> {
+        maze.neighbors(point, &Offset::ORTHOGONAL)
+            .filter_map(|next| Some((next, maze[next]?)))
+            .collect()
+    }
+
+    #[test]
+    fn check_bfs() {
+        let maze = maze();
+        let (start, end) = (Point(0, 0), Point(5, 0));
+        let open = |point: &Point| steps(&maze, *point).into_iter().map(|(next, _)| next);
+        let found = bfs(start, open, |point| *point == end).unwrap();
+        assert_eq!(found.cost, 9);
+        let path = found.path();
+        assert_eq!(path.len(), 10);
+        assert_eq!((path[0], path[9]), (start, end));
+        assert!(path.windows(2).all(|pair| pair[0].manhattan(&pair[1]) == 1));
+        assert!(bfs(start, open, |point| *point == Point(4, 0)).is_none());
+    }
+
+    #[test]
+    fn check_dijkstra_and_astar() {
+        let maze = maze();
+        let (start, end) = (Point(0, 0), Point(5, 3));
+        let found = dijkstra(start, |point| steps(&maze, *point), |point| *point == end).unwrap();
+
+        // It's cheaper to go the long way around than through either of the 9s
+        assert_eq!(found.cost, 10);
+        assert!(found.path().iter().all(|point| maze[*point] == Some(1)));
+
+        let distance = |point: &Point| point.manhattan(&end) as u32;
+        let guided = astar(start, |p| steps(&maze, *p), distance, |p| *p == end).unwrap();
+        assert_eq!(guided.cost, 10);
+        assert_eq!(guided.path(), found.path());
+        assert!(guided.visited.stats.expanded <= found.visited.stats.expanded);
+        let goal = astar_goal(start, |p| steps(&maze, *p), distance, |p| *p == end);
+        assert_eq!(goal, Some((end, 10)));
+
+        let everywhere = reachable(start, |point| steps(&maze, *point));
+        assert_eq!(everywhere.costs().count(), 19);
+        assert_eq!(everywhere.cost(&Point(2, 1)), Some(11));
+        assert_eq!(everywhere.cost(&Point(4, 0)), None);
+        assert_eq!(everywhere.path_to(&start), Some(vec![start]));
+    }
+}
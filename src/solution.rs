@@ -0,0 +1,202 @@
+//! The `Solution` trait is implemented by a unit struct for each day (`day01::Day01`,
+//! `day02::Day02`, etc.), and the registry collects all of them so that the binary, the
+//! benches, and the tests can look up or loop over the days instead of each keeping
+//! their own big `match` on the day number.
+use crate::*;
+use std::time::{Duration, Instant};
+
+/// Describes the solution for a single day: which day it is, the input bundled for it,
+/// how to parse puzzle input text, and how to solve each part from the parsed input.
+pub trait Solution {
+    /// The day of the Advent Calendar this solution is for
+    const DAY: u8;
+
+    /// My puzzle input for this day, bundled at compile time
+    const INPUT: &'static str;
+
+    /// The type the input text is parsed into. It gets a lifetime so that days
+    /// can borrow from the input text when they parse it.
+    type Input<'a>;
+
+    /// Parse the text of a puzzle input
+    fn parse(text: &str) -> Result<Self::Input<'_>>;
+
+    /// Solve part one from the parsed input
+    fn part1(input: &Self::Input<'_>) -> Result<Output>;
+
+    /// Solve part two from the parsed input
+    fn part2(input: &Self::Input<'_>) -> Result<Output>;
+
+    /// Read the input from `source`, parse it, and solve for one `Part`
+    fn run(part: Part, source: Source) -> Result<Output> {
+        let text = source.text(Self::INPUT)?;
+        let input = Self::parse(&text)?;
+        match part {
+            Part::One => Self::part1(&input),
+            Part::Two => Self::part2(&input),
+        }
+    }
+
+    /// Read the input from `source`, parse it once, and solve both parts
+    fn run_both(source: Source) -> Result<(Output, Output)> {
+        let text = source.text(Self::INPUT)?;
+        let input = Self::parse(&text)?;
+        Ok((Self::part1(&input)?, Self::part2(&input)?))
+    }
+
+    /// Read the input from `source`, then parse it and solve each of the `parts`,
+    /// timing each of those phases on its own. Reading the input isn't timed. The
+    /// answers for parts that weren't asked for are `None`.
+    fn run_timed(parts: &[Part], source: Source) -> Result<Answered> {
+        let text = source.text(Self::INPUT)?;
+        let start = Instant::now();
+        let input = Self::parse(&text)?;
+        let mut timings = Timings {
+            parse: start.elapsed(),
+            ..Default::default()
+        };
+
+        let (mut answer_one, mut answer_two) = (None, None);
+        if parts.contains(&Part::One) {
+            let start = Instant::now();
+            answer_one = Some(Self::part1(&input)?);
+            timings.part_one = start.elapsed();
+        }
+        if parts.contains(&Part::Two) {
+            let start = Instant::now();
+            answer_two = Some(Self::part2(&input)?);
+            timings.part_two = start.elapsed();
+        }
+        Ok((answer_one, answer_two, timings))
+    }
+}
+
+/// The answers to part one and part two (if they were run), and how long it took
+pub type Answered = (Option<Output>, Option<Output>, Timings);
+
+/// How long each phase of solving a day took, split up the same way as the benches.
+/// Parts that weren't run took no time at all.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct Timings {
+    pub parse: Duration,
+    pub part_one: Duration,
+    pub part_two: Duration,
+}
+
+impl Timings {
+    /// The time taken by all three phases together
+    pub fn total(&self) -> Duration {
+        self.parse + self.part_one + self.part_two
+    }
+}
+
+/// Since each `Solution` has its own `Input` type, they can't be stored together
+/// as trait objects. `Puzzle` is the object-safe face of a `Solution` that the
+/// registry hands out instead, and it's implemented for every `Solution`.
+pub trait Puzzle: Sync {
+    /// The day of the Advent Calendar this puzzle is for
+    fn day(&self) -> u8;
+
+    /// Read the input from `source`, parse it, and solve for one `Part`
+    fn run(&self, part: Part, source: Source) -> Result<Output>;
+
+    /// Read the input from `source`, parse it once, and solve both parts
+    fn run_both(&self, source: Source) -> Result<(Output, Output)>;
+
+    /// Read the input from `source`, then parse it and solve each of the `parts`,
+    /// timing each of those phases on its own
+    fn run_timed(&self, parts: &[Part], source: Source) -> Result<Answered>;
+}
+
+impl<S: Solution + Sync> Puzzle for S {
+    fn day(&self) -> u8 {
+        S::DAY
+    }
+
+    fn run(&self, part: Part, source: Source) -> Result<Output> {
+        S::run(part, source)
+    }
+
+    fn run_both(&self, source: Source) -> Result<(Output, Output)> {
+        S::run_both(source)
+    }
+
+    fn run_timed(&self, parts: &[Part], source: Source) -> Result<Answered> {
+        S::run_timed(parts, source)
+    }
+}
+
+/// All the days on the Advent Calendar, in order
+static REGISTRY: [&dyn Puzzle; 25] = [
+    &day01::Day01,
+    &day02::Day02,
+    &day03::Day03,
+    &day04::Day04,
+    &day05::Day05,
+    &day06::Day06,
+    &day07::Day07,
+    &day08::Day08,
+    &day09::Day09,
+    &day10::Day10,
+    &day11::Day11,
+    &day12::Day12,
+    &day13::Day13,
+    &day14::Day14,
+    &day15::Day15,
+    &day16::Day16,
+    &day17::Day17,
+    &day18::Day18,
+    &day19::Day19,
+    &day20::Day20,
+    &day21::Day21,
+    &day22::Day22,
+    &day23::Day23,
+    &day24::Day24,
+    &day25::Day25,
+];
+
+/// Iterate over the puzzles for every day, in order
+pub fn puzzles() -> impl Iterator<Item = &'static dyn Puzzle> {
+    REGISTRY.iter().copied()
+}
+
+/// Look up the puzzle for a particular day, if there is one
+pub fn puzzle(day: u8) -> Option<&'static dyn Puzzle> {
+    REGISTRY.iter().copied().find(|puzzle| puzzle.day() == day)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn check_registry() {
+        let days: Vec<_> = puzzles().map(|puzzle| puzzle.day()).collect();
+        let expected: Vec<_> = (1..=25).collect();
+        assert_eq!(days, expected);
+
+        assert_eq!(puzzle(12).map(|puzzle| puzzle.day()), Some(12));
+        assert!(puzzle(0).is_none());
+        assert!(puzzle(26).is_none());
+    }
+
+    #[test]
+    fn check_run_timed() {
+        let text = "1000\n2000\n\n4000\n\n5000\n6000\n";
+        let parts = [Part::One, Part::Two];
+        let (answer_one, answer_two, timings) =
+            <day01::Day01 as Solution>::run_timed(&parts, text.into()).unwrap();
+        assert_eq!(answer_one.unwrap(), 11000);
+        assert_eq!(answer_two.unwrap(), 18000);
+        assert_eq!(
+            timings.total(),
+            timings.parse + timings.part_one + timings.part_two
+        );
+
+        let (answer_one, answer_two, timings) =
+            <day01::Day01 as Solution>::run_timed(&[Part::Two], text.into()).unwrap();
+        assert!(answer_one.is_none());
+        assert_eq!(answer_two.unwrap(), 18000);
+        assert_eq!(timings.part_one, Duration::ZERO);
+    }
+}
@@ -0,0 +1,504 @@
+//! Submitting answers to the Advent of Code site, and remembering how that went. Every
+//! attempt is appended to a history file in the user's data directory (next to their
+//! downloaded inputs), which looks like this:
+//!
+//! ```toml
+//! [[attempt]]
+//! day = 1
+//! part = 1
+//! answer = "69795"
+//! verdict = "right"
+//! time = 1669870800
+//! ```
+//!
+//! The history is checked before sending anything, so an answer that's already known
+//! to be wrong never gets sent twice. The site also says whether a wrong answer was
+//! too high or too low, which rules out a lot of other answers, too.
+use crate::answers::toml_slice;
+use crate::fetch::{Client, FetchError};
+use crate::{Error, Part, Result};
+use std::fmt::{Display, Formatter, Result as FmtResult};
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::time::{SystemTime, UNIX_EPOCH};
+use toml::Value;
+
+/// What the puzzle server had to say about an answer
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Verdict {
+    Right,
+    Wrong,
+    TooHigh,
+    TooLow,
+    /// Answered too recently, so the answer wasn't checked. Try again after this
+    /// many seconds, if the server said how long.
+    RateLimited(Option<u64>),
+    /// That part has already been solved, so the answer wasn't checked
+    AlreadySolved,
+}
+
+impl Verdict {
+    /// Read the verdict from the page the server sends back after an answer is
+    /// submitted, if it's a page we recognize
+    pub fn parse(page: &str) -> Option<Self> {
+        // The verdict is in the page's only `<article>`
+        let text = page
+            .split_once("<article")
+            .and_then(|(_, rest)| rest.split_once("</article>"))
+            .map_or(page, |(article, _)| article);
+
+        if text.contains("That's the right answer") {
+            return Some(Verdict::Right);
+        }
+        if text.contains("That's not the right answer") {
+            return match text {
+                _ if text.contains("too high") => Some(Verdict::TooHigh),
+                _ if text.contains("too low") => Some(Verdict::TooLow),
+                _ => Some(Verdict::Wrong),
+            };
+        }
+        if text.contains("You gave an answer too recently") {
+            return Some(Verdict::RateLimited(wait_time(text)));
+        }
+        if text.contains("Did you already complete it?") {
+            return Some(Verdict::AlreadySolved);
+        }
+        None
+    }
+
+    /// Whether the server said the answer was wrong, one way or another
+    pub fn is_wrong(&self) -> bool {
+        matches!(self, Verdict::Wrong | Verdict::TooHigh | Verdict::TooLow)
+    }
+
+    /// The name of this verdict in the history file
+    fn label(&self) -> &'static str {
+        match self {
+            Verdict::Right => "right",
+            Verdict::Wrong => "wrong",
+            Verdict::TooHigh => "too_high",
+            Verdict::TooLow => "too_low",
+            Verdict::RateLimited(_) => "rate_limited",
+            Verdict::AlreadySolved => "already_solved",
+        }
+    }
+
+    /// Get a verdict back from its name in the history file
+    fn from_label(label: &str, wait: Option<u64>) -> Option<Self> {
+        let verdict = match label {
+            "right" => Verdict::Right,
+            "wrong" => Verdict::Wrong,
+            "too_high" => Verdict::TooHigh,
+            "too_low" => Verdict::TooLow,
+            "rate_limited" => Verdict::RateLimited(wait),
+            "already_solved" => Verdict::AlreadySolved,
+            _ => return None,
+        };
+        Some(verdict)
+    }
+}
+
+impl Display for Verdict {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match self {
+            Verdict::Right => write!(f, "right"),
+            Verdict::Wrong => write!(f, "wrong"),
+            Verdict::TooHigh => write!(f, "wrong (too high)"),
+            Verdict::TooLow => write!(f, "wrong (too low)"),
+            Verdict::RateLimited(Some(wait)) => {
+                write!(f, "not checked (answered too recently, wait {wait}s)")
+            }
+            Verdict::RateLimited(None) => write!(f, "not checked (answered too recently)"),
+            Verdict::AlreadySolved => write!(f, "not checked (already solved)"),
+        }
+    }
+}
+
+/// Find how long the server wants us to wait, from something like "You have 1m 5s
+/// left to wait."
+fn wait_time(text: &str) -> Option<u64> {
+    let (_, rest) = text.split_once("You have ")?;
+    let (wait, _) = rest.split_once(" left to wait")?;
+    wait.split_whitespace()
+        .map(|amount| {
+            let (number, unit) = amount.split_at(amount.len().checked_sub(1)?);
+            let number: u64 = number.parse().ok()?;
+            match unit {
+                "h" => Some(number * 60 * 60),
+                "m" => Some(number * 60),
+                "s" => Some(number),
+                _ => None,
+            }
+        })
+        .sum()
+}
+
+impl Client {
+    /// Submit an answer for one part of a day, and find out if it's right
+    pub fn submit(&self, day: u8, part: Part, answer: &str) -> Result<Verdict, FetchError> {
+        let url = self.day_url(day, "answer");
+        let level = part_number(part);
+        let form = format!("level={level}&answer={}", url_encode(answer));
+        let page = self.send(&url, Some(&form))?;
+        Verdict::parse(&page).ok_or_else(|| FetchError::Http {
+            url,
+            reason: String::from("couldn't find a verdict in the response"),
+        })
+    }
+}
+
+/// Percent-encode everything in a form value but the letters, numbers, and the few
+/// symbols that don't need it
+fn url_encode(value: &str) -> String {
+    value
+        .bytes()
+        .map(|byte| match byte {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'_' | b'.' | b'~' => {
+                (byte as char).to_string()
+            }
+            _ => format!("%{byte:02X}"),
+        })
+        .collect()
+}
+
+/// Parts are numbered in the history file, like they are on the site
+fn part_number(part: Part) -> u8 {
+    match part {
+        Part::One => 1,
+        Part::Two => 2,
+    }
+}
+
+/// One answer that was submitted, and what the server said about it
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Attempt {
+    pub day: u8,
+    pub part: Part,
+    pub answer: String,
+    pub verdict: Verdict,
+    /// When the answer was submitted, in seconds since the Unix epoch
+    pub time: u64,
+}
+
+impl Attempt {
+    /// An attempt that was just made
+    pub fn new(day: u8, part: Part, answer: &str, verdict: Verdict) -> Self {
+        let time = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|since| since.as_secs())
+            .unwrap_or_default();
+        let answer = answer.to_string();
+        Attempt {
+            day,
+            part,
+            answer,
+            verdict,
+            time,
+        }
+    }
+
+    /// Read an attempt from one of the `[[attempt]]` tables in the history file.
+    /// Fails with the key that's missing or doesn't make sense.
+    fn from_table(table: &Value) -> std::result::Result<Self, &'static str> {
+        let int = |key| table.get(key).and_then(Value::as_integer).ok_or(key);
+        let day = int("day")?.try_into().map_err(|_| "day")?;
+        let part = match int("part")? {
+            1 => Part::One,
+            2 => Part::Two,
+            _ => return Err("part"),
+        };
+        let answer = table
+            .get("answer")
+            .and_then(Value::as_str)
+            .ok_or("answer")?;
+        let answer = answer.to_string();
+        let verdict = table
+            .get("verdict")
+            .and_then(Value::as_str)
+            .ok_or("verdict")?;
+        let wait = int("wait").ok().and_then(|wait| wait.try_into().ok());
+        let verdict = Verdict::from_label(verdict, wait).ok_or("verdict")?;
+        let time = int("time")?.try_into().map_err(|_| "time")?;
+        Ok(Attempt {
+            day,
+            part,
+            answer,
+            verdict,
+            time,
+        })
+    }
+
+    /// Write this attempt as an `[[attempt]]` table for the history file
+    fn to_table(&self) -> String {
+        let mut table = format!(
+            "[[attempt]]\nday = {}\npart = {}\nanswer = {}\nverdict = \"{}\"\ntime = {}\n",
+            self.day,
+            part_number(self.part),
+            Value::String(self.answer.clone()),
+            self.verdict.label(),
+            self.time
+        );
+        if let Verdict::RateLimited(Some(wait)) = self.verdict {
+            table.push_str(&format!("wait = {wait}\n"));
+        }
+        table
+    }
+}
+
+/// All the ways that reading or writing the answer history can fail
+#[derive(Debug)]
+pub enum HistoryError {
+    /// The history file couldn't be read, or doesn't make sense
+    Read(crate::Error),
+
+    /// The history file couldn't be written
+    Write {
+        path: PathBuf,
+        error: std::io::Error,
+    },
+}
+
+impl Display for HistoryError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match self {
+            HistoryError::Read(error) => write!(f, "Could not read the answer history: {error}"),
+            HistoryError::Write { path, error } => {
+                write!(f, "Could not write to {}: {error}", path.display())
+            }
+        }
+    }
+}
+
+impl std::error::Error for HistoryError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            HistoryError::Read(error) => Some(error),
+            HistoryError::Write { error, .. } => Some(error),
+        }
+    }
+}
+
+/// Every answer that's been submitted for one user, kept in a TOML file
+#[derive(Debug, Clone)]
+pub struct History {
+    path: PathBuf,
+    attempts: Vec<Attempt>,
+}
+
+impl History {
+    /// Read the history from a file, which doesn't need to exist yet
+    pub fn read(path: &Path) -> Result<Self, HistoryError> {
+        let path = path.to_path_buf();
+        if !path.exists() {
+            let attempts = Vec::new();
+            return Ok(History { path, attempts });
+        }
+
+        let text = crate::Source::Path(&path).text("");
+        let attempts = text
+            .and_then(|text| History::parse(&text))
+            .map_err(HistoryError::Read)?;
+        Ok(History { path, attempts })
+    }
+
+    /// Parse the attempts from the text of a history file
+    fn parse(text: &str) -> Result<Vec<Attempt>> {
+        let table = match text.parse::<Value>() {
+            Ok(table) => table,
+            Err(error) => {
+                let (line, column) = error.line_col().unwrap_or_default();
+                let reason = error.to_string();
+                return Err(Error::Parse {
+                    line: line + 1,
+                    column: column + 1,
+                    reason,
+                });
+            }
+        };
+        let mut attempts = Vec::new();
+        let tables = table.get("attempt").and_then(Value::as_array);
+        for (idx, table) in tables.into_iter().flatten().enumerate() {
+            match Attempt::from_table(table) {
+                Ok(attempt) => attempts.push(attempt),
+                Err(key) => {
+                    let at = toml_slice(text, Some("attempt"), idx, Some(key));
+                    let reason = format!("attempt #{} has a missing or invalid {key}", idx + 1);
+                    return Err(Error::parse(text, at, reason));
+                }
+            }
+        }
+        Ok(attempts)
+    }
+
+    /// All the attempts so far, oldest first
+    pub fn attempts(&self) -> &[Attempt] {
+        &self.attempts
+    }
+
+    /// Add an attempt to the end of the history file. Each attempt is its own
+    /// table, so the file only ever needs to be appended to.
+    pub fn record(&mut self, attempt: Attempt) -> Result<(), HistoryError> {
+        let write_error = |error| HistoryError::Write {
+            path: self.path.clone(),
+            error,
+        };
+        if let Some(dir) = self.path.parent() {
+            std::fs::create_dir_all(dir).map_err(write_error)?;
+        }
+        let mut file = std::fs::OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&self.path)
+            .map_err(write_error)?;
+        writeln!(file, "{}", attempt.to_table()).map_err(write_error)?;
+        self.attempts.push(attempt);
+        Ok(())
+    }
+
+    /// What we already know about an answer for one part of a day, if anything,
+    /// without asking the server. Once a part has been solved, every other answer
+    /// is wrong. Otherwise, an answer is wrong if it's been tried before and was
+    /// wrong, or if it's a number at or past an answer that was too high or too low.
+    pub fn known(&self, day: u8, part: Part, answer: &str) -> Option<Verdict> {
+        let attempts: Vec<_> = self
+            .attempts
+            .iter()
+            .filter(|attempt| attempt.day == day && attempt.part == part)
+            .collect();
+        if let Some(right) = attempts.iter().find(|a| a.verdict == Verdict::Right) {
+            return match right.answer == answer {
+                true => Some(Verdict::Right),
+                false => Some(Verdict::Wrong),
+            };
+        }
+        if let Some(tried) = attempts
+            .iter()
+            .find(|a| a.verdict.is_wrong() && a.answer == answer)
+        {
+            return Some(tried.verdict);
+        }
+
+        let number = answer.parse::<i128>().ok()?;
+        let bound = |verdict| {
+            attempts
+                .iter()
+                .filter(move |a| a.verdict == verdict)
+                .flat_map(|a| a.answer.parse::<i128>().ok())
+        };
+        if bound(Verdict::TooHigh).any(|high| number >= high) {
+            return Some(Verdict::TooHigh);
+        }
+        if bound(Verdict::TooLow).any(|low| number <= low) {
+            return Some(Verdict::TooLow);
+        }
+        None
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::fetch::{mock_server, test_data_dir};
+
+    const RIGHT: &str = "<main><article><p>That's the right answer! You are one gold star \
+                         closer to saving your vacation.</p></article></main>";
+    const TOO_HIGH: &str = "<article><p>That's not the right answer; your answer is too \
+                            high. Please wait one minute before trying again.</p></article>";
+    const TOO_RECENT: &str = "<article><p>You gave an answer too recently; you have to wait \
+                              after submitting an answer before trying again. You have 1m 5s \
+                              left to wait.</p></article>";
+
+    #[test]
+    fn check_verdicts() {
+        assert_eq!(Verdict::parse(RIGHT), Some(Verdict::Right));
+        assert_eq!(Verdict::parse(TOO_HIGH), Some(Verdict::TooHigh));
+        let too_low = TOO_HIGH.replace("too high", "too low");
+        assert_eq!(Verdict::parse(&too_low), Some(Verdict::TooLow));
+        let wrong = "<article><p>That's not the right answer. If you're stuck...</p></article>";
+        assert_eq!(Verdict::parse(wrong), Some(Verdict::Wrong));
+        assert_eq!(
+            Verdict::parse(TOO_RECENT),
+            Some(Verdict::RateLimited(Some(65)))
+        );
+        let solved = "<article><p>You don't seem to be solving the right level.  Did you \
+                      already complete it?</p></article>";
+        assert_eq!(Verdict::parse(solved), Some(Verdict::AlreadySolved));
+        assert_eq!(Verdict::parse("<html>Something else</html>"), None);
+    }
+
+    #[test]
+    fn check_history() {
+        let dir = test_data_dir("history");
+        let path = dir.join("history.toml");
+        let mut history = History::read(&path).unwrap();
+        assert!(history.attempts().is_empty());
+
+        history
+            .record(Attempt::new(1, Part::One, "500", Verdict::TooHigh))
+            .unwrap();
+        history
+            .record(Attempt::new(1, Part::One, "100", Verdict::TooLow))
+            .unwrap();
+        history
+            .record(Attempt::new(1, Part::One, "\"ABC\"", Verdict::Wrong))
+            .unwrap();
+        history
+            .record(Attempt::new(
+                1,
+                Part::One,
+                "300",
+                Verdict::RateLimited(Some(30)),
+            ))
+            .unwrap();
+        history
+            .record(Attempt::new(2, Part::Two, "42", Verdict::Right))
+            .unwrap();
+
+        // Everything should come back just the way it was written
+        let history = History::read(&path).unwrap();
+        assert_eq!(history.attempts().len(), 5);
+        assert_eq!(history.attempts()[2].answer, "\"ABC\"");
+        assert_eq!(
+            history.attempts()[3].verdict,
+            Verdict::RateLimited(Some(30))
+        );
+
+        let known = |day, part, answer| history.known(day, part, answer);
+        assert_eq!(known(1, Part::One, "500"), Some(Verdict::TooHigh));
+        assert_eq!(known(1, Part::One, "501"), Some(Verdict::TooHigh));
+        assert_eq!(known(1, Part::One, "99"), Some(Verdict::TooLow));
+        assert_eq!(known(1, Part::One, "\"ABC\""), Some(Verdict::Wrong));
+        assert_eq!(known(1, Part::One, "300"), None);
+        assert_eq!(known(1, Part::Two, "500"), None);
+        assert_eq!(known(2, Part::Two, "42"), Some(Verdict::Right));
+        assert_eq!(known(2, Part::Two, "43"), Some(Verdict::Wrong));
+
+        // A bad attempt gets pointed out right where it's written
+        let mut text = std::fs::read_to_string(&path).unwrap();
+        let lines = text.lines().count();
+        text.push_str("[[attempt]]\nday = 3\npart = 1\nanswer = \"1\"\nverdict = \"maybe\"\n");
+        std::fs::write(&path, text).unwrap();
+        let Err(HistoryError::Read(Error::Parse { line, column, reason })) = History::read(&path)
+        else {
+            panic!("Should not read!");
+        };
+        assert_eq!((line, column), (lines + 5, 11));
+        assert!(reason.contains("attempt #6"));
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn check_submit() {
+        let (base_url, server) = mock_server(vec![(200, TOO_RECENT), (200, RIGHT)]);
+        let client = Client::new(&base_url, "abc123").unwrap();
+        let verdict = client.submit(10, Part::Two, "EHZFZHCZ").unwrap();
+        assert_eq!(verdict, Verdict::RateLimited(Some(65)));
+        let verdict = client.submit(18, Part::One, "1,2 3").unwrap();
+        assert_eq!(verdict, Verdict::Right);
+
+        let requests = server.join().unwrap();
+        assert!(requests[0].starts_with("POST /2022/day/10/answer HTTP/1.0\r\n"));
+        assert!(requests[0].ends_with("\r\n\r\nlevel=2&answer=EHZFZHCZ"));
+        assert!(requests[1].ends_with("\r\n\r\nlevel=1&answer=1%2C2%203"));
+    }
+}
@@ -0,0 +1,339 @@
+//! Drawing the states of the days that are simulations, for debugging (and showing
+//! off). Anything that can say what's in each cell of a grid implements `Frame`, and
+//! each day with something to see has a `visualize.rs` that runs its simulation and
+//! hands every interesting state to a `Sink`. The sinks decide what to do with them:
+//! print them as text, replay them in the terminal like a flip book, or write them
+//! out as PPM images (which just about anything can turn into a GIF or a video).
+use crate::{day09, day14, day17, day22, day23, day24};
+use crate::{Error, Result, Solution, Source};
+use std::io::Write;
+use std::ops::Range;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+/// The days that have a visualization
+pub const DAYS: [u8; 6] = [9, 14, 17, 22, 23, 24];
+
+/// A color, as red, green, and blue
+pub type Rgb = [u8; 3];
+
+/// A picture of some state, as a grid of characters
+pub trait Frame {
+    /// How big the frame is, as (rows, columns)
+    fn size(&self) -> (usize, usize);
+
+    /// The character to draw at a row and column in the frame
+    fn glyph(&self, row: usize, col: usize) -> char;
+
+    /// The color to paint a row and column in images. Picking the color from the
+    /// glyph is good enough for most days.
+    fn color(&self, row: usize, col: usize) -> Rgb {
+        palette(self.glyph(row, col))
+    }
+
+    /// Draw the whole frame as lines of text
+    fn ascii(&self) -> String {
+        let (rows, cols) = self.size();
+        let mut text = String::with_capacity(rows * (cols + 1));
+        for row in 0..rows {
+            text.extend((0..cols).map(|col| self.glyph(row, col)));
+            text.push('\n');
+        }
+        text
+    }
+}
+
+/// The colors for the glyphs the days use, more or less matching the Advent of Code
+/// site: dark blue for empty space, gray for walls and rock, and bright colors for
+/// the things that move around.
+pub fn palette(glyph: char) -> Rgb {
+    match glyph {
+        '.' | ' ' => [15, 15, 35],
+        '#' => [120, 120, 130],
+        '|' | '-' | '+' => [80, 80, 90],
+        'o' => [230, 190, 90],
+        '@' | 'E' | 'H' => [255, 70, 70],
+        '>' | '<' | '^' | 'v' => [150, 200, 255],
+        '1'..='9' => [255, 160, 60],
+        _ => [255, 255, 255],
+    }
+}
+
+/// Somewhere to send frames
+pub trait Sink {
+    /// Take the next frame
+    fn frame(&mut self, frame: &dyn Frame) -> Result<()>;
+}
+
+/// Prints each frame as text, with a blank line after each one
+pub struct Ascii<W: Write>(pub W);
+
+impl<W: Write> Sink for Ascii<W> {
+    fn frame(&mut self, frame: &dyn Frame) -> Result<()> {
+        writeln!(self.0, "{}", frame.ascii()).map_err(Error::Output)
+    }
+}
+
+/// Draws each frame over the last one in the terminal, waiting a bit between frames
+pub struct Replay<W: Write> {
+    out: W,
+    delay: Duration,
+    started: bool,
+}
+
+impl<W: Write> Replay<W> {
+    pub fn new(out: W, delay: Duration) -> Self {
+        let started = false;
+        Replay {
+            out,
+            delay,
+            started,
+        }
+    }
+}
+
+impl<W: Write> Sink for Replay<W> {
+    fn frame(&mut self, frame: &dyn Frame) -> Result<()> {
+        // Clear the screen once, then just move the cursor back to the top left
+        // and draw over the last frame, clearing whatever's left below it.
+        if !self.started {
+            write!(self.out, "\x1b[2J").map_err(Error::Output)?;
+            self.started = true;
+        }
+        write!(self.out, "\x1b[H{}\x1b[J", frame.ascii()).map_err(Error::Output)?;
+        self.out.flush().map_err(Error::Output)?;
+        std::thread::sleep(self.delay);
+        Ok(())
+    }
+}
+
+/// Writes each frame as a numbered PPM image (`frame_00000.ppm`, etc.) in a directory,
+/// with each cell drawn as a `scale` by `scale` square of pixels
+pub struct Ppm {
+    dir: PathBuf,
+    scale: usize,
+    count: usize,
+}
+
+impl Ppm {
+    pub fn new(dir: &Path, scale: usize) -> Self {
+        let dir = dir.to_path_buf();
+        let scale = scale.max(1);
+        Ppm {
+            dir,
+            scale,
+            count: 0,
+        }
+    }
+
+    /// How many images have been written so far
+    pub fn count(&self) -> usize {
+        self.count
+    }
+
+    /// Encode a frame as a binary PPM image
+    pub fn encode(frame: &dyn Frame, scale: usize) -> Vec<u8> {
+        let (rows, cols) = frame.size();
+        let (height, width) = (rows * scale, cols * scale);
+        let mut image = format!("P6\n{width} {height}\n255\n").into_bytes();
+        image.reserve(width * height * 3);
+        for row in 0..rows {
+            let colors: Vec<_> = (0..cols).map(|col| frame.color(row, col)).collect();
+            for _ in 0..scale {
+                for color in colors.iter() {
+                    for _ in 0..scale {
+                        image.extend_from_slice(color);
+                    }
+                }
+            }
+        }
+        image
+    }
+}
+
+impl Sink for Ppm {
+    fn frame(&mut self, frame: &dyn Frame) -> Result<()> {
+        let path = self.dir.join(format!("frame_{:05}.ppm", self.count));
+        let write_error = |error| Error::Write {
+            path: path.clone(),
+            error,
+        };
+        std::fs::create_dir_all(&self.dir).map_err(write_error)?;
+        std::fs::write(&path, Ppm::encode(frame, self.scale)).map_err(write_error)?;
+        self.count += 1;
+        Ok(())
+    }
+}
+
+/// Only passes every `n`th frame (starting with the first one) along to another
+/// sink, for the simulations that run a long time
+pub struct Every<S: Sink> {
+    sink: S,
+    n: usize,
+    seen: usize,
+}
+
+impl<S: Sink> Every<S> {
+    pub fn new(sink: S, n: usize) -> Self {
+        let n = n.max(1);
+        Every { sink, n, seen: 0 }
+    }
+
+    /// Get the other sink back
+    pub fn into_inner(self) -> S {
+        self.sink
+    }
+}
+
+impl<S: Sink> Sink for Every<S> {
+    fn frame(&mut self, frame: &dyn Frame) -> Result<()> {
+        self.seen += 1;
+        match (self.seen - 1) % self.n {
+            0 => self.sink.frame(frame),
+            _ => Ok(()),
+        }
+    }
+}
+
+/// Shows just part of another frame, for simulations that only use a small corner
+/// of a big grid. Rows and columns outside the other frame are blank.
+pub struct Crop<'a> {
+    pub frame: &'a dyn Frame,
+    pub rows: Range<usize>,
+    pub cols: Range<usize>,
+}
+
+impl Frame for Crop<'_> {
+    fn size(&self) -> (usize, usize) {
+        (self.rows.len(), self.cols.len())
+    }
+
+    fn glyph(&self, row: usize, col: usize) -> char {
+        let (rows, cols) = self.frame.size();
+        let (row, col) = (row + self.rows.start, col + self.cols.start);
+        match row < rows && col < cols {
+            true => self.frame.glyph(row, col),
+            false => ' ',
+        }
+    }
+
+    fn color(&self, row: usize, col: usize) -> Rgb {
+        let (rows, cols) = self.frame.size();
+        let (row, col) = (row + self.rows.start, col + self.cols.start);
+        match row < rows && col < cols {
+            true => self.frame.color(row, col),
+            false => palette(' '),
+        }
+    }
+}
+
+/// Runs a day's visualization on its puzzle input, sending the frames to a `Sink`
+pub type Visualization = fn(Source, &mut dyn Sink) -> Result<()>;
+
+/// Look up the visualization for a particular day, if there is one (see `DAYS`)
+pub fn visualization(day: u8) -> Option<Visualization> {
+    let visualization: Visualization = match day {
+        9 => |source, sink| run::<day09::Day09>(source, sink, day09::visualize::visualize),
+        14 => |source, sink| run::<day14::Day14>(source, sink, day14::visualize::visualize),
+        17 => |source, sink| run::<day17::Day17>(source, sink, day17::visualize::visualize),
+        22 => |source, sink| run::<day22::Day22>(source, sink, day22::visualize::visualize),
+        23 => |source, sink| run::<day23::Day23>(source, sink, day23::visualize::visualize),
+        24 => |source, sink| run::<day24::Day24>(source, sink, day24::visualize::visualize),
+        _ => return None,
+    };
+    Some(visualization)
+}
+
+/// Parse the input for a day and hand it to that day's visualization
+fn run<S: Solution>(
+    source: Source,
+    sink: &mut dyn Sink,
+    draw: fn(&S::Input<'_>, &mut dyn Sink) -> Result<()>,
+) -> Result<()> {
+    let text = source.text(S::INPUT)?;
+    let input = S::parse(&text)?;
+    draw(&input, sink)
+}
+
+/// Collects the text of every frame, for testing the days' visualizations
+#[cfg(test)]
+#[derive(Debug, Default)]
+pub(crate) struct Frames(pub Vec<String>);
+
+#[cfg(test)]
+impl Sink for Frames {
+    fn frame(&mut self, frame: &dyn Frame) -> Result<()> {
+        self.0.push(frame.ascii());
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    /// A checkerboard, `n` cells on a side
+    struct Checkers(usize);
+
+    impl Frame for Checkers {
+        fn size(&self) -> (usize, usize) {
+            (self.0, self.0)
+        }
+
+        fn glyph(&self, row: usize, col: usize) -> char {
+            match (row + col) % 2 {
+                0 => '#',
+                _ => '.',
+            }
+        }
+    }
+
+    #[test]
+    fn check_ascii() {
+        let mut sink = Ascii(Vec::new());
+        sink.frame(&Checkers(3)).unwrap();
+        sink.frame(&Checkers(2)).unwrap();
+        let text = String::from_utf8(sink.0).unwrap();
+        assert_eq!(text, "#.#\n.#.\n#.#\n\n#.\n.#\n\n");
+    }
+
+    #[test]
+    fn check_ppm() {
+        let image = Ppm::encode(&Checkers(2), 3);
+        let header = b"P6\n6 6\n255\n";
+        assert!(image.starts_with(header));
+        assert_eq!(image.len(), header.len() + 6 * 6 * 3);
+
+        // The first row of pixels is three rock pixels, then three empty pixels
+        let pixels = &image[header.len()..];
+        assert_eq!(pixels[..3], palette('#'));
+        assert_eq!(pixels[6..9], palette('#'));
+        assert_eq!(pixels[9..12], palette('.'));
+    }
+
+    #[test]
+    fn check_every_and_crop() {
+        let mut sink = Every::new(Frames::default(), 2);
+        for n in 1..=5 {
+            sink.frame(&Checkers(n)).unwrap();
+        }
+        let sizes: Vec<_> = sink.sink.0.iter().map(|f| f.lines().count()).collect();
+        assert_eq!(sizes, [1, 3, 5]);
+
+        let checkers = Checkers(4);
+        let crop = Crop {
+            frame: &checkers,
+            rows: 1..3,
+            cols: 2..5,
+        };
+        assert_eq!(crop.ascii(), ".# \n#. \n");
+    }
+
+    #[test]
+    fn check_visualizations() {
+        let days: Vec<_> = (1..=25)
+            .filter(|day| visualization(*day).is_some())
+            .collect();
+        assert_eq!(days, DAYS);
+    }
+}